@@ -10,23 +10,52 @@ from .orjson import __version__
 
 __all__ = (
     "__version__",
+    "apply_patch",
+    "create_merge_patch",
+    "Decoder",
+    "diff",
     "dumps",
+    "dumps_cbor",
+    "dumps_msgpack",
     "Fragment",
+    "iter_items",
     "JSONDecodeError",
     "JSONEncodeError",
     "loads",
+    "loads_cbor",
+    "loads_msgpack",
+    "merge_patch",
+    "merge_patch_json",
+    "Node",
     "OPT_APPEND_NEWLINE",
+    "OPT_CAMEL_CASE_KEYS",
+    "OPT_CANONICAL",
+    "OPT_CBOR_TYPED_ARRAY",
     "OPT_INDENT_2",
+    "OPT_KEBAB_CASE_KEYS",
+    "OPT_MSGPACK_TIMESTAMP",
     "OPT_NAIVE_UTC",
     "OPT_NON_STR_KEYS",
+    "OPT_OMIT_DEFAULTS",
     "OPT_OMIT_MICROSECONDS",
+    "OPT_OMIT_NONE",
+    "OPT_PASCAL_CASE_KEYS",
     "OPT_PASSTHROUGH_DATACLASS",
     "OPT_PASSTHROUGH_DATETIME",
     "OPT_PASSTHROUGH_SUBCLASS",
     "OPT_SERIALIZE_DATACLASS",
+    "OPT_SERIALIZE_NAMEDTUPLE",
     "OPT_SERIALIZE_NUMPY",
+    "OPT_SERIALIZE_OBJECT",
+    "OPT_SERIALIZE_UNDERSCORE",
     "OPT_SERIALIZE_UUID",
+    "OPT_SNAKE_CASE_KEYS",
     "OPT_SORT_KEYS",
     "OPT_STRICT_INTEGER",
     "OPT_UTC_Z",
+    "parse_lazy",
+    "query",
+    "raw_decode",
+    "reformat",
+    "validate",
 )
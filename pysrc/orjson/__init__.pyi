@@ -2,7 +2,7 @@
 # Copyright ijl (2019-2026), Eric Jolibois (2022), Anders Kaseorg (2020)
 
 import json
-from collections.abc import Callable
+from collections.abc import Callable, Iterator, Sequence
 from typing import Any
 
 __version__: str
@@ -12,25 +12,91 @@ def dumps(
     default: Callable[[Any], Any] | None = ...,
     option: int | None = ...,
 ) -> bytes: ...
-def loads(__obj: bytes | bytearray | memoryview | str) -> Any: ...
+def dumps_cbor(
+    __obj: Any,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+) -> bytes: ...
+def dumps_msgpack(
+    __obj: Any,
+    default: Callable[[Any], Any] | None = ...,
+    option: int | None = ...,
+) -> bytes: ...
+def loads(
+    __obj: bytes | bytearray | memoryview | str,
+    *,
+    pointer: str | tuple[str, ...] | None = ...,
+    keys: set[str] | frozenset[str] | dict[str, Any] | None = ...,
+    option: int | None = ...,
+) -> Any: ...
+def loads_cbor(__obj: bytes | bytearray | memoryview) -> Any: ...
+def loads_msgpack(
+    __obj: bytes | bytearray | memoryview,
+    option: int | None = ...,
+) -> Any: ...
+def iter_items(__obj: bytes | str, prefix: str = ...) -> Iterator[Any]: ...
+def parse_lazy(__obj: bytes | bytearray | memoryview | str) -> Any: ...
+def validate(__obj: bytes | bytearray | memoryview | str) -> None: ...
+def reformat(
+    __obj: bytes | bytearray | memoryview | str,
+    option: int | None = ...,
+) -> bytes: ...
+def merge_patch(__target: Any, __patch: Any) -> Any: ...
+def create_merge_patch(__source: Any, __target: Any) -> Any: ...
+def merge_patch_json(
+    __target: bytes | bytearray | memoryview | str,
+    __patch: bytes | bytearray | memoryview | str,
+    option: int | None = ...,
+) -> bytes: ...
+def apply_patch(__doc: Any, __ops: Sequence[dict[str, Any]]) -> Any: ...
+def diff(__a: Any, __b: Any) -> list[dict[str, Any]]: ...
+def query(__data: Any, __path: str) -> list[Any]: ...
+def raw_decode(
+    __obj: bytes | bytearray | memoryview | str,
+    idx: int = ...,
+) -> tuple[Any, int]: ...
 
 class JSONDecodeError(json.JSONDecodeError): ...
 class JSONEncodeError(TypeError): ...
 
+class Decoder:
+    def feed(self, __chunk: bytes | bytearray | memoryview | str) -> list[Any]: ...
+    def close(self) -> list[Any]: ...
+
+class Node:
+    def __getitem__(self, __key: str | int) -> Any: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[Any]: ...
+    def get(self, __key: str | int, __default: Any = ...) -> Any: ...
+    def keys(self) -> list[str]: ...
+    def to_python(self) -> Any: ...
+
 class Fragment(tuple):
     contents: bytes | str
 
 OPT_APPEND_NEWLINE: int
+OPT_CAMEL_CASE_KEYS: int
+OPT_CANONICAL: int
+OPT_CBOR_TYPED_ARRAY: int
 OPT_INDENT_2: int
+OPT_KEBAB_CASE_KEYS: int
+OPT_MSGPACK_TIMESTAMP: int
 OPT_NAIVE_UTC: int
 OPT_NON_STR_KEYS: int
+OPT_OMIT_DEFAULTS: int
 OPT_OMIT_MICROSECONDS: int
+OPT_OMIT_NONE: int
+OPT_PASCAL_CASE_KEYS: int
 OPT_PASSTHROUGH_DATACLASS: int
 OPT_PASSTHROUGH_DATETIME: int
 OPT_PASSTHROUGH_SUBCLASS: int
 OPT_SERIALIZE_DATACLASS: int
+OPT_SERIALIZE_NAMEDTUPLE: int
 OPT_SERIALIZE_NUMPY: int
+OPT_SERIALIZE_OBJECT: int
+OPT_SERIALIZE_UNDERSCORE: int
 OPT_SERIALIZE_UUID: int
+OPT_SNAKE_CASE_KEYS: int
 OPT_SORT_KEYS: int
 OPT_STRICT_INTEGER: int
 OPT_UTC_Z: int
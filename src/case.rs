@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::opt::{CAMEL_CASE_KEYS, KEBAB_CASE_KEYS, Opt, PASCAL_CASE_KEYS, SNAKE_CASE_KEYS};
+
+/// Split `key` into its leading underscores, its words, and its trailing
+/// underscores, which are kept as they are when converting from snake_case.
+fn split_underscores(key: &str) -> (&str, &str, &str) {
+    let body = key.trim_start_matches('_');
+    let prefix = &key[..key.len() - body.len()];
+    let words = body.trim_end_matches('_');
+    (prefix, words, &body[words.len()..])
+}
+
+/// Join the words of a snake_case `key`, upper-casing the first letter of
+/// each word after the first, or of every word if `capitalize_first`. A
+/// letter is upper-cased using Unicode case mapping, so `"é_ü"` is `"éÜ"`.
+fn join_words(key: &str, capitalize_first: bool) -> String {
+    let (prefix, words, suffix) = split_underscores(key);
+    let mut ret = String::with_capacity(key.len());
+    ret.push_str(prefix);
+    let mut capitalize = capitalize_first;
+    for ch in words.chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            ret.extend(ch.to_uppercase());
+            capitalize = false;
+        } else {
+            ret.push(ch);
+        }
+    }
+    ret.push_str(suffix);
+    ret
+}
+
+fn to_kebab_case(key: &str) -> String {
+    let (prefix, words, suffix) = split_underscores(key);
+    let mut ret = String::with_capacity(key.len());
+    ret.push_str(prefix);
+    ret.push_str(&words.replace('_', "-"));
+    ret.push_str(suffix);
+    ret
+}
+
+/// Convert a camelCase, PascalCase, or kebab-case `key` to snake_case. An
+/// uppercase letter begins a word if it follows a lowercase letter or digit,
+/// or if it is the last of a run of uppercase letters followed by a
+/// lowercase letter, so that `"HTTPServer"` is `"http_server"`. Letters are
+/// classified and lower-cased using Unicode case mapping.
+fn to_snake_case(key: &str) -> String {
+    let mut ret = String::with_capacity(key.len() + 4);
+    let mut prev: Option<char> = None;
+    let mut chars = key.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '-' {
+            ret.push('_');
+        } else if ch.is_uppercase() {
+            if let Some(prev) = prev
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase()
+                        && chars.peek().is_some_and(|next| next.is_lowercase())))
+            {
+                ret.push('_');
+            }
+            ret.extend(ch.to_lowercase());
+        } else {
+            ret.push(ch);
+        }
+        prev = Some(ch);
+    }
+    ret
+}
+
+/// Whether converting `key` to the case in `opts` changes it. This is
+/// cheaper than converting and is checked before the key is looked up in a
+/// cache.
+#[inline]
+pub(crate) fn changes_case(key: &str, opts: Opt) -> bool {
+    if opt_enabled!(opts, SNAKE_CASE_KEYS) {
+        key.bytes().any(|ch| ch.is_ascii_uppercase() || ch == b'-')
+            || (!key.is_ascii() && key.chars().any(char::is_uppercase))
+    } else if opt_enabled!(opts, PASCAL_CASE_KEYS) {
+        let (_, words, _) = split_underscores(key);
+        words.chars().next().is_some_and(char::is_lowercase) || words.contains('_')
+    } else {
+        split_underscores(key).1.contains('_')
+    }
+}
+
+/// Convert `key` to the case in `opts`, which has exactly one of the key
+/// case options.
+#[cold]
+#[inline(never)]
+pub(crate) fn convert_case(key: &str, opts: Opt) -> String {
+    if opt_enabled!(opts, CAMEL_CASE_KEYS) {
+        join_words(key, false)
+    } else if opt_enabled!(opts, PASCAL_CASE_KEYS) {
+        join_words(key, true)
+    } else if opt_enabled!(opts, KEBAB_CASE_KEYS) {
+        to_kebab_case(key)
+    } else {
+        debug_assert!(opt_enabled!(opts, SNAKE_CASE_KEYS));
+        to_snake_case(key)
+    }
+}
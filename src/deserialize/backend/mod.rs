@@ -4,4 +4,7 @@
 mod ffi;
 mod yyjson;
 
-pub(crate) use yyjson::deserialize;
+pub(crate) use yyjson::{
+    Document, Kind, PoolBuffer, TypedKind, Value, deserialize, deserialize_snake_case,
+    deserialize_with_pool, read, read_raw,
+};
@@ -5,26 +5,37 @@ use super::ffi::{
     YYJSON_READ_SUCCESS, yyjson_alc, yyjson_alc_pool_init, yyjson_doc, yyjson_read_err,
     yyjson_read_opts, yyjson_val,
 };
+use crate::case::{changes_case, convert_case};
 use crate::deserialize::DeserializeError;
-use crate::deserialize::pyobject::get_unicode_key;
+use crate::deserialize::projection::Projection;
+use crate::deserialize::pyobject::{get_snake_case_key, get_unicode_key};
+use crate::deserialize::scan::Numbers;
 use crate::ffi::{
     PyBoolRef, PyDictRef, PyFloatRef, PyIntRef, PyListRef, PyMem_Free, PyMem_Malloc, PyNoneRef,
     PyStrRef,
 };
-use core::ffi::c_char;
+use crate::opt::SNAKE_CASE_KEYS;
+use core::ffi::{c_char, c_void};
+use core::marker::PhantomData;
 use core::ptr::{NonNull, null, null_mut};
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 
 const YYJSON_TAG_BIT: u8 = 8;
 
 const YYJSON_VAL_SIZE: usize = core::mem::size_of::<yyjson_val>();
 
+/// Number of values the `yyjson_doc` occupies before the root value.
+const YYJSON_DOC_LEN: usize = core::mem::size_of::<yyjson_doc>().div_ceil(YYJSON_VAL_SIZE);
+
 const TAG_ARRAY: u8 = 0b00000110;
 const TAG_DOUBLE: u8 = 0b00010100;
 const TAG_FALSE: u8 = 0b00000011;
 const TAG_INT64: u8 = 0b00001100;
 const TAG_NULL: u8 = 0b00000010;
 const TAG_OBJECT: u8 = 0b00000111;
+const TAG_RAW: u8 = 0b00000001;
 const TAG_STRING: u8 = 0b00000101;
 const TAG_TRUE: u8 = 0b00001011;
 const TAG_UINT64: u8 = 0b00000100;
@@ -68,13 +79,82 @@ fn unsafe_yyjson_get_next_non_container(val: *mut yyjson_val) -> *mut yyjson_val
     unsafe { (val.cast::<u8>().add(YYJSON_VAL_SIZE)).cast::<yyjson_val>() }
 }
 
+/// Buffer for the yyjson pool allocator. This may be reused to parse
+/// multiple documents and grows as needed.
+pub(crate) struct PoolBuffer {
+    ptr: *mut c_void,
+    capacity: usize,
+}
+
+impl PoolBuffer {
+    pub const fn new() -> Self {
+        PoolBuffer {
+            ptr: null_mut(),
+            capacity: 0,
+        }
+    }
+
+    fn reserve(&mut self, len: usize) -> bool {
+        let capacity = buffer_capacity_to_allocate(len);
+        if capacity > self.capacity {
+            unsafe {
+                if !self.ptr.is_null() {
+                    PyMem_Free(self.ptr);
+                }
+                self.ptr = PyMem_Malloc(capacity);
+            }
+            if self.ptr.is_null() {
+                cold_path!();
+                self.capacity = 0;
+                return false;
+            }
+            self.capacity = capacity;
+        }
+        true
+    }
+}
+
+impl Drop for PoolBuffer {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe {
+                PyMem_Free(self.ptr);
+            }
+        }
+    }
+}
+
 pub(crate) fn deserialize(
     data: &'static str,
 ) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    let mut pool = PoolBuffer::new();
+    deserialize_with_pool(data, &mut pool)
+}
+
+pub(crate) fn deserialize_snake_case(
+    data: &'static str,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    let mut pool = PoolBuffer::new();
+    let root = read(data, &mut pool)?;
+    root.check_snake_case_keys(root, &Projection::All, data)?;
+    Ok(root.to_python_snake_case())
+}
+
+pub(crate) fn deserialize_with_pool<'a>(
+    data: &'a str,
+    pool: &mut PoolBuffer,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'a>> {
+    Ok(read(data, pool)?.to_python())
+}
+
+/// Parse `data` into `pool` and return the root value. The value and its
+/// children are valid until `pool` is used again.
+pub(crate) fn read<'a, 'p>(
+    data: &'a str,
+    pool: &'p mut PoolBuffer,
+) -> Result<Value<'p>, DeserializeError<'a>> {
     assume!(!data.is_empty());
-    let buffer_capacity = buffer_capacity_to_allocate(data.len());
-    let buffer_ptr = unsafe { PyMem_Malloc(buffer_capacity) };
-    if buffer_ptr.is_null() {
+    if !pool.reserve(data.len()) {
         return Err(DeserializeError::from_yyjson(
             Cow::Borrowed("Not enough memory to allocate buffer for parsing"),
             0,
@@ -88,7 +168,7 @@ pub(crate) fn deserialize(
         ctx: null_mut(),
     };
     unsafe {
-        yyjson_alc_pool_init(&raw mut alloc, buffer_ptr, buffer_capacity);
+        yyjson_alc_pool_init(&raw mut alloc, pool.ptr, pool.capacity);
     }
 
     let mut err = yyjson_read_err {
@@ -106,16 +186,203 @@ pub(crate) fn deserialize(
         )
     };
     if doc.is_null() {
-        unsafe {
-            PyMem_Free(buffer_ptr);
-        }
         let msg: Cow<str> = unsafe { core::ffi::CStr::from_ptr(err.msg).to_string_lossy() };
         #[allow(clippy::cast_possible_wrap)]
         let pos = err.pos as i64;
         return Err(DeserializeError::from_yyjson(msg, pos, data));
     }
-    let val = yyjson_doc_get_root(doc);
-    let pyval = {
+    Ok(Value {
+        val: yyjson_doc_get_root(doc),
+        _pool: PhantomData,
+    })
+}
+
+/// Parse `data` as `read()` does, but keep the text of each number in place
+/// of its value so that it can be written unchanged. The text is found by
+/// scanning `data`, and an error is returned if it does not pair with the
+/// numbers yyjson parsed.
+pub(crate) fn read_raw<'a: 'p, 'p>(
+    data: &'a str,
+    pool: &'p mut PoolBuffer,
+) -> Result<Value<'p>, DeserializeError<'a>> {
+    let root = read(data, pool)?;
+    let end = if unsafe_yyjson_is_ctn(root.val) {
+        unsafe_yyjson_get_next_container(root.val)
+    } else {
+        unsafe_yyjson_get_next_non_container(root.val)
+    };
+    // values are stored contiguously in document order
+    let mut numbers = Numbers::new(data);
+    let mut val = root.val;
+    while val < end {
+        let tag = unsafe { (*val).tag as u8 };
+        if matches!(tag, TAG_UINT64 | TAG_INT64 | TAG_DOUBLE) {
+            let Some(text) = numbers.next() else {
+                cold_path!();
+                return Err(numbers_mismatch(data));
+            };
+            if tag != TAG_DOUBLE && text.bytes().any(|ch| matches!(ch, b'.' | b'e' | b'E')) {
+                cold_path!();
+                return Err(numbers_mismatch(data));
+            }
+            unsafe {
+                (*val).tag = ((text.len() as u64) << YYJSON_TAG_BIT) | u64::from(TAG_RAW);
+                (*val).uni.str_ = text.as_ptr().cast::<c_char>();
+            }
+        }
+        val = unsafe { val.add(1) };
+    }
+    if numbers.next().is_some() {
+        cold_path!();
+        return Err(numbers_mismatch(data));
+    }
+    Ok(root)
+}
+
+/// The numbers found in the text of a document differ from those parsed.
+#[cold]
+fn numbers_mismatch(data: &str) -> DeserializeError<'_> {
+    DeserializeError::from_yyjson(
+        Cow::Borrowed("numbers in the document could not be read as written"),
+        0,
+        data,
+    )
+}
+
+/// Type of a value in a document parsed by `read_raw()`, with the text of
+/// strings and numbers.
+pub(crate) enum Kind<'p> {
+    Null,
+    Bool(bool),
+    Number(&'p str),
+    Str(&'p str),
+    Array,
+    Object,
+}
+
+/// Type of a value in a document parsed by `read()`, with the value of
+/// strings and numbers.
+pub(crate) enum TypedKind<'p> {
+    Null,
+    Bool(bool),
+    Uint(u64),
+    Int(i64),
+    Float(f64),
+    Str(&'p str),
+    Array,
+    Object,
+}
+
+/// Value in a document parsed by `read()`.
+#[derive(Clone, Copy)]
+pub(crate) struct Value<'p> {
+    val: *mut yyjson_val,
+    _pool: PhantomData<&'p PoolBuffer>,
+}
+
+impl<'p> Value<'p> {
+    fn from_val(val: *mut yyjson_val) -> Self {
+        Value {
+            val: val,
+            _pool: PhantomData,
+        }
+    }
+
+    pub fn is_array(self) -> bool {
+        is_yyjson_tag!(self.val, TAG_ARRAY)
+    }
+
+    pub fn is_object(self) -> bool {
+        is_yyjson_tag!(self.val, TAG_OBJECT)
+    }
+
+    /// Return the number of elements in an array or members in an object.
+    pub fn len(self) -> usize {
+        debug_assert!(unsafe_yyjson_is_ctn(self.val));
+        unsafe_yyjson_get_len(self.val)
+    }
+
+    /// Return the type of a value in a document parsed by `read_raw()`.
+    pub fn kind(self) -> Kind<'p> {
+        match unsafe { (*self.val).tag as u8 } {
+            TAG_NULL => Kind::Null,
+            TAG_TRUE => Kind::Bool(true),
+            TAG_FALSE => Kind::Bool(false),
+            TAG_RAW => Kind::Number(yy_str(self.val)),
+            TAG_STRING => Kind::Str(yy_str(self.val)),
+            TAG_ARRAY => Kind::Array,
+            TAG_OBJECT => Kind::Object,
+            _ => unreachable_unchecked!(),
+        }
+    }
+
+    /// Return the type of a value in a document parsed by `read()`.
+    pub fn typed_kind(self) -> TypedKind<'p> {
+        match ElementType::from_tag(self.val) {
+            ElementType::String => TypedKind::Str(yy_str(self.val)),
+            ElementType::Uint64 => TypedKind::Uint(unsafe { (*self.val).uni.u64_ }),
+            ElementType::Int64 => TypedKind::Int(unsafe { (*self.val).uni.i64_ }),
+            ElementType::Double => TypedKind::Float(unsafe { (*self.val).uni.f64_ }),
+            ElementType::Null => TypedKind::Null,
+            ElementType::True => TypedKind::Bool(true),
+            ElementType::False => TypedKind::Bool(false),
+            ElementType::Array => TypedKind::Array,
+            ElementType::Object => TypedKind::Object,
+        }
+    }
+
+    pub fn is_container(self) -> bool {
+        unsafe_yyjson_is_ctn(self.val)
+    }
+
+    /// Return an iterator of the elements of an array.
+    pub fn elements(self) -> Elements<'p> {
+        debug_assert!(self.is_array());
+        Elements {
+            next: unsafe_yyjson_get_first(self.val),
+            remaining: self.len(),
+            _pool: PhantomData,
+        }
+    }
+
+    /// Return an iterator of the keys and values of the members of an
+    /// object, including duplicate keys.
+    pub fn members(self) -> Members<'p> {
+        debug_assert!(self.is_object());
+        Members {
+            next: unsafe_yyjson_get_first(self.val),
+            remaining: self.len(),
+            _pool: PhantomData,
+        }
+    }
+
+    /// Return the element at `idx` of an array.
+    pub fn index(self, idx: usize) -> Option<Value<'p>> {
+        self.elements().nth(idx)
+    }
+
+    /// Return the value of the last member of an object named `key`, which
+    /// is the member `loads()` would keep.
+    pub fn get(self, key: &str) -> Option<Value<'p>> {
+        self.members()
+            .filter(|(each, _)| *each == key)
+            .last()
+            .map(|(_, val)| val)
+    }
+
+    /// Convert the value and its children to Python objects.
+    pub fn to_python(self) -> NonNull<crate::ffi::PyObject> {
+        self.to_python_keyed::<false>()
+    }
+
+    /// Convert the value and its children to Python objects with the keys
+    /// of objects converted to snake_case.
+    pub fn to_python_snake_case(self) -> NonNull<crate::ffi::PyObject> {
+        self.to_python_keyed::<true>()
+    }
+
+    fn to_python_keyed<const SNAKE_CASE: bool>(self) -> NonNull<crate::ffi::PyObject> {
+        let val = self.val;
         if !unsafe_yyjson_is_ctn(val) {
             cold_path!();
             match ElementType::from_tag(val) {
@@ -131,21 +398,212 @@ pub(crate) fn deserialize(
         } else if is_yyjson_tag!(val, TAG_ARRAY) {
             let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
             if unsafe_yyjson_get_len(val) > 0 {
-                populate_yy_array(pyval.clone(), val);
+                populate_yy_array::<SNAKE_CASE>(pyval.clone(), val);
             }
             pyval.as_non_null_ptr()
         } else {
             let pyval = PyDictRef::with_capacity(unsafe_yyjson_get_len(val));
             if unsafe_yyjson_get_len(val) > 0 {
-                populate_yy_object(pyval.clone(), val);
+                populate_yy_object::<SNAKE_CASE>(pyval.clone(), val);
             }
             pyval.as_non_null_ptr()
         }
-    };
+    }
+
+    /// Convert the value to Python objects, skipping the members of objects
+    /// not in `projection`. Members are matched before their keys are
+    /// converted to snake_case.
+    pub fn to_python_projected<const SNAKE_CASE: bool>(
+        self,
+        projection: &Projection,
+    ) -> NonNull<crate::ffi::PyObject> {
+        let val = self.val;
+        if projection.is_all() || !unsafe_yyjson_is_ctn(val) {
+            self.to_python_keyed::<SNAKE_CASE>()
+        } else if is_yyjson_tag!(val, TAG_ARRAY) {
+            let mut pyval = PyListRef::with_capacity(self.len());
+            for (idx, each) in self.elements().enumerate() {
+                pyval.set(
+                    idx,
+                    each.to_python_projected::<SNAKE_CASE>(projection).as_ptr(),
+                );
+            }
+            pyval.as_non_null_ptr()
+        } else {
+            let mut pyval = PyDictRef::new();
+            for (key, each) in self.members() {
+                if let Some(nested) = projection.get(key) {
+                    let pyitem = each.to_python_projected::<SNAKE_CASE>(nested);
+                    let pykey = if SNAKE_CASE {
+                        get_snake_case_key(key)
+                    } else {
+                        get_unicode_key(key)
+                    };
+                    pyval.set(pykey, pyitem.as_ptr());
+                }
+            }
+            pyval.as_non_null_ptr()
+        }
+    }
+
+    /// Return an error if two different keys of an object that are
+    /// deserialized, as selected by `projection`, are the same converted to
+    /// snake_case, which `to_python_snake_case()` would otherwise merge.
+    /// `root` is the root of the document `data`, which locates the key.
+    pub fn check_snake_case_keys<'a>(
+        self,
+        root: Value<'p>,
+        projection: &Projection,
+        data: &'a str,
+    ) -> Result<(), DeserializeError<'a>> {
+        if self.is_array() {
+            for each in self.elements() {
+                each.check_snake_case_keys(root, projection, data)?;
+            }
+        } else if self.is_object() {
+            let mut changed = false;
+            for (key, each) in self.members() {
+                if let Some(nested) = projection.get(key) {
+                    changed |= changes_case(key, SNAKE_CASE_KEYS);
+                    each.check_snake_case_keys(root, nested, data)?;
+                }
+            }
+            if changed {
+                cold_path!();
+                self.check_converted_keys(root, projection, data)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn check_converted_keys<'a>(
+        self,
+        root: Value<'p>,
+        projection: &Projection,
+        data: &'a str,
+    ) -> Result<(), DeserializeError<'a>> {
+        let mut seen: HashMap<Cow<'p, str>, &'p str> = HashMap::with_capacity(self.len());
+        for (key, _) in self.members() {
+            if projection.get(key).is_none() {
+                continue;
+            }
+            let converted = if changes_case(key, SNAKE_CASE_KEYS) {
+                Cow::Owned(convert_case(key, SNAKE_CASE_KEYS))
+            } else {
+                Cow::Borrowed(key)
+            };
+            match seen.entry(converted) {
+                Entry::Occupied(entry) if *entry.get() != key => {
+                    return Err(DeserializeError::from_yyjson(
+                        Cow::Borrowed("Key converted to snake_case collides with another key"),
+                        key_offset(root, key),
+                        data,
+                    ));
+                }
+                Entry::Occupied(_) => {}
+                Entry::Vacant(entry) => {
+                    entry.insert(key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Return the offset in the document of the opening quote of `key`, a key
+/// in the document whose root is `root`. yyjson copies the document and
+/// unescapes each string in place in the copy, so a string begins at the
+/// same offset in the copy as in the document.
+#[cold]
+#[allow(clippy::cast_possible_wrap)]
+fn key_offset(root: Value<'_>, key: &str) -> i64 {
     unsafe {
-        PyMem_Free(buffer_ptr);
+        let doc = root.val.sub(YYJSON_DOC_LEN).cast::<yyjson_doc>();
+        debug_assert!(core::ptr::eq((*doc).root, root.val));
+        let text = (*doc).str_pool.cast::<u8>().cast_const();
+        (key.as_ptr().offset_from(text) - 1) as i64
     }
-    Ok(pyval)
+}
+
+pub(crate) struct Elements<'p> {
+    next: *mut yyjson_val,
+    remaining: usize,
+    _pool: PhantomData<&'p PoolBuffer>,
+}
+
+impl<'p> Iterator for Elements<'p> {
+    type Item = Value<'p>;
+
+    fn next(&mut self) -> Option<Value<'p>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let val = self.next;
+        self.next = next_sibling(val);
+        Some(Value::from_val(val))
+    }
+}
+
+pub(crate) struct Members<'p> {
+    next: *mut yyjson_val,
+    remaining: usize,
+    _pool: PhantomData<&'p PoolBuffer>,
+}
+
+impl<'p> Iterator for Members<'p> {
+    type Item = (&'p str, Value<'p>);
+
+    fn next(&mut self) -> Option<(&'p str, Value<'p>)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let key = self.next;
+        let val = unsafe { key.add(1) };
+        self.next = next_sibling(val);
+        Some((yy_str(key), Value::from_val(val)))
+    }
+}
+
+/// Document parsed by yyjson that owns its pool so that its values may be
+/// converted to Python objects after parsing.
+pub(crate) struct Document {
+    _pool: PoolBuffer,
+    root: *mut yyjson_val,
+}
+
+// the document is not modified after parsing
+unsafe impl Send for Document {}
+unsafe impl Sync for Document {}
+
+impl Document {
+    pub fn parse(data: &str) -> Result<Self, DeserializeError<'_>> {
+        let mut pool = PoolBuffer::new();
+        let root = read(data, &mut pool)?.val;
+        Ok(Document {
+            _pool: pool,
+            root: root,
+        })
+    }
+
+    pub fn root(&self) -> Value<'_> {
+        Value::from_val(self.root)
+    }
+}
+
+fn next_sibling(val: *mut yyjson_val) -> *mut yyjson_val {
+    if unsafe_yyjson_is_ctn(val) {
+        unsafe_yyjson_get_next_container(val)
+    } else {
+        unsafe_yyjson_get_next_non_container(val)
+    }
+}
+
+fn yy_str(val: *mut yyjson_val) -> &'static str {
+    str_from_slice!((*val).uni.str_.cast::<u8>(), unsafe_yyjson_get_len(val))
 }
 
 enum ElementType {
@@ -202,7 +660,7 @@ fn parse_yy_f64(elem: *mut yyjson_val) -> NonNull<crate::ffi::PyObject> {
 }
 
 #[inline(never)]
-fn populate_yy_array(mut list: PyListRef, elem: *mut yyjson_val) {
+fn populate_yy_array<const SNAKE_CASE: bool>(mut list: PyListRef, elem: *mut yyjson_val) {
     unsafe {
         let len = unsafe_yyjson_get_len(elem);
         assume!(len >= 1);
@@ -217,13 +675,13 @@ fn populate_yy_array(mut list: PyListRef, elem: *mut yyjson_val) {
                     let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
                     list.set(idx, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_array(pyval.clone(), val);
+                        populate_yy_array::<SNAKE_CASE>(pyval.clone(), val);
                     }
                 } else {
                     let pyval = PyDictRef::with_capacity(unsafe_yyjson_get_len(val));
                     list.set(idx, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_object(pyval.clone(), val);
+                        populate_yy_object::<SNAKE_CASE>(pyval.clone(), val);
                     }
                 }
             } else {
@@ -245,7 +703,7 @@ fn populate_yy_array(mut list: PyListRef, elem: *mut yyjson_val) {
 }
 
 #[inline(never)]
-fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
+fn populate_yy_object<const SNAKE_CASE: bool>(mut dict: PyDictRef, elem: *mut yyjson_val) {
     unsafe {
         let len = unsafe_yyjson_get_len(elem);
         assume!(len >= 1);
@@ -258,7 +716,11 @@ fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
                     (*next_key).uni.str_.cast::<u8>(),
                     unsafe_yyjson_get_len(next_key)
                 );
-                get_unicode_key(key_str)
+                if SNAKE_CASE {
+                    get_snake_case_key(key_str)
+                } else {
+                    get_unicode_key(key_str)
+                }
             };
             if unsafe_yyjson_is_ctn(val) {
                 cold_path!();
@@ -268,13 +730,13 @@ fn populate_yy_object(mut dict: PyDictRef, elem: *mut yyjson_val) {
                     let pyval = PyListRef::with_capacity(unsafe_yyjson_get_len(val));
                     dict.set(pykey, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_array(pyval, val);
+                        populate_yy_array::<SNAKE_CASE>(pyval, val);
                     }
                 } else {
                     let pyval = PyDictRef::with_capacity(unsafe_yyjson_get_len(val));
                     dict.set(pykey, pyval.as_ptr());
                     if unsafe_yyjson_get_len(val) > 0 {
-                        populate_yy_object(pyval.clone(), val);
+                        populate_yy_object::<SNAKE_CASE>(pyval.clone(), val);
                     }
                 }
             } else {
@@ -38,3 +38,7 @@ pub(crate) type KeyMap =
     AssociativeCache<u64, CachedKey, Capacity2048, HashDirectMapped, RoundRobinReplacement>;
 
 pub(crate) static mut KEY_MAP: OnceCell<KeyMap> = OnceCell::new();
+
+/// Keys converted to snake_case for `OPT_SNAKE_CASE_KEYS`, keyed by the
+/// hash of the key before it is converted.
+pub(crate) static mut SNAKE_CASE_KEY_MAP: OnceCell<KeyMap> = OnceCell::new();
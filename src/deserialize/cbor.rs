@@ -0,0 +1,797 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+// https://www.rfc-editor.org/rfc/rfc8949
+
+use crate::deserialize::DeserializeError;
+use crate::deserialize::input::binary_from_pyobject;
+use crate::deserialize::pyobject::{datetime_from_parts, datetime_from_timestamp, get_unicode_key};
+use crate::ffi::{
+    _PyLong_FromByteArray, Py_DECREF, Py_INCREF, Py_SIZE, Py_TYPE, PyBoolRef,
+    PyBytes_FromStringAndSize, PyDict_SetItem, PyDictRef, PyErr_Clear, PyErr_Occurred,
+    PyFloat_AS_DOUBLE, PyFloatRef, PyIntRef, PyList_Append, PyList_GET_ITEM, PyList_New, PyListRef,
+    PyLong_AsLongLong, PyLong_FromUnsignedLongLong, PyNoneRef, PyNumber_Invert, PyObject,
+    PyObject_Str, PyObject_Vectorcall, PyStrRef, is_valid_utf8,
+};
+use crate::serialize::writer::{
+    TAG_DATETIME, TAG_DECIMAL_FRACTION, TAG_EPOCH_DATETIME, TAG_MULTI_DIMENSIONAL_ARRAY,
+    TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM, TAG_UUID, f16_to_f32,
+};
+use crate::typeref::{
+    DECIMAL_TYPE, FLOAT_TYPE, INT_TYPE, LIST_TYPE, STR_TYPE, UUID_TYPE, load_decimal_type,
+};
+use crate::util::{isize_to_usize, usize_to_isize};
+use core::ptr::NonNull;
+use std::borrow::Cow;
+
+const RECURSION_LIMIT: usize = 1024;
+
+/// `decimal.MAX_EMAX` of a 64-bit build, beyond which `decimal.Decimal`
+/// raises.
+const DECIMAL_MAX_EXPONENT: u64 = 999_999_999_999_999_999;
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+
+/// Additional information of an item of indefinite length or, as major
+/// type 7, of the break that ends it.
+const INDEFINITE: u8 = 31;
+const BREAK: u8 = 0xff;
+
+type DecodeResult<T> = Result<T, DeserializeError<'static>>;
+
+struct Decoder {
+    data: &'static [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl Decoder {
+    #[cold]
+    #[inline(never)]
+    fn error<T>(&self, message: &'static str, pos: usize) -> DecodeResult<T> {
+        Err(DeserializeError::from_binary(Cow::Borrowed(message), pos))
+    }
+
+    #[inline]
+    fn read(&mut self, len: usize) -> DecodeResult<&'static [u8]> {
+        if self.data.len() - self.pos < len {
+            cold_path!();
+            return self.error("Unexpected end of data", self.data.len());
+        }
+        let buf = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(buf)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> DecodeResult<[u8; N]> {
+        let buf = self.read(N)?;
+        Ok(buf.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+
+    #[inline]
+    fn read_u8(&mut self) -> DecodeResult<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    #[inline]
+    fn peek(&self) -> DecodeResult<u8> {
+        match self.data.get(self.pos) {
+            Some(val) => Ok(*val),
+            None => self.error("Unexpected end of data", self.data.len()),
+        }
+    }
+
+    /// Read the argument of a head, which is `None` if the item is of
+    /// indefinite length.
+    #[inline]
+    fn read_argument(&mut self, info: u8, start: usize) -> DecodeResult<Option<u64>> {
+        match info {
+            0..=23 => Ok(Some(u64::from(info))),
+            24 => Ok(Some(u64::from(self.read_u8()?))),
+            25 => Ok(Some(u64::from(u16::from_be_bytes(self.read_array()?)))),
+            26 => Ok(Some(u64::from(u32::from_be_bytes(self.read_array()?)))),
+            27 => Ok(Some(u64::from_be_bytes(self.read_array()?))),
+            INDEFINITE => Ok(None),
+            _ => self.error("Invalid additional information", start),
+        }
+    }
+
+    #[inline]
+    fn read_definite(&mut self, info: u8, start: usize) -> DecodeResult<u64> {
+        match self.read_argument(info, start)? {
+            Some(val) => Ok(val),
+            None => self.error("Invalid indefinite length", start),
+        }
+    }
+
+    /// A length that does not fit in `usize` is more than the data anyway.
+    #[inline]
+    fn length(&self, len: u64) -> DecodeResult<usize> {
+        match usize::try_from(len) {
+            Ok(len) => Ok(len),
+            Err(_) => self.error("Unexpected end of data", self.data.len()),
+        }
+    }
+
+    fn decode(&mut self) -> DecodeResult<NonNull<PyObject>> {
+        let start = self.pos;
+        let initial = self.read_u8()?;
+        let info = initial & 0x1f;
+        match initial >> 5 {
+            UNSIGNED => {
+                let val = self.read_definite(info, start)?;
+                Ok(PyIntRef::from_u64(val).as_non_null_ptr())
+            }
+            NEGATIVE => {
+                let val = self.read_definite(info, start)?;
+                Ok(negative_integer(val))
+            }
+            BYTES => {
+                let buf = self.read_string(BYTES, info, start)?;
+                Ok(new_bytes(&buf))
+            }
+            TEXT => {
+                let buf = self.read_text(info, start)?;
+                Ok(PyStrRef::from_str(&buf).as_non_null_ptr())
+            }
+            ARRAY => self.decode_array(info, start),
+            MAP => self.decode_map(info, start),
+            TAG => {
+                let tag = self.read_definite(info, start)?;
+                self.decode_tag(tag, start)
+            }
+            _ => self.decode_simple(info, start),
+        }
+    }
+
+    /// Read a byte or text string, whose chunks, if it is of indefinite
+    /// length, are joined.
+    fn read_string(
+        &mut self,
+        major: u8,
+        info: u8,
+        start: usize,
+    ) -> DecodeResult<Cow<'static, [u8]>> {
+        if let Some(len) = self.read_argument(info, start)? {
+            let len = self.length(len)?;
+            return Ok(Cow::Borrowed(self.read(len)?));
+        }
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            let chunk_start = self.pos;
+            let initial = self.read_u8()?;
+            if initial == BREAK {
+                break;
+            }
+            if initial >> 5 != major || initial & 0x1f == INDEFINITE {
+                cold_path!();
+                return self.error("Invalid chunk of indefinite-length string", chunk_start);
+            }
+            let len = self.read_definite(initial & 0x1f, chunk_start)?;
+            let len = self.length(len)?;
+            buf.extend_from_slice(self.read(len)?);
+        }
+        Ok(Cow::Owned(buf))
+    }
+
+    fn read_text(&mut self, info: u8, start: usize) -> DecodeResult<Cow<'static, str>> {
+        match self.read_string(TEXT, info, start)? {
+            Cow::Borrowed(buf) => {
+                if !is_valid_utf8(buf) {
+                    cold_path!();
+                    return self.error("str is not valid UTF-8", start);
+                }
+                Ok(Cow::Borrowed(unsafe {
+                    core::str::from_utf8_unchecked(buf)
+                }))
+            }
+            Cow::Owned(buf) => {
+                if !is_valid_utf8(&buf) {
+                    cold_path!();
+                    return self.error("str is not valid UTF-8", start);
+                }
+                Ok(Cow::Owned(unsafe { String::from_utf8_unchecked(buf) }))
+            }
+        }
+    }
+
+    /// Read the head of the content of a tag, which must be of `major`.
+    #[inline]
+    fn read_content_head(&mut self, major: u8, start: usize) -> DecodeResult<u8> {
+        let initial = self.read_u8()?;
+        if initial >> 5 != major {
+            cold_path!();
+            return self.error("Invalid content of tag", start);
+        }
+        Ok(initial & 0x1f)
+    }
+
+    /// Map keys that are not definite-length text strings are decoded as any
+    /// other item and must then be `str` or `int`.
+    fn decode_key(&mut self) -> DecodeResult<NonNull<PyObject>> {
+        let start = self.pos;
+        let initial = self.peek()?;
+        if initial >> 5 == TEXT && initial & 0x1f != INDEFINITE {
+            self.pos += 1;
+            let len = self.read_definite(initial & 0x1f, start)?;
+            let len = self.length(len)?;
+            let buf = self.read(len)?;
+            if !is_valid_utf8(buf) {
+                cold_path!();
+                return self.error("str is not valid UTF-8", start);
+            }
+            let key = get_unicode_key(unsafe { core::str::from_utf8_unchecked(buf) });
+            return Ok(key.as_non_null_ptr());
+        }
+        let key = self.decode()?;
+        let ob_type = unsafe { Py_TYPE(key.as_ptr()) };
+        if !(is_type!(ob_type, STR_TYPE) || is_type!(ob_type, INT_TYPE)) {
+            cold_path!();
+            unsafe {
+                Py_DECREF(key.as_ptr());
+            }
+            return self.error("Map key must be str or int", start);
+        }
+        Ok(key)
+    }
+
+    #[inline]
+    fn enter(&mut self, len: usize, min_size: usize, start: usize) -> DecodeResult<()> {
+        if len > (self.data.len() - self.pos) / min_size {
+            cold_path!();
+            return self.error("Unexpected end of data", self.data.len());
+        }
+        if self.depth == RECURSION_LIMIT {
+            cold_path!();
+            return self.error("Recursion limit reached", start);
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn decode_array(&mut self, info: u8, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let Some(len) = self.read_argument(info, start)? else {
+            return self.decode_indefinite_array(start);
+        };
+        let len = self.length(len)?;
+        self.enter(len, 1, start)?;
+        let mut list = PyListRef::with_capacity(len);
+        for idx in 0..len {
+            match self.decode() {
+                Ok(val) => list.set(idx, val.as_ptr()),
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(list.as_ptr());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(list.as_non_null_ptr())
+    }
+
+    #[cold]
+    fn decode_indefinite_array(&mut self, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        self.enter(0, 1, start)?;
+        let list = unsafe { PyList_New(0) };
+        debug_assert!(!list.is_null());
+        loop {
+            if self.peek()? == BREAK {
+                self.pos += 1;
+                break;
+            }
+            match self.decode() {
+                Ok(val) => unsafe {
+                    PyList_Append(list, val.as_ptr());
+                    Py_DECREF(val.as_ptr());
+                },
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(list);
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(nonnull!(list))
+    }
+
+    fn decode_map(&mut self, info: u8, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let len = match self.read_argument(info, start)? {
+            Some(len) => Some(self.length(len)?),
+            None => None,
+        };
+        self.enter(len.unwrap_or(0), 2, start)?;
+        let dict = PyDictRef::with_capacity(len.unwrap_or(0));
+        let mut idx = 0;
+        loop {
+            match len {
+                Some(len) if idx == len => break,
+                None if self.peek()? == BREAK => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => idx += 1,
+            }
+            let entry = self.decode_key().and_then(|key| match self.decode() {
+                Ok(val) => Ok((key, val)),
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(key.as_ptr());
+                    }
+                    Err(err)
+                }
+            });
+            match entry {
+                Ok((key, val)) => unsafe {
+                    PyDict_SetItem(dict.as_ptr(), key.as_ptr(), val.as_ptr());
+                    Py_DECREF(key.as_ptr());
+                    Py_DECREF(val.as_ptr());
+                },
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(dict.as_ptr());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(dict.as_non_null_ptr())
+    }
+
+    fn decode_simple(&mut self, info: u8, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        match info {
+            20 => Ok(PyBoolRef::pyfalse().as_non_null_ptr()),
+            21 => Ok(PyBoolRef::pytrue().as_non_null_ptr()),
+            // null and undefined
+            22 | 23 => Ok(PyNoneRef::none().as_non_null_ptr()),
+            25 => {
+                let val = f16_to_f32(u16::from_be_bytes(self.read_array()?));
+                Ok(PyFloatRef::from_f64(f64::from(val)).as_non_null_ptr())
+            }
+            26 => {
+                let val = f32::from_be_bytes(self.read_array()?);
+                Ok(PyFloatRef::from_f64(f64::from(val)).as_non_null_ptr())
+            }
+            27 => {
+                let val = f64::from_be_bytes(self.read_array()?);
+                Ok(PyFloatRef::from_f64(val).as_non_null_ptr())
+            }
+            INDEFINITE => self.error("Unexpected break", start),
+            _ => self.error("Simple value is not supported", start),
+        }
+    }
+
+    /// Decode the content of the tag `tag`. The content of a tag that is not
+    /// supported is returned as it is.
+    fn decode_tag(&mut self, tag: u64, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        if self.depth == RECURSION_LIMIT {
+            cold_path!();
+            return self.error("Recursion limit reached", start);
+        }
+        self.depth += 1;
+        let val = match u32::try_from(tag) {
+            Ok(TAG_DATETIME) => self.decode_datetime(start),
+            Ok(TAG_EPOCH_DATETIME) => self.decode_epoch_datetime(start),
+            Ok(TAG_POSITIVE_BIGNUM) => self.decode_bignum(false, start),
+            Ok(TAG_NEGATIVE_BIGNUM) => self.decode_bignum(true, start),
+            Ok(TAG_DECIMAL_FRACTION) => self.decode_decimal(start),
+            Ok(TAG_UUID) => self.decode_uuid(start),
+            Ok(TAG_MULTI_DIMENSIONAL_ARRAY) => self.decode_multi_dimensional_array(start),
+            Ok(tag @ 64..=87) => self.decode_typed_array(tag, start),
+            _ => self.decode(),
+        }?;
+        self.depth -= 1;
+        Ok(val)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn decode_datetime(&mut self, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let info = self.read_content_head(TEXT, start)?;
+        let buf = self.read_text(info, start)?;
+        match parse_datetime(buf.as_bytes()) {
+            Some(val) => Ok(val),
+            None => self.error("Invalid date/time string", start),
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn decode_epoch_datetime(&mut self, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let content = self.decode()?;
+        let ptr = content.as_ptr();
+        let ob_type = unsafe { Py_TYPE(ptr) };
+        let timestamp = if is_type!(ob_type, INT_TYPE) {
+            let val = unsafe { PyLong_AsLongLong(ptr) };
+            if val == -1 && unsafe { !PyErr_Occurred().is_null() } {
+                unsafe {
+                    PyErr_Clear();
+                }
+                None
+            } else {
+                jiff::Timestamp::from_second(val).ok()
+            }
+        } else if is_type!(ob_type, FLOAT_TYPE) {
+            let val = (unsafe { PyFloat_AS_DOUBLE(ptr) } * 1_000_000.0).round();
+            if val.is_finite() && (-9.2e18..9.2e18).contains(&val) {
+                #[allow(clippy::cast_possible_truncation)]
+                jiff::Timestamp::from_microsecond(val as i64).ok()
+            } else {
+                None
+            }
+        } else {
+            unsafe {
+                Py_DECREF(ptr);
+            }
+            return self.error("Invalid content of tag", start);
+        };
+        unsafe {
+            Py_DECREF(ptr);
+        }
+        match timestamp.and_then(datetime_from_timestamp) {
+            Some(val) => Ok(val),
+            None => self.error("Timestamp is out of range of datetime", start),
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn decode_bignum(&mut self, negative: bool, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let info = self.read_content_head(BYTES, start)?;
+        let buf = self.read_string(BYTES, info, start)?;
+        unsafe {
+            let val = _PyLong_FromByteArray(buf.as_ptr(), buf.len(), 0, 0);
+            debug_assert!(!val.is_null());
+            if negative {
+                let inverted = PyNumber_Invert(val);
+                Py_DECREF(val);
+                return Ok(nonnull!(inverted));
+            }
+            Ok(nonnull!(val))
+        }
+    }
+
+    /// A decimal fraction is `decimal.Decimal` of "{mantissa}E{exponent}".
+    #[cold]
+    #[inline(never)]
+    fn decode_decimal(&mut self, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let content = self.decode()?;
+        let ptr = content.as_ptr();
+        let parts = unsafe {
+            if is_type!(Py_TYPE(ptr), LIST_TYPE)
+                && Py_SIZE(ptr) == 2
+                && is_type!(Py_TYPE(PyList_GET_ITEM(ptr, 0)), INT_TYPE)
+                && is_type!(Py_TYPE(PyList_GET_ITEM(ptr, 1)), INT_TYPE)
+            {
+                let exponent = PyObject_Str(PyList_GET_ITEM(ptr, 0));
+                let mantissa = PyObject_Str(PyList_GET_ITEM(ptr, 1));
+                let parts = if exponent.is_null() || mantissa.is_null() {
+                    // sys.get_int_max_str_digits()
+                    PyErr_Clear();
+                    None
+                } else {
+                    PyStrRef::from_ptr_unchecked(mantissa)
+                        .as_str()
+                        .zip(PyStrRef::from_ptr_unchecked(exponent).as_str())
+                        .filter(|(_, exponent)| {
+                            exponent
+                                .trim_start_matches('-')
+                                .parse::<u64>()
+                                .is_ok_and(|val| val <= DECIMAL_MAX_EXPONENT)
+                        })
+                        .map(|(mantissa, exponent)| format!("{mantissa}E{exponent}"))
+                };
+                crate::ffi::Py_XDECREF(exponent);
+                crate::ffi::Py_XDECREF(mantissa);
+                parts
+            } else {
+                None
+            }
+        };
+        unsafe {
+            Py_DECREF(ptr);
+        }
+        let Some(parts) = parts else {
+            return self.error("Invalid content of tag", start);
+        };
+        unsafe {
+            let decimal_type = DECIMAL_TYPE.get_or_init(load_decimal_type);
+            let arg = PyStrRef::from_str(&parts).as_ptr();
+            let val = PyObject_Vectorcall(
+                decimal_type.as_ptr().cast::<PyObject>(),
+                &raw const arg,
+                1,
+                core::ptr::null_mut(),
+            );
+            Py_DECREF(arg);
+            if val.is_null() {
+                PyErr_Clear();
+                return self.error("Invalid content of tag", start);
+            }
+            Ok(nonnull!(val))
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn decode_uuid(&mut self, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let info = self.read_content_head(BYTES, start)?;
+        let buf = self.read_string(BYTES, info, start)?;
+        if buf.len() != 16 {
+            return self.error("Invalid content of tag", start);
+        }
+        let hex = buf
+            .iter()
+            .map(|val| format!("{val:02x}"))
+            .collect::<String>();
+        unsafe {
+            let arg = PyStrRef::from_str(&hex).as_ptr();
+            let val = PyObject_Vectorcall(
+                UUID_TYPE.cast::<PyObject>(),
+                &raw const arg,
+                1,
+                core::ptr::null_mut(),
+            );
+            Py_DECREF(arg);
+            if val.is_null() {
+                PyErr_Clear();
+                return self.error("Invalid content of tag", start);
+            }
+            Ok(nonnull!(val))
+        }
+    }
+
+    /// A typed array of RFC 8746 is a `list` of its items.
+    #[cold]
+    #[inline(never)]
+    fn decode_typed_array(&mut self, tag: u32, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let float = tag & 0b10000 != 0;
+        let signed = tag & 0b1000 != 0;
+        let little_endian = tag & 0b100 != 0;
+        let size_bits = tag & 0b11;
+        // 76 is reserved, as uint8 clamped is 68; 83 and 87 are float128
+        if (!float && size_bits == 0 && signed && little_endian) || (float && size_bits == 3) {
+            return self.error("Typed array is not supported", start);
+        }
+        let size = if float {
+            2 << size_bits
+        } else {
+            1 << size_bits
+        };
+        let info = self.read_content_head(BYTES, start)?;
+        let buf = self.read_string(BYTES, info, start)?;
+        if buf.len() % size != 0 {
+            return self.error("Invalid content of tag", start);
+        }
+        let mut list = PyListRef::with_capacity(buf.len() / size);
+        for (idx, chunk) in buf.chunks_exact(size).enumerate() {
+            let mut bytes = [0u8; 8];
+            if little_endian {
+                bytes[8 - size..].copy_from_slice(chunk);
+                bytes[8 - size..].reverse();
+            } else {
+                bytes[8 - size..].copy_from_slice(chunk);
+            }
+            let bits = u64::from_be_bytes(bytes);
+            #[allow(clippy::cast_possible_truncation)]
+            let val = if float {
+                let val = match size {
+                    2 => f64::from(f16_to_f32(bits as u16)),
+                    4 => f64::from(f32::from_bits(bits as u32)),
+                    _ => f64::from_bits(bits),
+                };
+                PyFloatRef::from_f64(val).as_non_null_ptr().as_ptr()
+            } else if signed {
+                let shift = 64 - 8 * size;
+                PyIntRef::from_i64((bits << shift).cast_signed() >> shift).as_ptr()
+            } else {
+                PyIntRef::from_u64(bits).as_ptr()
+            };
+            list.set(idx, val);
+        }
+        Ok(list.as_non_null_ptr())
+    }
+
+    /// A multi-dimensional array of its shape and items in row-major order
+    /// is nested `list`.
+    #[cold]
+    #[inline(never)]
+    fn decode_multi_dimensional_array(&mut self, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let content = self.decode()?;
+        let ptr = content.as_ptr();
+        let shape = unsafe { parse_shape(ptr) };
+        let val = match shape {
+            Some(shape) if shape.len() + self.depth > RECURSION_LIMIT => {
+                unsafe {
+                    Py_DECREF(ptr);
+                }
+                return self.error("Recursion limit reached", start);
+            }
+            Some(shape) => {
+                let mut offset = 0;
+                unsafe { reshape(PyList_GET_ITEM(ptr, 1), &shape, &mut offset) }
+            }
+            None => {
+                unsafe {
+                    Py_DECREF(ptr);
+                }
+                return self.error("Invalid content of tag", start);
+            }
+        };
+        unsafe {
+            Py_DECREF(ptr);
+        }
+        Ok(nonnull!(val))
+    }
+}
+
+/// The integer -1 - `val`.
+#[inline]
+fn negative_integer(val: u64) -> NonNull<PyObject> {
+    match i64::try_from(val) {
+        Ok(val) => PyIntRef::from_i64(-1 - val).as_non_null_ptr(),
+        Err(_) => unsafe {
+            let magnitude = PyLong_FromUnsignedLongLong(val);
+            let val = PyNumber_Invert(magnitude);
+            Py_DECREF(magnitude);
+            nonnull!(val)
+        },
+    }
+}
+
+#[inline]
+fn new_bytes(buf: &[u8]) -> NonNull<PyObject> {
+    let ptr = unsafe {
+        PyBytes_FromStringAndSize(
+            buf.as_ptr().cast::<core::ffi::c_char>(),
+            usize_to_isize(buf.len()),
+        )
+    };
+    debug_assert!(!ptr.is_null());
+    nonnull!(ptr)
+}
+
+/// Parse an RFC 3339 date-time, e.g., "2013-03-21T20:04:00+01:00".
+fn parse_datetime(buf: &[u8]) -> Option<NonNull<PyObject>> {
+    fn digits(buf: &[u8]) -> Option<i32> {
+        buf.iter().try_fold(0, |acc, val| {
+            val.is_ascii_digit()
+                .then(|| acc * 10 + i32::from(val - b'0'))
+        })
+    }
+    if buf.len() < 20
+        || buf[4] != b'-'
+        || buf[7] != b'-'
+        || !matches!(buf[10], b'T' | b't')
+        || buf[13] != b':'
+        || buf[16] != b':'
+    {
+        return None;
+    }
+    let mut microsecond = 0;
+    let mut rest = &buf[19..];
+    if rest[0] == b'.' {
+        let len = rest[1..]
+            .iter()
+            .take_while(|val| val.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return None;
+        }
+        let fraction = &rest[1..=len.min(6)];
+        microsecond = digits(fraction)?;
+        for _ in fraction.len()..6 {
+            microsecond *= 10;
+        }
+        rest = &rest[len + 1..];
+    }
+    let offset = match rest {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), hour @ .., b':', minute_0, minute_1] if hour.len() == 2 => {
+            let hours = digits(hour)?;
+            let minutes = digits(&[*minute_0, *minute_1])?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let val = hours * 3600 + minutes * 60;
+            if *sign == b'-' { -val } else { val }
+        }
+        _ => return None,
+    };
+    datetime_from_parts(
+        digits(&buf[0..4])?,
+        digits(&buf[5..7])?,
+        digits(&buf[8..10])?,
+        digits(&buf[11..13])?,
+        digits(&buf[14..16])?,
+        digits(&buf[17..19])?,
+        microsecond,
+        offset,
+    )
+}
+
+/// The shape of `[shape, items]` if it is a multi-dimensional array whose
+/// shape is non-negative `int` of the number of items.
+unsafe fn parse_shape(ptr: *mut PyObject) -> Option<Vec<usize>> {
+    unsafe {
+        if !is_type!(Py_TYPE(ptr), LIST_TYPE) || Py_SIZE(ptr) != 2 {
+            return None;
+        }
+        let shape = PyList_GET_ITEM(ptr, 0);
+        let items = PyList_GET_ITEM(ptr, 1);
+        if !is_type!(Py_TYPE(shape), LIST_TYPE)
+            || !is_type!(Py_TYPE(items), LIST_TYPE)
+            || Py_SIZE(shape) == 0
+        {
+            return None;
+        }
+        let mut dimensions = Vec::with_capacity(isize_to_usize(Py_SIZE(shape)));
+        let mut len: usize = 1;
+        for idx in 0..Py_SIZE(shape) {
+            let dimension = PyList_GET_ITEM(shape, idx);
+            if !is_type!(Py_TYPE(dimension), INT_TYPE) {
+                return None;
+            }
+            let val = PyLong_AsLongLong(dimension);
+            if val < 0 {
+                PyErr_Clear();
+                return None;
+            }
+            let val = usize::try_from(val).ok()?;
+            len = len.checked_mul(val)?;
+            dimensions.push(val);
+        }
+        (len == isize_to_usize(Py_SIZE(items))).then_some(dimensions)
+    }
+}
+
+/// Nest the items of `items` from `offset` in `list` of `shape`.
+unsafe fn reshape(items: *mut PyObject, shape: &[usize], offset: &mut usize) -> *mut PyObject {
+    unsafe {
+        let mut list = PyListRef::with_capacity(shape[0]);
+        for idx in 0..shape[0] {
+            let val = if shape.len() == 1 {
+                let val = PyList_GET_ITEM(items, usize_to_isize(*offset));
+                Py_INCREF(val);
+                *offset += 1;
+                val
+            } else {
+                reshape(items, &shape[1..], offset)
+            };
+            list.set(idx, val);
+        }
+        list.as_ptr()
+    }
+}
+
+/// Deserialize the CBOR document in `ptr` to Python objects.
+pub(crate) fn deserialize_cbor(
+    ptr: *mut PyObject,
+) -> Result<NonNull<PyObject>, DeserializeError<'static>> {
+    let data = binary_from_pyobject(ptr)?;
+    let mut decoder = Decoder {
+        data: data,
+        pos: 0,
+        depth: 0,
+    };
+    let obj = decoder.decode()?;
+    if decoder.pos != data.len() {
+        cold_path!();
+        unsafe {
+            Py_DECREF(obj.as_ptr());
+        }
+        return decoder.error("Trailing data", decoder.pos);
+    }
+    Ok(obj)
+}
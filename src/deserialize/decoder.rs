@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::input::bytes_from_pyobject;
+use super::stream::StreamDecoder;
+use crate::exception::{raise_args_exception, raise_loads_exception};
+use crate::ffi::{
+    Py_TPFLAGS_DEFAULT, Py_TPFLAGS_IMMUTABLETYPE, Py_tp_dealloc, Py_tp_new, PyMethodDef,
+    PyMethodDefPointer, PyObject, PyTupleRef, PyType_FromSpec, PyType_Slot, PyType_Spec,
+    PyTypeObject,
+};
+use core::ffi::c_void;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::borrow::Cow;
+
+#[cfg(Py_GIL_DISABLED)]
+use core::sync::atomic::{AtomicIsize, AtomicU32};
+
+#[cfg(Py_GIL_DISABLED)]
+macro_rules! pymutex_new {
+    () => {
+        unsafe { core::mem::zeroed() }
+    };
+}
+
+#[repr(C)]
+pub(crate) struct Decoder {
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_tid: usize,
+    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+    pub ob_flags: u16,
+    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+    pub _padding: u16,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_mutex: pyo3_ffi::PyMutex,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_gc_bits: u8,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_local: AtomicU32,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_shared: AtomicIsize,
+    #[cfg(not(Py_GIL_DISABLED))]
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    #[cfg(PyPy)]
+    pub ob_pypy_link: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    state: StreamDecoder,
+    closed: bool,
+    busy: AtomicBool,
+}
+
+#[cold]
+fn raise_decoder_state_exception(msg: &'static str) -> *mut PyObject {
+    raise_loads_exception(DeserializeError::invalid(Cow::Borrowed(msg)))
+}
+
+impl Decoder {
+    /// Feed `chunk` to the decoder. This is not reentrant and raises if
+    /// called while another call on the same object is in progress.
+    fn call(&mut self, chunk: &[u8], last: bool) -> *mut PyObject {
+        if self.closed {
+            cold_path!();
+            if last {
+                return crate::ffi::PyListRef::with_capacity(0).as_ptr();
+            }
+            return raise_decoder_state_exception("Decoder is closed");
+        }
+        if self.busy.swap(true, Ordering::Acquire) {
+            cold_path!();
+            return raise_decoder_state_exception("Decoder is already in use");
+        }
+        let ret = match self.state.feed(chunk, last) {
+            Ok(list) => list.as_ptr(),
+            Err(err) => {
+                cold_path!();
+                self.closed = true;
+                raise_loads_exception(err)
+            }
+        };
+        if last || self.closed {
+            self.closed = true;
+            self.state = StreamDecoder::new();
+        }
+        self.busy.store(false, Ordering::Release);
+        ret
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_decoder_tp_new(
+    _subtype: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwds: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let argsob = PyTupleRef::from_ptr_unchecked(args);
+        if argsob.len() != 0 || (!kwds.is_null() && crate::ffi::PyDict_Size(kwds) != 0) {
+            return raise_args_exception("orjson.Decoder() takes no arguments");
+        }
+        let obj = Box::new(Decoder {
+            #[cfg(Py_GIL_DISABLED)]
+            ob_tid: 0,
+            #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+            ob_flags: 0,
+            #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+            _padding: 0,
+            #[cfg(Py_GIL_DISABLED)]
+            ob_mutex: pymutex_new!(),
+            #[cfg(Py_GIL_DISABLED)]
+            ob_gc_bits: 0,
+            #[cfg(Py_GIL_DISABLED)]
+            ob_ref_local: AtomicU32::new(0),
+            #[cfg(Py_GIL_DISABLED)]
+            ob_ref_shared: AtomicIsize::new(0),
+            #[cfg(not(Py_GIL_DISABLED))]
+            ob_refcnt: 1,
+            #[cfg(PyPy)]
+            ob_pypy_link: 0,
+            ob_type: crate::typeref::DECODER_TYPE,
+            state: StreamDecoder::new(),
+            closed: false,
+            busy: AtomicBool::new(false),
+        });
+        Box::into_raw(obj).cast::<PyObject>()
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_decoder_dealloc(object: *mut PyObject) {
+    unsafe {
+        drop(Box::from_raw(object.cast::<Decoder>()));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_decoder_feed(
+    object: *mut PyObject,
+    chunk: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        match bytes_from_pyobject(chunk) {
+            Ok(chunk) => (*object.cast::<Decoder>()).call(chunk, false),
+            Err(err) => raise_loads_exception(err),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_decoder_close(
+    object: *mut PyObject,
+    _args: *mut PyObject,
+) -> *mut PyObject {
+    unsafe { (*object.cast::<Decoder>()).call(&[], true) }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_decodertype_new() -> *mut PyTypeObject {
+    unsafe {
+        let methods = Box::new([
+            PyMethodDef {
+                ml_name: c"feed".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_decoder_feed,
+                },
+                ml_flags: crate::ffi::METH_O,
+                ml_doc: c"feed(chunk, /)\n--\n\nBuffer chunk and return a list of the JSON documents it completes.".as_ptr(),
+            },
+            PyMethodDef {
+                ml_name: c"close".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_decoder_close,
+                },
+                ml_flags: crate::ffi::METH_NOARGS,
+                ml_doc: c"close()\n--\n\nEnd the input and return a list of the remaining JSON documents.".as_ptr(),
+            },
+            PyMethodDef::zeroed(),
+        ]);
+        let mut slots = [
+            PyType_Slot {
+                slot: Py_tp_new,
+                pfunc: orjson_decoder_tp_new as *mut c_void,
+            },
+            PyType_Slot {
+                slot: Py_tp_dealloc,
+                pfunc: orjson_decoder_dealloc as *mut c_void,
+            },
+            PyType_Slot {
+                slot: crate::ffi::Py_tp_methods,
+                pfunc: Box::into_raw(methods).cast::<c_void>(),
+            },
+            PyType_Slot {
+                slot: crate::ffi::Py_tp_doc,
+                pfunc:
+                    c"Decoder()\n--\n\nIncrementally deserialize JSON documents given in chunks."
+                        .as_ptr()
+                        .cast_mut()
+                        .cast::<c_void>(),
+            },
+            PyType_Slot {
+                slot: 0,
+                pfunc: null_mut(),
+            },
+        ];
+        let mut spec = PyType_Spec {
+            name: c"orjson.Decoder".as_ptr(),
+            basicsize: core::mem::size_of::<Decoder>().cast_signed() as i32,
+            itemsize: 0,
+            flags: (Py_TPFLAGS_DEFAULT | Py_TPFLAGS_IMMUTABLETYPE) as u32,
+            slots: &raw mut slots[0],
+        };
+        PyType_FromSpec(&raw mut spec).cast::<PyTypeObject>()
+    }
+}
@@ -2,9 +2,15 @@
 // Copyright ijl (2024-2026)
 
 use super::DeserializeError;
+use super::backend::{PoolBuffer, Value, read};
 use super::input::Utf8Buffer;
-use crate::ffi::{PyDictRef, PyListRef, PyStrRef};
+use super::pointer::resolve;
+use super::projection::Projection;
+use crate::ffi::{PyDictRef, PyListRef, PyStrRef, PyTupleRef};
+use crate::opt::{Opt, SNAKE_CASE_KEYS};
+use crate::util::INVALID_STR;
 use core::ptr::NonNull;
+use std::borrow::Cow;
 
 #[repr(transparent)]
 pub struct Deserializer {
@@ -34,6 +40,14 @@ impl Deserializer {
         }
         crate::deserialize::backend::deserialize(self.buffer.as_str())
     }
+
+    #[cold]
+    #[inline(never)]
+    pub fn deserialize_snake_case(
+        &self,
+    ) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+        crate::deserialize::backend::deserialize_snake_case(self.buffer.as_str())
+    }
 }
 
 pub(crate) fn deserialize(
@@ -42,3 +56,90 @@ pub(crate) fn deserialize(
     let deserializer = Deserializer::from_pyobject(ptr)?;
     deserializer.deserialize()
 }
+
+/// Deserialize the document `ptr` as `deserialize()` does, with the options
+/// of `loads()`.
+pub(crate) fn deserialize_with_opts(
+    ptr: *mut crate::ffi::PyObject,
+    opts: Opt,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    let deserializer = Deserializer::from_pyobject(ptr)?;
+    if opt_enabled!(opts, SNAKE_CASE_KEYS) {
+        deserializer.deserialize_snake_case()
+    } else {
+        deserializer.deserialize()
+    }
+}
+
+/// Validate the document `ptr` by parsing it as `loads()` does, reporting
+/// the same error, without creating Python objects.
+pub(crate) fn validate(ptr: *mut crate::ffi::PyObject) -> Result<(), DeserializeError<'static>> {
+    let buffer = Utf8Buffer::from_pyobject(ptr)?;
+    let mut pool = PoolBuffer::new();
+    read(buffer.as_str(), &mut pool)?;
+    Ok(())
+}
+
+/// Deserialize only the value referred to by `pointer`, which is a `str`,
+/// or a `tuple` of the values referred to by each `str` in a `tuple`. If
+/// `pointer` is `None`, this is the whole document. Members of objects not
+/// in `projection` are skipped.
+pub(crate) fn deserialize_selected(
+    ptr: *mut crate::ffi::PyObject,
+    pointer: *mut crate::ffi::PyObject,
+    projection: &Projection,
+    opts: Opt,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    if opt_enabled!(opts, SNAKE_CASE_KEYS) {
+        deserialize_selected_keyed::<true>(ptr, pointer, projection)
+    } else {
+        deserialize_selected_keyed::<false>(ptr, pointer, projection)
+    }
+}
+
+fn deserialize_selected_keyed<const SNAKE_CASE: bool>(
+    ptr: *mut crate::ffi::PyObject,
+    pointer: *mut crate::ffi::PyObject,
+    projection: &Projection,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    let buffer = Utf8Buffer::from_pyobject(ptr)?;
+    let mut pool = PoolBuffer::new();
+    let root = read(buffer.as_str(), &mut pool)?;
+    let check = |val: Value<'_>| {
+        if SNAKE_CASE {
+            val.check_snake_case_keys(root, projection, buffer.as_str())
+        } else {
+            Ok(())
+        }
+    };
+    if core::ptr::eq(pointer, unsafe { crate::typeref::NONE }) {
+        check(root)?;
+        Ok(root.to_python_projected::<SNAKE_CASE>(projection))
+    } else if let Ok(pointer) = PyStrRef::from_ptr(pointer) {
+        let pointer = pointer
+            .as_str()
+            .ok_or_else(|| DeserializeError::invalid(Cow::Borrowed(INVALID_STR)))?;
+        let val = resolve(root, pointer)?;
+        check(val)?;
+        Ok(val.to_python_projected::<SNAKE_CASE>(projection))
+    } else {
+        let pointers = unsafe { PyTupleRef::from_ptr_unchecked(pointer) };
+        let mut values = Vec::with_capacity(pointers.len());
+        for idx in 0..pointers.len() {
+            let pointer = unsafe { PyStrRef::from_ptr_unchecked(pointers.get(idx)) }
+                .as_str()
+                .ok_or_else(|| DeserializeError::invalid(Cow::Borrowed(INVALID_STR)))?;
+            let val = resolve(root, pointer)?;
+            check(val)?;
+            values.push(val);
+        }
+        let mut ret = PyTupleRef::with_capacity(values.len());
+        for (idx, val) in values.iter().enumerate() {
+            ret.set(
+                idx,
+                val.to_python_projected::<SNAKE_CASE>(projection).as_ptr(),
+            );
+        }
+        Ok(ret.as_non_null_ptr())
+    }
+}
@@ -28,6 +28,31 @@ impl<'a> DeserializeError<'a> {
         }
     }
 
+    /// Make an error from deserializing binary data at the byte offset `pos`
+    #[cold]
+    pub fn from_binary(message: Cow<'a, str>, pos: usize) -> Self {
+        DeserializeError {
+            message: message,
+            data: None,
+            pos: crate::util::usize_to_isize(pos) as i64,
+        }
+    }
+
+    /// Make an error from parsing a slice of `data` at `offset` relative to
+    /// all of `data`
+    #[cold]
+    pub fn rebase(self, offset: usize, data: &'a str) -> Self {
+        match self.data {
+            #[allow(clippy::cast_possible_wrap)]
+            Some(_) => DeserializeError {
+                message: self.message,
+                data: Some(data),
+                pos: self.pos + offset as i64,
+            },
+            None => self,
+        }
+    }
+
     /// Return position of the error in the deserialized data
     #[cold]
     #[cfg_attr(feature = "optimize", optimize(size))]
@@ -40,7 +65,7 @@ impl<'a> DeserializeError<'a> {
                 let res = as_str[0..pos].chars().count() as i64; // stmt_expr_attributes
                 res
             }
-            None => 0,
+            None => self.pos,
         }
     }
 }
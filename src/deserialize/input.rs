@@ -17,6 +17,12 @@ const INPUT_TYPE_MESSAGE: &str = "Input must be bytes or str";
 #[cfg(not(CPython))]
 const INPUT_TYPE_MESSAGE: &str = "Input must be bytes, bytearray, or str";
 
+#[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+const BINARY_INPUT_TYPE_MESSAGE: &str = "Input must be bytes, bytearray, or memoryview";
+
+#[cfg(any(not(CPython), Py_GIL_DISABLED))]
+const BINARY_INPUT_TYPE_MESSAGE: &str = "Input must be bytes";
+
 #[cfg_attr(not(Py_GIL_DISABLED), repr(transparent))]
 pub struct Utf8Buffer {
     buffer: &'static str,
@@ -53,6 +59,26 @@ impl Utf8Buffer {
         }
     }
 
+    /// Return whether `ptr` is of a type `from_pyobject()` reads.
+    #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+    pub fn is_input_type(ptr: *mut crate::ffi::PyObject) -> bool {
+        PyBytesRef::from_ptr(ptr).is_ok()
+            || PyStrRef::from_ptr(ptr).is_ok()
+            || PyByteArrayRef::from_ptr(ptr).is_ok()
+            || unsafe {
+                core::ptr::eq(
+                    crate::ffi::PyObject_Type(ptr),
+                    &raw mut crate::ffi::PyMemoryView_Type,
+                )
+            }
+    }
+
+    /// Return whether `ptr` is of a type `from_pyobject()` reads.
+    #[cfg(any(not(CPython), Py_GIL_DISABLED))]
+    pub fn is_input_type(ptr: *mut crate::ffi::PyObject) -> bool {
+        PyBytesRef::from_ptr(ptr).is_ok() || PyStrRef::from_ptr(ptr).is_ok()
+    }
+
     pub fn from_pyobject(
         ptr: *mut crate::ffi::PyObject,
     ) -> Result<Self, DeserializeError<'static>> {
@@ -88,3 +114,93 @@ impl Utf8Buffer {
         self.buffer.len()
     }
 }
+
+/// Return the contents of a `bytes`, `bytearray`, `memoryview`, or `str`
+/// object without validating that it is UTF-8 except for `str`. An empty
+/// input is not an error.
+#[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+pub(crate) fn bytes_from_pyobject(
+    ptr: *mut crate::ffi::PyObject,
+) -> Result<&'static [u8], DeserializeError<'static>> {
+    if let Ok(ob) = PyBytesRef::from_ptr(ptr) {
+        Ok(ob.as_bytes())
+    } else if let Ok(ob) = PyStrRef::from_ptr(ptr) {
+        match ob.as_str() {
+            Some(as_str) => Ok(as_str.as_bytes()),
+            None => Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR))),
+        }
+    } else if let Ok(ob) = PyByteArrayRef::from_ptr(ptr) {
+        Ok(ob.as_bytes())
+    } else if let Ok(ob) = PyMemoryViewRef::from_ptr(ptr) {
+        Ok(ob.as_bytes())
+    } else {
+        Err(DeserializeError::invalid(Cow::Borrowed(INPUT_TYPE_MESSAGE)))
+    }
+}
+
+/// Return the contents of a `bytes` or `str` object without validating that
+/// it is UTF-8 except for `str`. An empty input is not an error.
+#[cfg(any(not(CPython), Py_GIL_DISABLED))]
+pub(crate) fn bytes_from_pyobject(
+    ptr: *mut crate::ffi::PyObject,
+) -> Result<&'static [u8], DeserializeError<'static>> {
+    if let Ok(ob) = PyBytesRef::from_ptr(ptr) {
+        Ok(ob.as_bytes())
+    } else if let Ok(ob) = PyStrRef::from_ptr(ptr) {
+        match ob.as_str() {
+            Some(as_str) => Ok(as_str.as_bytes()),
+            None => Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR))),
+        }
+    } else {
+        Err(DeserializeError::invalid(Cow::Borrowed(INPUT_TYPE_MESSAGE)))
+    }
+}
+
+/// Return the contents of a `bytes`, `bytearray`, or `memoryview` object
+/// holding a binary format. An empty input is an error.
+#[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+pub(crate) fn binary_from_pyobject(
+    ptr: *mut crate::ffi::PyObject,
+) -> Result<&'static [u8], DeserializeError<'static>> {
+    let buffer = if let Ok(ob) = PyBytesRef::from_ptr(ptr) {
+        ob.as_bytes()
+    } else if let Ok(ob) = PyByteArrayRef::from_ptr(ptr) {
+        ob.as_bytes()
+    } else if let Ok(ob) = PyMemoryViewRef::from_ptr(ptr) {
+        ob.as_bytes()
+    } else {
+        return Err(DeserializeError::invalid(Cow::Borrowed(
+            BINARY_INPUT_TYPE_MESSAGE,
+        )));
+    };
+    if buffer.is_empty() {
+        cold_path!();
+        return Err(DeserializeError::invalid(Cow::Borrowed(
+            "Input is a zero-length, empty document",
+        )));
+    }
+    Ok(buffer)
+}
+
+/// Return the contents of a `bytes` object holding a binary format. An empty
+/// input is an error.
+#[cfg(any(not(CPython), Py_GIL_DISABLED))]
+pub(crate) fn binary_from_pyobject(
+    ptr: *mut crate::ffi::PyObject,
+) -> Result<&'static [u8], DeserializeError<'static>> {
+    let buffer = match PyBytesRef::from_ptr(ptr) {
+        Ok(ob) => ob.as_bytes(),
+        Err(_) => {
+            return Err(DeserializeError::invalid(Cow::Borrowed(
+                BINARY_INPUT_TYPE_MESSAGE,
+            )));
+        }
+    };
+    if buffer.is_empty() {
+        cold_path!();
+        return Err(DeserializeError::invalid(Cow::Borrowed(
+            "Input is a zero-length, empty document",
+        )));
+    }
+    Ok(buffer)
+}
@@ -0,0 +1,417 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::backend::{PoolBuffer, deserialize_with_pool, read};
+use super::input::Utf8Buffer;
+use super::scan::{Scan, skip_whitespace, value_end};
+use crate::exception::raise_loads_exception;
+use crate::ffi::{
+    Py_DECREF, Py_INCREF, Py_TPFLAGS_DEFAULT, Py_TPFLAGS_DISALLOW_INSTANTIATION,
+    Py_TPFLAGS_IMMUTABLETYPE, Py_tp_dealloc, Py_tp_iter, Py_tp_iternext, PyBytesRef, PyObject,
+    PyObject_SelfIter, PyStrRef, PyType_FromSpec, PyType_Slot, PyType_Spec, PyTypeObject,
+};
+use core::ffi::c_void;
+use core::ptr::{NonNull, null_mut};
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::borrow::Cow;
+
+#[cfg(Py_GIL_DISABLED)]
+use core::sync::atomic::{AtomicIsize, AtomicU32};
+
+#[cfg(Py_GIL_DISABLED)]
+macro_rules! pymutex_new {
+    () => {
+        unsafe { core::mem::zeroed() }
+    };
+}
+
+/// Name of the array element in a prefix, as in ijson.
+const ITEM: &[u8] = b"item";
+
+struct Frame {
+    array: bool,
+    first: bool,
+    level: usize,
+}
+
+/// Walk of a document that deserializes only the values at a prefix.
+///
+/// The document is scanned for structure and each value matching the prefix
+/// is parsed from its own range of the input. Content not at the prefix is
+/// validated and skipped.
+pub(crate) struct Items {
+    data: &'static str,
+    path: Vec<&'static [u8]>,
+    pos: usize,
+    stack: Vec<Frame>,
+    started: bool,
+    pool: PoolBuffer,
+}
+
+#[cold]
+#[allow(clippy::cast_possible_wrap)]
+fn error_at(msg: &'static str, pos: usize, data: &'static str) -> DeserializeError<'static> {
+    DeserializeError::from_yyjson(Cow::Borrowed(msg), pos as i64, data)
+}
+
+impl Items {
+    pub fn new(data: &'static str, prefix: &'static str) -> Self {
+        let path = if prefix.is_empty() {
+            Vec::new()
+        } else {
+            prefix.split('.').map(str::as_bytes).collect()
+        };
+        Items {
+            data: data,
+            path: path,
+            pos: 0,
+            stack: Vec::new(),
+            started: false,
+            pool: PoolBuffer::new(),
+        }
+    }
+
+    fn parse(
+        &mut self,
+        begin: usize,
+        end: usize,
+    ) -> Result<NonNull<PyObject>, DeserializeError<'static>> {
+        deserialize_with_pool(&self.data[begin..end], &mut self.pool)
+            .map_err(|err| err.rebase(begin, self.data))
+    }
+
+    /// Validate the skipped value in `begin..end` without deserializing it.
+    fn validate(&mut self, begin: usize, end: usize) -> Result<(), DeserializeError<'static>> {
+        read(&self.data[begin..end], &mut self.pool).map_err(|err| err.rebase(begin, self.data))?;
+        Ok(())
+    }
+
+    fn key_matches(&mut self, begin: usize, end: usize, component: &[u8]) -> bool {
+        let key = &self.data.as_bytes()[begin + 1..end - 1];
+        if !key.contains(&b'\\') {
+            return key == component;
+        }
+        cold_path!();
+        match deserialize_with_pool(&self.data[begin..end], &mut self.pool) {
+            Ok(obj) => {
+                let ret = unsafe { PyStrRef::from_ptr_unchecked(obj.as_ptr()) }
+                    .as_str()
+                    .is_some_and(|key| key.as_bytes() == component);
+                unsafe {
+                    Py_DECREF(obj.as_ptr());
+                }
+                ret
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Return the end of the document, which must be followed only by
+    /// whitespace.
+    fn finish(&mut self, end: usize) -> Result<(), DeserializeError<'static>> {
+        let idx = skip_whitespace(self.data.as_bytes(), end);
+        self.pos = self.data.len();
+        if idx != self.data.len() {
+            cold_path!();
+            return Err(error_at(
+                "unexpected content after document",
+                idx,
+                self.data,
+            ));
+        }
+        Ok(())
+    }
+
+    fn start(&mut self) -> Result<Option<NonNull<PyObject>>, DeserializeError<'static>> {
+        self.started = true;
+        let buf = self.data.as_bytes();
+        let begin = skip_whitespace(buf, 0);
+        if begin == buf.len() {
+            cold_path!();
+            return Err(error_at("unexpected end of data", begin, self.data));
+        }
+        if self.path.is_empty() {
+            let end = value_end(buf, begin).unwrap_or(buf.len());
+            let obj = self.parse(begin, end)?;
+            if let Err(err) = self.finish(end) {
+                unsafe {
+                    Py_DECREF(obj.as_ptr());
+                }
+                return Err(err);
+            }
+            return Ok(Some(obj));
+        }
+        match buf[begin] {
+            b'[' | b'{' => {
+                self.stack.push(Frame {
+                    array: buf[begin] == b'[',
+                    first: true,
+                    level: 0,
+                });
+                self.pos = begin + 1;
+                Ok(None)
+            }
+            _ => match value_end(buf, begin) {
+                Some(end) => {
+                    self.validate(begin, end)?;
+                    self.finish(end)?;
+                    Ok(None)
+                }
+                None => Err(error_at("unexpected character", begin, self.data)),
+            },
+        }
+    }
+
+    /// Return the next value at the prefix or `None` if there are no more.
+    pub fn next(&mut self) -> Result<Option<NonNull<PyObject>>, DeserializeError<'static>> {
+        if !self.started
+            && let Some(obj) = self.start()?
+        {
+            return Ok(Some(obj));
+        }
+        let buf = self.data.as_bytes();
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                return Ok(None);
+            };
+            let mut idx = skip_whitespace(buf, self.pos);
+            if idx == buf.len() {
+                cold_path!();
+                return Err(error_at("unexpected end of data", idx, self.data));
+            }
+            let close = if frame.array { b']' } else { b'}' };
+            if buf[idx] == close {
+                self.stack.pop();
+                if self.stack.is_empty() {
+                    self.finish(idx + 1)?;
+                } else {
+                    self.pos = idx + 1;
+                }
+                continue;
+            }
+            if !frame.first {
+                if buf[idx] != b',' {
+                    cold_path!();
+                    return Err(error_at("unexpected character", idx, self.data));
+                }
+                idx = skip_whitespace(buf, idx + 1);
+            }
+            frame.first = false;
+            let array = frame.array;
+            let level = frame.level;
+
+            let matches = if array {
+                self.path[level] == ITEM
+            } else {
+                if idx == buf.len() || buf[idx] != b'"' {
+                    cold_path!();
+                    return Err(error_at("unexpected character", idx, self.data));
+                }
+                let Some(key_end) = Scan::new(idx).resume(buf) else {
+                    cold_path!();
+                    return Err(error_at("unexpected end of data", buf.len(), self.data));
+                };
+                self.validate(idx, key_end)?;
+                let matches = self.key_matches(idx, key_end, self.path[level]);
+                idx = skip_whitespace(buf, key_end);
+                if idx == buf.len() || buf[idx] != b':' {
+                    cold_path!();
+                    return Err(error_at("unexpected character", idx, self.data));
+                }
+                idx = skip_whitespace(buf, idx + 1);
+                matches
+            };
+            if idx == buf.len() {
+                cold_path!();
+                return Err(error_at("unexpected end of data", idx, self.data));
+            }
+
+            if matches && level + 1 == self.path.len() {
+                let end = value_end(buf, idx).unwrap_or(buf.len());
+                let obj = self.parse(idx, end)?;
+                self.pos = end;
+                return Ok(Some(obj));
+            }
+            if matches && matches!(buf[idx], b'[' | b'{') {
+                self.stack.push(Frame {
+                    array: buf[idx] == b'[',
+                    first: true,
+                    level: level + 1,
+                });
+                self.pos = idx + 1;
+                continue;
+            }
+            match value_end(buf, idx) {
+                Some(end) => {
+                    self.validate(idx, end)?;
+                    self.pos = end;
+                }
+                None => {
+                    cold_path!();
+                    return Err(error_at("unexpected character", idx, self.data));
+                }
+            }
+        }
+    }
+}
+
+#[repr(C)]
+pub(crate) struct ItemsIterator {
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_tid: usize,
+    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+    pub ob_flags: u16,
+    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+    pub _padding: u16,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_mutex: pyo3_ffi::PyMutex,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_gc_bits: u8,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_local: AtomicU32,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_shared: AtomicIsize,
+    #[cfg(not(Py_GIL_DISABLED))]
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    #[cfg(PyPy)]
+    pub ob_pypy_link: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    data: *mut PyObject,
+    prefix: *mut PyObject,
+    state: Items,
+    done: bool,
+    busy: AtomicBool,
+}
+
+/// Return an iterator of the values at `prefix` in the document `ptr`.
+pub(crate) fn iter_items(
+    ptr: *mut PyObject,
+    prefix: *mut PyObject,
+) -> Result<NonNull<PyObject>, DeserializeError<'static>> {
+    if PyBytesRef::from_ptr(ptr).is_err() && PyStrRef::from_ptr(ptr).is_err() {
+        cold_path!();
+        return Err(DeserializeError::invalid(Cow::Borrowed(
+            "Input must be bytes or str",
+        )));
+    }
+    let buffer = Utf8Buffer::from_pyobject(ptr)?;
+    let Some(prefix_str) = PyStrRef::from_ptr(prefix)
+        .ok()
+        .and_then(|prefix| prefix.as_str())
+    else {
+        cold_path!();
+        return Err(DeserializeError::invalid(Cow::Borrowed(
+            "prefix must be a str",
+        )));
+    };
+    unsafe {
+        Py_INCREF(ptr);
+        Py_INCREF(prefix);
+    }
+    let obj = Box::new(ItemsIterator {
+        #[cfg(Py_GIL_DISABLED)]
+        ob_tid: 0,
+        #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+        ob_flags: 0,
+        #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+        _padding: 0,
+        #[cfg(Py_GIL_DISABLED)]
+        ob_mutex: pymutex_new!(),
+        #[cfg(Py_GIL_DISABLED)]
+        ob_gc_bits: 0,
+        #[cfg(Py_GIL_DISABLED)]
+        ob_ref_local: AtomicU32::new(0),
+        #[cfg(Py_GIL_DISABLED)]
+        ob_ref_shared: AtomicIsize::new(0),
+        #[cfg(not(Py_GIL_DISABLED))]
+        ob_refcnt: 1,
+        #[cfg(PyPy)]
+        ob_pypy_link: 0,
+        ob_type: unsafe { crate::typeref::ITEMS_ITERATOR_TYPE },
+        data: ptr,
+        prefix: prefix,
+        state: Items::new(buffer.as_str(), prefix_str),
+        done: false,
+        busy: AtomicBool::new(false),
+    });
+    Ok(unsafe { NonNull::new_unchecked(Box::into_raw(obj).cast::<PyObject>()) })
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_items_dealloc(object: *mut PyObject) {
+    unsafe {
+        let iter = Box::from_raw(object.cast::<ItemsIterator>());
+        Py_DECREF(iter.data);
+        Py_DECREF(iter.prefix);
+        drop(iter);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_items_iternext(object: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        let iter = &mut *object.cast::<ItemsIterator>();
+        if iter.done {
+            return null_mut();
+        }
+        if iter.busy.swap(true, Ordering::Acquire) {
+            cold_path!();
+            return raise_loads_exception(DeserializeError::invalid(Cow::Borrowed(
+                "iterator is already in use",
+            )));
+        }
+        let ret = match iter.state.next() {
+            Ok(Some(obj)) => obj.as_ptr(),
+            Ok(None) => {
+                iter.done = true;
+                null_mut()
+            }
+            Err(err) => {
+                cold_path!();
+                iter.done = true;
+                raise_loads_exception(err)
+            }
+        };
+        iter.busy.store(false, Ordering::Release);
+        ret
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_itemsiteratortype_new() -> *mut PyTypeObject {
+    unsafe {
+        let mut slots = [
+            PyType_Slot {
+                slot: Py_tp_dealloc,
+                pfunc: orjson_items_dealloc as *mut c_void,
+            },
+            PyType_Slot {
+                slot: Py_tp_iter,
+                pfunc: PyObject_SelfIter as *mut c_void,
+            },
+            PyType_Slot {
+                slot: Py_tp_iternext,
+                pfunc: orjson_items_iternext as *mut c_void,
+            },
+            PyType_Slot {
+                slot: 0,
+                pfunc: null_mut(),
+            },
+        ];
+        let mut spec = PyType_Spec {
+            name: c"orjson.ItemsIterator".as_ptr(),
+            basicsize: core::mem::size_of::<ItemsIterator>().cast_signed() as i32,
+            itemsize: 0,
+            flags: (Py_TPFLAGS_DEFAULT
+                | Py_TPFLAGS_IMMUTABLETYPE
+                | Py_TPFLAGS_DISALLOW_INSTANTIATION) as u32,
+            slots: &raw mut slots[0],
+        };
+        PyType_FromSpec(&raw mut spec).cast::<PyTypeObject>()
+    }
+}
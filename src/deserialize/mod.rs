@@ -4,12 +4,34 @@
 mod backend;
 #[cfg(not(Py_GIL_DISABLED))]
 mod cache;
+mod cbor;
+mod decoder;
 mod deserializer;
 mod error;
 mod input;
+mod items;
+mod msgpack;
+mod node;
+mod pointer;
+mod projection;
 mod pyobject;
+mod raw;
+mod reformat;
+mod scan;
+mod stream;
 
+pub(crate) use backend::{Kind, PoolBuffer, TypedKind, Value, read, read_raw};
 #[cfg(not(Py_GIL_DISABLED))]
-pub(crate) use cache::{KEY_MAP, KeyMap};
-pub(crate) use deserializer::deserialize;
+pub(crate) use cache::{KEY_MAP, KeyMap, SNAKE_CASE_KEY_MAP};
+pub(crate) use cbor::deserialize_cbor;
+pub(crate) use decoder::orjson_decodertype_new;
+pub(crate) use deserializer::{deserialize, deserialize_selected, deserialize_with_opts, validate};
 pub(crate) use error::DeserializeError;
+pub(crate) use input::Utf8Buffer;
+pub(crate) use items::{iter_items, orjson_itemsiteratortype_new};
+pub(crate) use msgpack::deserialize_msgpack;
+pub(crate) use node::{orjson_nodetype_new, parse_lazy};
+pub(crate) use pointer::{parse_index, parse_pointer};
+pub(crate) use projection::Projection;
+pub(crate) use raw::raw_decode;
+pub(crate) use reformat::{REFORMAT_OPT, ValueSerializer, reformat};
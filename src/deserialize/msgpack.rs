@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::deserialize::DeserializeError;
+use crate::deserialize::input::binary_from_pyobject;
+use crate::deserialize::pyobject::{datetime_from_timestamp, get_unicode_key};
+use crate::ffi::{
+    Py_DECREF, PyBoolRef, PyBytes_FromStringAndSize, PyDictRef, PyFloatRef, PyIntRef, PyListRef,
+    PyNoneRef, PyObject, PyStrRef, is_valid_utf8,
+};
+use crate::opt::{MSGPACK_TIMESTAMP, Opt};
+use crate::util::usize_to_isize;
+use core::ptr::NonNull;
+use std::borrow::Cow;
+
+const RECURSION_LIMIT: usize = 1024;
+
+const TIMESTAMP_EXT: i8 = -1;
+
+type DecodeResult<T> = Result<T, DeserializeError<'static>>;
+
+struct Decoder {
+    data: &'static [u8],
+    pos: usize,
+    depth: usize,
+    opts: Opt,
+}
+
+impl Decoder {
+    #[cold]
+    #[inline(never)]
+    fn error<T>(&self, message: &'static str, pos: usize) -> DecodeResult<T> {
+        Err(DeserializeError::from_binary(Cow::Borrowed(message), pos))
+    }
+
+    #[inline]
+    fn read(&mut self, len: usize) -> DecodeResult<&'static [u8]> {
+        if self.data.len() - self.pos < len {
+            cold_path!();
+            return self.error("Unexpected end of data", self.data.len());
+        }
+        let buf = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(buf)
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> DecodeResult<[u8; N]> {
+        let buf = self.read(N)?;
+        Ok(buf.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+
+    #[inline]
+    fn read_u8(&mut self) -> DecodeResult<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    #[inline]
+    fn read_u16(&mut self) -> DecodeResult<usize> {
+        Ok(usize::from(u16::from_be_bytes(self.read_array()?)))
+    }
+
+    #[inline]
+    fn read_u32(&mut self) -> DecodeResult<usize> {
+        Ok(u32::from_be_bytes(self.read_array()?) as usize)
+    }
+
+    fn decode(&mut self) -> DecodeResult<NonNull<PyObject>> {
+        let start = self.pos;
+        let marker = self.read_u8()?;
+        match marker {
+            0x00..=0x7f => Ok(PyIntRef::from_u64(u64::from(marker)).as_non_null_ptr()),
+            0x80..=0x8f => self.decode_map(usize::from(marker & 0x0f), start),
+            0x90..=0x9f => self.decode_array(usize::from(marker & 0x0f), start),
+            0xa0..=0xbf => self.decode_str(usize::from(marker & 0x1f)),
+            0xc0 => Ok(PyNoneRef::none().as_non_null_ptr()),
+            0xc2 => Ok(PyBoolRef::pyfalse().as_non_null_ptr()),
+            0xc3 => Ok(PyBoolRef::pytrue().as_non_null_ptr()),
+            0xc4 => {
+                let len = usize::from(self.read_u8()?);
+                self.decode_bin(len)
+            }
+            0xc5 => {
+                let len = self.read_u16()?;
+                self.decode_bin(len)
+            }
+            0xc6 => {
+                let len = self.read_u32()?;
+                self.decode_bin(len)
+            }
+            0xc7 => {
+                let len = usize::from(self.read_u8()?);
+                self.decode_ext(len, start)
+            }
+            0xc8 => {
+                let len = self.read_u16()?;
+                self.decode_ext(len, start)
+            }
+            0xc9 => {
+                let len = self.read_u32()?;
+                self.decode_ext(len, start)
+            }
+            0xca => {
+                let val = f32::from_be_bytes(self.read_array()?);
+                Ok(PyFloatRef::from_f64(f64::from(val)).as_non_null_ptr())
+            }
+            0xcb => {
+                let val = f64::from_be_bytes(self.read_array()?);
+                Ok(PyFloatRef::from_f64(val).as_non_null_ptr())
+            }
+            0xcc => {
+                let val = u64::from(self.read_u8()?);
+                Ok(PyIntRef::from_u64(val).as_non_null_ptr())
+            }
+            0xcd => {
+                let val = u64::from(u16::from_be_bytes(self.read_array()?));
+                Ok(PyIntRef::from_u64(val).as_non_null_ptr())
+            }
+            0xce => {
+                let val = u64::from(u32::from_be_bytes(self.read_array()?));
+                Ok(PyIntRef::from_u64(val).as_non_null_ptr())
+            }
+            0xcf => {
+                let val = u64::from_be_bytes(self.read_array()?);
+                Ok(PyIntRef::from_u64(val).as_non_null_ptr())
+            }
+            0xd0 => {
+                let val = i64::from(i8::from_be_bytes(self.read_array()?));
+                Ok(PyIntRef::from_i64(val).as_non_null_ptr())
+            }
+            0xd1 => {
+                let val = i64::from(i16::from_be_bytes(self.read_array()?));
+                Ok(PyIntRef::from_i64(val).as_non_null_ptr())
+            }
+            0xd2 => {
+                let val = i64::from(i32::from_be_bytes(self.read_array()?));
+                Ok(PyIntRef::from_i64(val).as_non_null_ptr())
+            }
+            0xd3 => {
+                let val = i64::from_be_bytes(self.read_array()?);
+                Ok(PyIntRef::from_i64(val).as_non_null_ptr())
+            }
+            0xd4 => self.decode_ext(1, start),
+            0xd5 => self.decode_ext(2, start),
+            0xd6 => self.decode_ext(4, start),
+            0xd7 => self.decode_ext(8, start),
+            0xd8 => self.decode_ext(16, start),
+            0xd9 => {
+                let len = usize::from(self.read_u8()?);
+                self.decode_str(len)
+            }
+            0xda => {
+                let len = self.read_u16()?;
+                self.decode_str(len)
+            }
+            0xdb => {
+                let len = self.read_u32()?;
+                self.decode_str(len)
+            }
+            0xdc => {
+                let len = self.read_u16()?;
+                self.decode_array(len, start)
+            }
+            0xdd => {
+                let len = self.read_u32()?;
+                self.decode_array(len, start)
+            }
+            0xde => {
+                let len = self.read_u16()?;
+                self.decode_map(len, start)
+            }
+            0xdf => {
+                let len = self.read_u32()?;
+                self.decode_map(len, start)
+            }
+            0xe0..=0xff => {
+                let val = i64::from(i8::from_be_bytes([marker]));
+                Ok(PyIntRef::from_i64(val).as_non_null_ptr())
+            }
+            0xc1 => self.error("Invalid marker 0xc1", start),
+        }
+    }
+
+    #[inline]
+    fn read_str(&mut self, len: usize) -> DecodeResult<&'static str> {
+        let start = self.pos;
+        let buf = self.read(len)?;
+        if !is_valid_utf8(buf) {
+            cold_path!();
+            return self.error("str is not valid UTF-8", start);
+        }
+        Ok(unsafe { core::str::from_utf8_unchecked(buf) })
+    }
+
+    fn decode_str(&mut self, len: usize) -> DecodeResult<NonNull<PyObject>> {
+        let buf = self.read_str(len)?;
+        Ok(PyStrRef::from_str(buf).as_non_null_ptr())
+    }
+
+    fn decode_bin(&mut self, len: usize) -> DecodeResult<NonNull<PyObject>> {
+        let buf = self.read(len)?;
+        let ptr = unsafe {
+            PyBytes_FromStringAndSize(
+                buf.as_ptr().cast::<core::ffi::c_char>(),
+                usize_to_isize(len),
+            )
+        };
+        debug_assert!(!ptr.is_null());
+        Ok(nonnull!(ptr))
+    }
+
+    fn decode_key(&mut self) -> DecodeResult<PyStrRef> {
+        let start = self.pos;
+        let marker = self.read_u8()?;
+        let len = match marker {
+            0xa0..=0xbf => usize::from(marker & 0x1f),
+            0xd9 => usize::from(self.read_u8()?),
+            0xda => self.read_u16()?,
+            0xdb => self.read_u32()?,
+            _ => return self.error("Map key must be str", start),
+        };
+        let buf = self.read_str(len)?;
+        Ok(get_unicode_key(buf))
+    }
+
+    #[inline]
+    fn enter(&mut self, len: usize, min_size: usize, start: usize) -> DecodeResult<()> {
+        if len > (self.data.len() - self.pos) / min_size {
+            cold_path!();
+            return self.error("Unexpected end of data", self.data.len());
+        }
+        if self.depth == RECURSION_LIMIT {
+            cold_path!();
+            return self.error("Recursion limit reached", start);
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn decode_array(&mut self, len: usize, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        self.enter(len, 1, start)?;
+        let mut list = PyListRef::with_capacity(len);
+        for idx in 0..len {
+            match self.decode() {
+                Ok(val) => list.set(idx, val.as_ptr()),
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(list.as_ptr());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(list.as_non_null_ptr())
+    }
+
+    fn decode_map(&mut self, len: usize, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        self.enter(len, 2, start)?;
+        let mut dict = PyDictRef::with_capacity(len);
+        for _ in 0..len {
+            let entry = self.decode_key().and_then(|key| match self.decode() {
+                Ok(val) => Ok((key, val)),
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(key.as_ptr());
+                    }
+                    Err(err)
+                }
+            });
+            match entry {
+                Ok((key, val)) => dict.set(key, val.as_ptr()),
+                Err(err) => {
+                    unsafe {
+                        Py_DECREF(dict.as_ptr());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(dict.as_non_null_ptr())
+    }
+
+    fn decode_ext(&mut self, len: usize, start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let ext = i8::from_be_bytes(self.read_array()?);
+        let buf = self.read(len)?;
+        if ext != TIMESTAMP_EXT || opt_disabled!(self.opts, MSGPACK_TIMESTAMP) {
+            cold_path!();
+            return self.error("Extension type is not supported", start);
+        }
+        self.decode_timestamp(buf, start)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn decode_timestamp(&self, buf: &[u8], start: usize) -> DecodeResult<NonNull<PyObject>> {
+        let (seconds, nanoseconds) = match buf.len() {
+            4 => (
+                i64::from(u32::from_be_bytes(
+                    buf.try_into().unwrap_or_else(|_| unreachable!()),
+                )),
+                0,
+            ),
+            8 => {
+                let val = u64::from_be_bytes(buf.try_into().unwrap_or_else(|_| unreachable!()));
+                #[allow(clippy::cast_possible_truncation)]
+                let nanoseconds = (val >> 34) as u32;
+                ((val & 0x3_ffff_ffff).cast_signed(), nanoseconds)
+            }
+            12 => (
+                i64::from_be_bytes(buf[4..].try_into().unwrap_or_else(|_| unreachable!())),
+                u32::from_be_bytes(buf[..4].try_into().unwrap_or_else(|_| unreachable!())),
+            ),
+            _ => return self.error("Invalid timestamp", start),
+        };
+        if nanoseconds > 999_999_999 {
+            return self.error("Invalid timestamp", start);
+        }
+        jiff::Timestamp::new(seconds, nanoseconds.cast_signed())
+            .ok()
+            .and_then(datetime_from_timestamp)
+            .map_or_else(
+                || self.error("Timestamp is out of range of datetime", start),
+                Ok,
+            )
+    }
+}
+
+/// Deserialize the MessagePack document in `ptr` to Python objects.
+pub(crate) fn deserialize_msgpack(
+    ptr: *mut PyObject,
+    opts: Opt,
+) -> Result<NonNull<PyObject>, DeserializeError<'static>> {
+    let data = binary_from_pyobject(ptr)?;
+    let mut decoder = Decoder {
+        data: data,
+        pos: 0,
+        depth: 0,
+        opts: opts,
+    };
+    let obj = decoder.decode()?;
+    if decoder.pos != data.len() {
+        cold_path!();
+        unsafe {
+            Py_DECREF(obj.as_ptr());
+        }
+        return decoder.error("Trailing data", decoder.pos);
+    }
+    Ok(obj)
+}
@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::backend::{Document, Value};
+use super::input::Utf8Buffer;
+use super::pyobject::get_unicode_key;
+use crate::exception::{raise_args_exception, raise_index_exception, raise_key_exception};
+use crate::ffi::{
+    Py_DECREF, Py_INCREF, Py_TPFLAGS_DEFAULT, Py_TPFLAGS_DISALLOW_INSTANTIATION,
+    Py_TPFLAGS_IMMUTABLETYPE, Py_mp_length, Py_mp_subscript, Py_ssize_t, Py_tp_dealloc, Py_tp_iter,
+    PyListRef, PyMethodDef, PyMethodDefPointer, PyObject, PyObject_GetIter, PyStrRef,
+    PyType_FromSpec, PyType_Slot, PyType_Spec, PyTypeObject,
+};
+use crate::util::{isize_to_usize, usize_to_isize};
+use core::ffi::c_void;
+use core::ptr::{NonNull, null_mut};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+#[cfg(Py_GIL_DISABLED)]
+use core::sync::atomic::{AtomicIsize, AtomicU32};
+
+#[cfg(Py_GIL_DISABLED)]
+macro_rules! pymutex_new {
+    () => {
+        unsafe { core::mem::zeroed() }
+    };
+}
+
+/// Array or object in a document returned by `parse_lazy()`. Nodes of the
+/// same document share it and it is freed when the last is deallocated.
+#[repr(C)]
+pub(crate) struct Node {
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_tid: usize,
+    #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+    pub ob_flags: u16,
+    #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+    pub _padding: u16,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_mutex: pyo3_ffi::PyMutex,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_gc_bits: u8,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_local: AtomicU32,
+    #[cfg(Py_GIL_DISABLED)]
+    pub ob_ref_shared: AtomicIsize,
+    #[cfg(not(Py_GIL_DISABLED))]
+    pub ob_refcnt: pyo3_ffi::Py_ssize_t,
+    #[cfg(PyPy)]
+    pub ob_pypy_link: pyo3_ffi::Py_ssize_t,
+    pub ob_type: *mut pyo3_ffi::PyTypeObject,
+    doc: Arc<Document>,
+    val: Value<'static>,
+    table: OnceLock<Table>,
+}
+
+/// Index of the children of a `Node`, built on first use so that indexing
+/// does not walk the siblings before each child.
+struct Table {
+    /// The elements of an array.
+    elements: Vec<Value<'static>>,
+    /// The keys of an object once each, in the order `loads()` would.
+    keys: Vec<&'static str>,
+    /// The value of each key of an object that `loads()` would keep, which
+    /// is of the last member of that name.
+    values: HashMap<&'static str, Value<'static>>,
+}
+
+impl Table {
+    fn new(val: Value<'static>) -> Self {
+        let mut table = Table {
+            elements: Vec::new(),
+            keys: Vec::new(),
+            values: HashMap::new(),
+        };
+        if val.is_object() {
+            table.values.reserve(val.len());
+            for (key, each) in val.members() {
+                if table.values.insert(key, each).is_none() {
+                    table.keys.push(key);
+                }
+            }
+        } else {
+            table.elements.extend(val.elements());
+        }
+        table
+    }
+}
+
+/// Parse `ptr` and return the root `Node` or, if the root is not an array
+/// or object, its value.
+pub(crate) fn parse_lazy(
+    ptr: *mut PyObject,
+) -> Result<NonNull<PyObject>, DeserializeError<'static>> {
+    let buffer = Utf8Buffer::from_pyobject(ptr)?;
+    let doc = Arc::new(Document::parse(buffer.as_str())?);
+    // the document outlives its values because each node holds a reference
+    let root = unsafe { core::mem::transmute::<Value<'_>, Value<'static>>(doc.root()) };
+    Ok(node_or_value(&doc, root))
+}
+
+/// Return a `Node` for an array or object and convert any other value.
+fn node_or_value(doc: &Arc<Document>, val: Value<'static>) -> NonNull<PyObject> {
+    if !val.is_container() {
+        return val.to_python();
+    }
+    let obj = Box::new(Node {
+        #[cfg(Py_GIL_DISABLED)]
+        ob_tid: 0,
+        #[cfg(all(Py_GIL_DISABLED, Py_3_14))]
+        ob_flags: 0,
+        #[cfg(all(Py_GIL_DISABLED, not(Py_3_14)))]
+        _padding: 0,
+        #[cfg(Py_GIL_DISABLED)]
+        ob_mutex: pymutex_new!(),
+        #[cfg(Py_GIL_DISABLED)]
+        ob_gc_bits: 0,
+        #[cfg(Py_GIL_DISABLED)]
+        ob_ref_local: AtomicU32::new(0),
+        #[cfg(Py_GIL_DISABLED)]
+        ob_ref_shared: AtomicIsize::new(0),
+        #[cfg(not(Py_GIL_DISABLED))]
+        ob_refcnt: 1,
+        #[cfg(PyPy)]
+        ob_pypy_link: 0,
+        ob_type: unsafe { crate::typeref::NODE_TYPE },
+        doc: doc.clone(),
+        val: val,
+        table: OnceLock::new(),
+    });
+    unsafe { NonNull::new_unchecked(Box::into_raw(obj).cast::<PyObject>()) }
+}
+
+impl Node {
+    fn table(&self) -> &Table {
+        self.table.get_or_init(|| Table::new(self.val))
+    }
+
+    /// Return the value at `key`, which is a `str` for an object and an
+    /// `int` for an array, or `None` if there is none. This raises
+    /// `TypeError` if `key` is of the wrong type.
+    fn lookup(&self, key: *mut PyObject) -> Result<Option<Value<'static>>, ()> {
+        if self.val.is_object() {
+            let Ok(key) = PyStrRef::from_ptr(key) else {
+                cold_path!();
+                raise_args_exception("Node of an object must be indexed by str");
+                return Err(());
+            };
+            Ok(key
+                .as_str()
+                .and_then(|key| self.table().values.get(key).copied()))
+        } else {
+            if unsafe { crate::ffi::PyObject_Type(key) != crate::typeref::INT_TYPE } {
+                cold_path!();
+                raise_args_exception("Node of an array must be indexed by int");
+                return Err(());
+            }
+            let idx = unsafe { crate::ffi::PyLong_AsLongLong(key) };
+            if idx == -1 && unsafe { !crate::ffi::PyErr_Occurred().is_null() } {
+                cold_path!();
+                unsafe {
+                    crate::ffi::PyErr_Clear();
+                }
+                return Ok(None);
+            }
+            let len = usize_to_isize(self.val.len()) as i64;
+            let idx = if idx < 0 { idx + len } else { idx };
+            Ok(usize::try_from(idx)
+                .ok()
+                .and_then(|idx| self.table().elements.get(idx).copied()))
+        }
+    }
+
+    fn keys_list(&self) -> PyListRef {
+        let keys = &self.table().keys;
+        let mut list = PyListRef::with_capacity(keys.len());
+        for (idx, key) in keys.iter().enumerate() {
+            list.set(idx, get_unicode_key(key).as_ptr());
+        }
+        list
+    }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_node_dealloc(object: *mut PyObject) {
+    unsafe {
+        drop(Box::from_raw(object.cast::<Node>()));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_node_length(object: *mut PyObject) -> Py_ssize_t {
+    unsafe {
+        let node = &*object.cast::<Node>();
+        if node.val.is_object() {
+            usize_to_isize(node.table().keys.len())
+        } else {
+            usize_to_isize(node.val.len())
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_node_subscript(
+    object: *mut PyObject,
+    key: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let node = &*object.cast::<Node>();
+        match node.lookup(key) {
+            Ok(Some(val)) => node_or_value(&node.doc, val).as_ptr(),
+            Ok(None) => {
+                cold_path!();
+                if node.val.is_object() {
+                    raise_key_exception(key)
+                } else {
+                    raise_index_exception("Node index out of range")
+                }
+            }
+            Err(()) => null_mut(),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_node_iter(object: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        let node = &*object.cast::<Node>();
+        let list = if node.val.is_object() {
+            node.keys_list()
+        } else {
+            let mut list = PyListRef::with_capacity(node.val.len());
+            for (idx, val) in node.val.elements().enumerate() {
+                list.set(idx, node_or_value(&node.doc, val).as_ptr());
+            }
+            list
+        };
+        let iter = PyObject_GetIter(list.as_ptr());
+        Py_DECREF(list.as_ptr());
+        iter
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_node_get(
+    object: *mut PyObject,
+    args: *mut *mut PyObject,
+    nargs: Py_ssize_t,
+) -> *mut PyObject {
+    unsafe {
+        let num_args = isize_to_usize(nargs);
+        if num_args == 0 || num_args > 2 {
+            cold_path!();
+            return raise_args_exception("get() takes 1 or 2 positional arguments");
+        }
+        let node = &*object.cast::<Node>();
+        match node.lookup(*args) {
+            Ok(Some(val)) => node_or_value(&node.doc, val).as_ptr(),
+            Ok(None) => {
+                let default = if num_args == 2 {
+                    *args.add(1)
+                } else {
+                    crate::typeref::NONE
+                };
+                Py_INCREF(default);
+                default
+            }
+            Err(()) => null_mut(),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_node_keys(
+    object: *mut PyObject,
+    _args: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let node = &*object.cast::<Node>();
+        if !node.val.is_object() {
+            cold_path!();
+            return raise_args_exception("keys() requires a Node of an object");
+        }
+        node.keys_list().as_ptr()
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn orjson_node_to_python(
+    object: *mut PyObject,
+    _args: *mut PyObject,
+) -> *mut PyObject {
+    unsafe { (*object.cast::<Node>()).val.to_python().as_ptr() }
+}
+
+#[unsafe(no_mangle)]
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) unsafe extern "C" fn orjson_nodetype_new() -> *mut PyTypeObject {
+    unsafe {
+        let methods = Box::new([
+            PyMethodDef {
+                ml_name: c"get".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFast: orjson_node_get,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL,
+                ml_doc: c"get(key, default=None, /)\n--\n\nReturn the value at key if present, else default.".as_ptr(),
+            },
+            PyMethodDef {
+                ml_name: c"keys".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_node_keys,
+                },
+                ml_flags: crate::ffi::METH_NOARGS,
+                ml_doc: c"keys()\n--\n\nReturn a list of the keys of an object.".as_ptr(),
+            },
+            PyMethodDef {
+                ml_name: c"to_python".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: orjson_node_to_python,
+                },
+                ml_flags: crate::ffi::METH_NOARGS,
+                ml_doc: c"to_python()\n--\n\nDeserialize the node and its children to Python objects.".as_ptr(),
+            },
+            PyMethodDef::zeroed(),
+        ]);
+        let mut slots = [
+            PyType_Slot {
+                slot: Py_tp_dealloc,
+                pfunc: orjson_node_dealloc as *mut c_void,
+            },
+            PyType_Slot {
+                slot: Py_mp_length,
+                pfunc: orjson_node_length as *mut c_void,
+            },
+            PyType_Slot {
+                slot: Py_mp_subscript,
+                pfunc: orjson_node_subscript as *mut c_void,
+            },
+            PyType_Slot {
+                slot: Py_tp_iter,
+                pfunc: orjson_node_iter as *mut c_void,
+            },
+            PyType_Slot {
+                slot: crate::ffi::Py_tp_methods,
+                pfunc: Box::into_raw(methods).cast::<c_void>(),
+            },
+            PyType_Slot {
+                slot: crate::ffi::Py_tp_doc,
+                pfunc: c"Array or object in a document returned by parse_lazy()."
+                    .as_ptr()
+                    .cast_mut()
+                    .cast::<c_void>(),
+            },
+            PyType_Slot {
+                slot: 0,
+                pfunc: null_mut(),
+            },
+        ];
+        let mut spec = PyType_Spec {
+            name: c"orjson.Node".as_ptr(),
+            basicsize: core::mem::size_of::<Node>().cast_signed() as i32,
+            itemsize: 0,
+            flags: (Py_TPFLAGS_DEFAULT
+                | Py_TPFLAGS_IMMUTABLETYPE
+                | Py_TPFLAGS_DISALLOW_INSTANTIATION) as u32,
+            slots: &raw mut slots[0],
+        };
+        PyType_FromSpec(&raw mut spec).cast::<PyTypeObject>()
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::backend::Value;
+use std::borrow::Cow;
+
+/// Return the reference tokens of an RFC 6901 JSON Pointer.
+pub(crate) fn parse_pointer(pointer: &str) -> Result<Vec<Cow<'_, str>>, DeserializeError<'static>> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    if !pointer.starts_with('/') {
+        cold_path!();
+        return Err(invalid_pointer(pointer));
+    }
+    let mut tokens = Vec::new();
+    for token in pointer[1..].split('/') {
+        if !token.contains('~') {
+            tokens.push(Cow::Borrowed(token));
+            continue;
+        }
+        let mut unescaped = String::with_capacity(token.len());
+        let mut chars = token.chars();
+        while let Some(each) = chars.next() {
+            if each == '~' {
+                match chars.next() {
+                    Some('0') => unescaped.push('~'),
+                    Some('1') => unescaped.push('/'),
+                    _ => {
+                        cold_path!();
+                        return Err(invalid_pointer(pointer));
+                    }
+                }
+            } else {
+                unescaped.push(each);
+            }
+        }
+        tokens.push(Cow::Owned(unescaped));
+    }
+    Ok(tokens)
+}
+
+/// Parse an array index token, which has no sign or leading zeros.
+pub(crate) fn parse_index(token: &str) -> Option<usize> {
+    let bytes = token.as_bytes();
+    if bytes.is_empty()
+        || !bytes.iter().all(u8::is_ascii_digit)
+        || (bytes.len() > 1 && bytes[0] == b'0')
+    {
+        return None;
+    }
+    token.parse::<usize>().ok()
+}
+
+/// Return the value in `root` referred to by `pointer`.
+pub(crate) fn resolve<'p>(
+    root: Value<'p>,
+    pointer: &str,
+) -> Result<Value<'p>, DeserializeError<'static>> {
+    let mut val = root;
+    for token in parse_pointer(pointer)? {
+        let next = if val.is_object() {
+            val.get(&token)
+        } else if val.is_array() {
+            parse_index(&token).and_then(|idx| val.index(idx))
+        } else {
+            None
+        };
+        match next {
+            Some(next) => val = next,
+            None => {
+                cold_path!();
+                return Err(DeserializeError::invalid(Cow::Owned(format!(
+                    "JSON Pointer does not refer to a value: {pointer}"
+                ))));
+            }
+        }
+    }
+    Ok(val)
+}
+
+#[cold]
+fn invalid_pointer(pointer: &str) -> DeserializeError<'static> {
+    DeserializeError::invalid(Cow::Owned(format!("Invalid JSON Pointer: {pointer}")))
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::{
+    Py_DECREF, PyAnySet_Check, PyDict_Check, PyDict_Next, PyIter_Next, PyObject, PyObject_GetIter,
+    PyStrRef,
+};
+use core::ptr::null_mut;
+
+const PROJECTION_RECURSION_LIMIT: u8 = 255;
+
+const PROJECTION_TYPE_MESSAGE: &str =
+    "loads() argument 'keys' must be a set of str or a dict of str to True, a set, or a dict";
+
+/// Members of objects to deserialize, from the `keys` argument of `loads()`.
+/// A projection applies to each element of an array.
+pub(crate) enum Projection {
+    All,
+    Keys(Vec<(&'static str, Projection)>),
+}
+
+impl Projection {
+    /// Build a projection from a `set` of `str` or a `dict` of `str` to
+    /// `True` or a nested projection.
+    pub fn from_pyobject(ptr: *mut PyObject) -> Result<Self, &'static str> {
+        Self::from_pyobject_impl(ptr, 0)
+    }
+
+    fn from_pyobject_impl(ptr: *mut PyObject, depth: u8) -> Result<Self, &'static str> {
+        if depth == PROJECTION_RECURSION_LIMIT {
+            cold_path!();
+            return Err("loads() argument 'keys' is nested too deeply");
+        }
+        let mut keys: Vec<(&'static str, Projection)> = Vec::new();
+        unsafe {
+            if PyAnySet_Check(ptr) != 0 {
+                let iter = PyObject_GetIter(ptr);
+                loop {
+                    let item = PyIter_Next(iter);
+                    if item.is_null() {
+                        break;
+                    }
+                    // the set holds a reference to the str
+                    Py_DECREF(item);
+                    match Self::key_from_pyobject(item) {
+                        Ok(key) => keys.push((key, Projection::All)),
+                        Err(msg) => {
+                            cold_path!();
+                            Py_DECREF(iter);
+                            return Err(msg);
+                        }
+                    }
+                }
+                Py_DECREF(iter);
+            } else if PyDict_Check(ptr) != 0 {
+                let mut pos = 0;
+                let mut key: *mut PyObject = null_mut();
+                let mut value: *mut PyObject = null_mut();
+                while PyDict_Next(ptr, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+                    let projection = if core::ptr::eq(value, crate::typeref::TRUE) {
+                        Projection::All
+                    } else {
+                        Self::from_pyobject_impl(value, depth + 1)?
+                    };
+                    keys.push((Self::key_from_pyobject(key)?, projection));
+                }
+            } else {
+                cold_path!();
+                return Err(PROJECTION_TYPE_MESSAGE);
+            }
+        }
+        keys.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        Ok(Projection::Keys(keys))
+    }
+
+    fn key_from_pyobject(ptr: *mut PyObject) -> Result<&'static str, &'static str> {
+        match PyStrRef::from_ptr(ptr) {
+            Ok(key) => key.as_str().ok_or(crate::util::INVALID_STR),
+            Err(_) => Err(PROJECTION_TYPE_MESSAGE),
+        }
+    }
+
+    /// Return the projection of the member named `key` or `None` if the
+    /// member is not to be deserialized.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&Projection> {
+        match self {
+            Projection::All => Some(&Projection::All),
+            Projection::Keys(keys) => keys
+                .binary_search_by(|each| each.0.cmp(key))
+                .ok()
+                .map(|idx| &keys[idx].1),
+        }
+    }
+
+    #[inline]
+    pub fn is_all(&self) -> bool {
+        matches!(self, Projection::All)
+    }
+}
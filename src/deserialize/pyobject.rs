@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2022-2026)
 
+use crate::case::{changes_case, convert_case};
 use crate::ffi::PyStrRef;
+use crate::opt::SNAKE_CASE_KEYS;
 
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
 #[inline(always)]
@@ -41,3 +43,131 @@ pub(crate) fn get_unicode_key(key_str: &str) -> PyStrRef {
 pub(crate) fn get_unicode_key(key_str: &str) -> PyStrRef {
     PyStrRef::from_str(key_str)
 }
+
+/// The key `key_str` converted to snake_case. Keys that are converted are
+/// cached separately from those that are not.
+#[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+#[inline(always)]
+pub(crate) fn get_snake_case_key(key_str: &str) -> PyStrRef {
+    if !changes_case(key_str, SNAKE_CASE_KEYS) {
+        get_unicode_key(key_str)
+    } else if key_str.len() > 64 {
+        cold_path!();
+        PyStrRef::from_str_with_hash(&convert_case(key_str, SNAKE_CASE_KEYS))
+    } else {
+        let hash = xxhash_rust::xxh3::xxh3_64(key_str.as_bytes());
+        unsafe {
+            let entry = crate::deserialize::cache::SNAKE_CASE_KEY_MAP
+                .get_mut()
+                .unwrap_or_else(|| unreachable_unchecked!())
+                .entry(&hash)
+                .or_insert_with(
+                    || hash,
+                    || {
+                        crate::deserialize::cache::CachedKey::new(PyStrRef::from_str_with_hash(
+                            &convert_case(key_str, SNAKE_CASE_KEYS),
+                        ))
+                    },
+                );
+            entry.get()
+        }
+    }
+}
+
+#[cfg(all(CPython, Py_GIL_DISABLED))]
+#[inline(always)]
+pub(crate) fn get_snake_case_key(key_str: &str) -> PyStrRef {
+    if changes_case(key_str, SNAKE_CASE_KEYS) {
+        PyStrRef::from_str_with_hash(&convert_case(key_str, SNAKE_CASE_KEYS))
+    } else {
+        PyStrRef::from_str_with_hash(key_str)
+    }
+}
+
+#[cfg(not(CPython))]
+#[inline(always)]
+pub(crate) fn get_snake_case_key(key_str: &str) -> PyStrRef {
+    if changes_case(key_str, SNAKE_CASE_KEYS) {
+        PyStrRef::from_str(&convert_case(key_str, SNAKE_CASE_KEYS))
+    } else {
+        PyStrRef::from_str(key_str)
+    }
+}
+
+/// Create an aware `datetime.datetime` with the fixed UTC offset of
+/// `offset` seconds, or `None`, with no exception set, if a field is out of
+/// range.
+#[cold]
+#[inline(never)]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn datetime_from_parts(
+    year: i32,
+    month: i32,
+    day: i32,
+    hour: i32,
+    minute: i32,
+    second: i32,
+    microsecond: i32,
+    offset: i32,
+) -> Option<core::ptr::NonNull<crate::ffi::PyObject>> {
+    unsafe {
+        crate::ffi::PyDateTime_IMPORT();
+        let api = crate::ffi::PyDateTimeAPI();
+        let tzinfo = if offset == 0 {
+            crate::ffi::PyDateTime_TimeZone_UTC()
+        } else {
+            let delta = ((*api).Delta_FromDelta)(0, offset, 0, 1, (*api).DeltaType);
+            if delta.is_null() {
+                crate::ffi::PyErr_Clear();
+                return None;
+            }
+            let tzinfo = ((*api).TimeZone_FromTimeZone)(delta, core::ptr::null_mut());
+            crate::ffi::Py_DECREF(delta);
+            if tzinfo.is_null() {
+                crate::ffi::PyErr_Clear();
+                return None;
+            }
+            tzinfo
+        };
+        let ptr = ((*api).DateTime_FromDateAndTime)(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            microsecond,
+            tzinfo,
+            (*api).DateTimeType,
+        );
+        if offset != 0 {
+            crate::ffi::Py_DECREF(tzinfo);
+        }
+        if ptr.is_null() {
+            crate::ffi::PyErr_Clear();
+            return None;
+        }
+        Some(nonnull!(ptr))
+    }
+}
+
+/// Create a `datetime.datetime` in UTC of `timestamp`, or `None` if it is
+/// before year 1.
+pub(crate) fn datetime_from_timestamp(
+    timestamp: jiff::Timestamp,
+) -> Option<core::ptr::NonNull<crate::ffi::PyObject>> {
+    let datetime = timestamp.to_zoned(jiff::tz::TimeZone::UTC).datetime();
+    if datetime.year() < 1 {
+        return None;
+    }
+    datetime_from_parts(
+        i32::from(datetime.year()),
+        i32::from(datetime.month()),
+        i32::from(datetime.day()),
+        i32::from(datetime.hour()),
+        i32::from(datetime.minute()),
+        i32::from(datetime.second()),
+        datetime.subsec_nanosecond() / 1000,
+        0,
+    )
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::backend::{PoolBuffer, deserialize, deserialize_with_pool};
+use super::input::bytes_from_pyobject;
+#[cfg(CPython)]
+use super::scan::Latin1;
+use super::scan::{Unit, skip_whitespace, value_end};
+#[cfg(CPython)]
+use crate::ffi::StrUnits;
+use crate::ffi::{PyStrRef, is_valid_utf8};
+use crate::util::INVALID_STR;
+use core::ptr::NonNull;
+use std::borrow::Cow;
+
+type Decoded = Result<(NonNull<crate::ffi::PyObject>, usize), DeserializeError<'static>>;
+
+#[cold]
+fn out_of_range() -> DeserializeError<'static> {
+    DeserializeError::invalid(Cow::Borrowed("idx is out of range"))
+}
+
+/// Return the offset one past the end of the value beginning at `begin`. If
+/// the value does not end, this is the end of `buf`, and if no value begins
+/// there, the end of its first character, so that parsing the range reports
+/// the error.
+fn document_end<U: Unit>(buf: &[U], begin: usize) -> usize {
+    match value_end(buf, begin) {
+        Some(end) => end,
+        None if matches!(buf[begin].ascii(), b'{' | b'[' | b'"') => buf.len(),
+        None => {
+            let mut end = begin + 1;
+            while end < buf.len() && buf[end].ascii() & 0xc0 == 0x80 {
+                end += 1;
+            }
+            end
+        }
+    }
+}
+
+/// Make an error at the byte offset `pos` in `buf`. It is reported at the
+/// offset in characters if all of `buf` is UTF-8 and otherwise in bytes.
+#[cold]
+#[allow(clippy::cast_possible_wrap)]
+fn error_in_bytes(
+    message: Cow<'_, str>,
+    pos: usize,
+    buf: &'static [u8],
+) -> DeserializeError<'static> {
+    let message = Cow::Owned(message.into_owned());
+    match core::str::from_utf8(buf) {
+        Ok(data) => DeserializeError::from_yyjson(message, pos as i64, data),
+        Err(_) => DeserializeError::from_binary(message, pos),
+    }
+}
+
+/// Deserialize the value at or after the byte offset `idx` in `buf`. Only the
+/// value is validated as UTF-8, so that decoding a buffer of many values one
+/// at a time takes time proportional to its length.
+fn decode_bytes(buf: &'static [u8], idx: usize) -> Decoded {
+    if idx > buf.len() {
+        return Err(out_of_range());
+    }
+    let begin = skip_whitespace(buf, idx);
+    if begin == buf.len() {
+        cold_path!();
+        return Err(error_in_bytes(
+            Cow::Borrowed("unexpected end of data"),
+            begin,
+            buf,
+        ));
+    }
+    let end = document_end(buf, begin);
+    if !is_valid_utf8(&buf[begin..end]) {
+        cold_path!();
+        return Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR)));
+    }
+    let doc = unsafe { core::str::from_utf8_unchecked(&buf[begin..end]) };
+    #[allow(clippy::cast_sign_loss)]
+    let obj = deserialize(doc).map_err(|err| match err.data {
+        Some(_) => error_in_bytes(err.message, begin + err.pos as usize, buf),
+        None => err,
+    })?;
+    Ok((obj, end))
+}
+
+/// Make an error at the offset `pos` in characters in `ob`.
+#[cold]
+#[allow(clippy::cast_possible_wrap)]
+fn error_in_str(ob: PyStrRef, message: Cow<'_, str>, pos: usize) -> DeserializeError<'static> {
+    let message = Cow::Owned(message.into_owned());
+    match ob.as_str() {
+        Some(data) => {
+            let offset = data
+                .char_indices()
+                .nth(pos)
+                .map_or(data.len(), |(offset, _)| offset);
+            DeserializeError::from_yyjson(message, offset as i64, data)
+        }
+        None => {
+            ffi!(PyErr_Clear());
+            DeserializeError::from_binary(message, pos)
+        }
+    }
+}
+
+/// Deserialize the value at or after the character `idx` of `ob`, whose code
+/// points are `units`. Only the value is encoded as UTF-8.
+#[cfg(CPython)]
+fn decode_units<U: Unit>(ob: PyStrRef, units: &[U], idx: usize) -> Decoded
+where
+    u32: From<U>,
+{
+    if idx > units.len() {
+        return Err(out_of_range());
+    }
+    let begin = skip_whitespace(units, idx);
+    if begin == units.len() {
+        cold_path!();
+        return Err(error_in_str(
+            ob,
+            Cow::Borrowed("unexpected end of data"),
+            begin,
+        ));
+    }
+    let end = document_end(units, begin);
+    let mut doc = String::with_capacity(end - begin);
+    for unit in &units[begin..end] {
+        match char::from_u32(u32::from(*unit)) {
+            Some(ch) => doc.push(ch),
+            None => {
+                cold_path!();
+                return Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR)));
+            }
+        }
+    }
+    let mut pool = PoolBuffer::new();
+    #[allow(clippy::cast_sign_loss)]
+    let obj = deserialize_with_pool(&doc, &mut pool).map_err(|err| match err.data {
+        Some(data) => {
+            let pos = begin + data[..err.pos as usize].chars().count();
+            error_in_str(ob, err.message, pos)
+        }
+        None => DeserializeError {
+            message: Cow::Owned(err.message.into_owned()),
+            data: None,
+            pos: err.pos,
+        },
+    })?;
+    Ok((obj, end))
+}
+
+#[cfg(CPython)]
+fn decode_str(ob: PyStrRef, idx: usize) -> Decoded {
+    match ob.as_units() {
+        StrUnits::Ascii(data) => decode_bytes(data.as_bytes(), idx),
+        StrUnits::Latin1(units) => {
+            let units = unsafe { &*(core::ptr::from_ref(units) as *const [Latin1]) };
+            decode_units(ob, units, idx)
+        }
+        StrUnits::Ucs2(units) => decode_units(ob, units, idx),
+        StrUnits::Ucs4(units) => decode_units(ob, units, idx),
+    }
+}
+
+#[cfg(not(CPython))]
+fn decode_str(ob: PyStrRef, idx: usize) -> Decoded {
+    let data = match ob.as_str() {
+        Some(data) => data,
+        None => return Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR))),
+    };
+    let start = match data.char_indices().map(|(offset, _)| offset).nth(idx) {
+        Some(start) => start,
+        None if idx == data.chars().count() => data.len(),
+        None => return Err(out_of_range()),
+    };
+    let (obj, end) = decode_bytes(data.as_bytes(), start)?;
+    Ok((obj, idx + data[start..end].chars().count()))
+}
+
+/// Deserialize the first JSON value in `ptr` at or after `idx` and return it
+/// with the offset one past its end. Content after the value is ignored.
+/// Offsets are in characters if `ptr` is a `str` and in bytes otherwise.
+pub(crate) fn raw_decode(ptr: *mut crate::ffi::PyObject, idx: usize) -> Decoded {
+    match PyStrRef::from_ptr(ptr) {
+        Ok(ob) => decode_str(ob, idx),
+        Err(_) => decode_bytes(bytes_from_pyobject(ptr)?, idx),
+    }
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::backend::{Kind, PoolBuffer, Value, read_raw};
+use super::input::Utf8Buffer;
+use crate::opt::{APPEND_NEWLINE, INDENT_2, Opt, SORT_KEYS};
+use crate::serialize::writer::{BytesWriter, to_writer, to_writer_pretty};
+use core::ptr::NonNull;
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// Options `reformat()` accepts.
+pub(crate) const REFORMAT_OPT: Opt = APPEND_NEWLINE | INDENT_2 | SORT_KEYS;
+
+/// Write a value in a document parsed by `read_raw()`.
+pub(crate) struct ValueSerializer<'p> {
+    val: Value<'p>,
+    sort_keys: bool,
+}
+
+impl<'p> ValueSerializer<'p> {
+    pub fn new(val: Value<'p>, sort_keys: bool) -> Self {
+        ValueSerializer {
+            val: val,
+            sort_keys: sort_keys,
+        }
+    }
+}
+
+impl Serialize for ValueSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.val.kind() {
+            Kind::Null => serializer.serialize_unit(),
+            Kind::Bool(val) => serializer.serialize_bool(val),
+            Kind::Number(text) => serializer.serialize_bytes(text.as_bytes()),
+            Kind::Str(val) => serializer.serialize_str(val),
+            Kind::Array => {
+                let mut seq = serializer.serialize_seq(None)?;
+                for each in self.val.elements() {
+                    seq.serialize_element(&ValueSerializer::new(each, self.sort_keys))?;
+                }
+                seq.end()
+            }
+            Kind::Object => {
+                let mut map = serializer.serialize_map(None)?;
+                if self.sort_keys {
+                    let mut members: Vec<(&str, Value)> = self.val.members().collect();
+                    members.sort_by(|a, b| a.0.cmp(b.0));
+                    for (key, each) in members {
+                        map.serialize_key(key)?;
+                        map.serialize_value(&ValueSerializer::new(each, true))?;
+                    }
+                } else {
+                    for (key, each) in self.val.members() {
+                        map.serialize_key(key)?;
+                        map.serialize_value(&ValueSerializer::new(each, false))?;
+                    }
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// Parse the document `ptr` and write it to `bytes` formatted by `opts`
+/// without creating Python objects for its values.
+pub(crate) fn reformat(
+    ptr: *mut crate::ffi::PyObject,
+    opts: Opt,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    debug_assert!(opts & !REFORMAT_OPT == 0);
+    let buffer = Utf8Buffer::from_pyobject(ptr)?;
+    let mut pool = PoolBuffer::new();
+    let root = read_raw(buffer.as_str(), &mut pool)?;
+    let val = ValueSerializer::new(root, opt_enabled!(opts, SORT_KEYS));
+    let mut buf = BytesWriter::default();
+    let res = if opt_disabled!(opts, INDENT_2) {
+        to_writer(&mut buf, &val)
+    } else {
+        to_writer_pretty(&mut buf, &val)
+    };
+    debug_assert!(res.is_ok());
+    Ok(buf.finish(opt_enabled!(opts, APPEND_NEWLINE)))
+}
@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+/// A unit of scanned text: a byte of UTF-8, or a code point of a `str` as
+/// stored in 1, 2, or 4 bytes. Only ASCII is significant to the scan.
+pub(crate) trait Unit: Copy {
+    /// Return the unit if it is ASCII, a byte of UTF-8 as is, and otherwise
+    /// `0xff`, which is not ASCII and does not continue a UTF-8 sequence.
+    fn ascii(self) -> u8;
+}
+
+impl Unit for u8 {
+    #[inline(always)]
+    fn ascii(self) -> u8 {
+        self
+    }
+}
+
+/// A code point of a `str` stored in one byte, which is a whole character
+/// even if it is not ASCII.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub(crate) struct Latin1(pub u8);
+
+impl Unit for Latin1 {
+    #[inline(always)]
+    fn ascii(self) -> u8 {
+        if self.0 < 0x80 { self.0 } else { 0xff }
+    }
+}
+
+impl From<Latin1> for u32 {
+    fn from(val: Latin1) -> u32 {
+        u32::from(val.0)
+    }
+}
+
+impl Unit for u16 {
+    #[inline(always)]
+    fn ascii(self) -> u8 {
+        if self < 0x80 { self as u8 } else { 0xff }
+    }
+}
+
+impl Unit for u32 {
+    #[inline(always)]
+    fn ascii(self) -> u8 {
+        if self < 0x80 { self as u8 } else { 0xff }
+    }
+}
+
+#[inline(always)]
+const fn is_whitespace(val: u8) -> bool {
+    matches!(val, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Return the offset of the first unit at or after `idx` that is not JSON
+/// whitespace.
+pub(crate) fn skip_whitespace<U: Unit>(buf: &[U], mut idx: usize) -> usize {
+    while idx < buf.len() && is_whitespace(buf[idx].ascii()) {
+        idx += 1;
+    }
+    idx
+}
+
+/// Return the offset one past the end of the JSON value beginning at `idx`.
+///
+/// This only matches the structure of the document, i.e., string delimiters,
+/// container nesting, the bytes of `true`, `false`, and `null`, and the
+/// longest prefix of a number that matches the JSON number grammar. It does
+/// not validate the contents and the caller must parse the returned range to
+/// find out if it is valid JSON. The returned offset is always at a character
+/// boundary. `None` is returned if the input ends before the value or if the
+/// value does not begin with a character that can start a JSON value.
+pub(crate) fn value_end<U: Unit>(buf: &[U], idx: usize) -> Option<usize> {
+    debug_assert!(idx < buf.len());
+    match buf[idx].ascii() {
+        b'{' | b'[' | b'"' => Scan::new(idx).resume(buf),
+        b'-' | b'0'..=b'9' => Some(number_prefix_end(buf, idx)),
+        b't' => Some(literal_prefix_end(buf, idx, b"true")),
+        b'f' => Some(literal_prefix_end(buf, idx, b"false")),
+        b'n' => Some(literal_prefix_end(buf, idx, b"null")),
+        _ => None,
+    }
+}
+
+/// Return the offset one past the longest prefix of `buf` at `idx` that
+/// matches `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`, so that
+/// `"1-2"` ends after `"1"` and `"5.0.1"` after `"5.0"`. A fraction or
+/// exponent without digits is not part of it. If there is no digit after a
+/// leading `-`, this is one past the `-`, so parsing the range reports an
+/// invalid number.
+fn number_prefix_end<U: Unit>(buf: &[U], idx: usize) -> usize {
+    let digits = |mut end: usize| {
+        while end < buf.len() && buf[end].ascii().is_ascii_digit() {
+            end += 1;
+        }
+        end
+    };
+    let at = |end: usize| buf.get(end).map(|val| val.ascii());
+    let mut end = idx;
+    if at(end) == Some(b'-') {
+        end += 1;
+    }
+    match at(end) {
+        Some(b'0') => end += 1,
+        Some(b'1'..=b'9') => end = digits(end + 1),
+        _ => return end,
+    }
+    if at(end) == Some(b'.') && at(end + 1).is_some_and(|val| val.is_ascii_digit()) {
+        end = digits(end + 1);
+    }
+    if matches!(at(end), Some(b'e' | b'E')) {
+        let mut exp = end + 1;
+        if matches!(at(exp), Some(b'+' | b'-')) {
+            exp += 1;
+        }
+        if at(exp).is_some_and(|val| val.is_ascii_digit()) {
+            end = digits(exp);
+        }
+    }
+    end
+}
+
+/// Return the offset one past the end of `literal` beginning at `idx`, or of
+/// as much of it as `buf` contains. If `buf` differs from `literal`, this is
+/// one past the first character that differs, so parsing the range reports
+/// an invalid literal.
+fn literal_prefix_end<U: Unit>(buf: &[U], idx: usize, literal: &[u8]) -> usize {
+    let mut end = idx;
+    for expected in literal {
+        if end == buf.len() {
+            break;
+        }
+        end += 1;
+        if buf[end - 1].ascii() != *expected {
+            while end < buf.len() && buf[end].ascii() & 0xc0 == 0x80 {
+                end += 1;
+            }
+            break;
+        }
+    }
+    end
+}
+
+/// Iterator of the text of each number in a valid document, in order.
+pub(crate) struct Numbers<'a> {
+    buf: &'a str,
+    idx: usize,
+}
+
+impl<'a> Numbers<'a> {
+    pub fn new(buf: &'a str) -> Self {
+        Numbers { buf: buf, idx: 0 }
+    }
+}
+
+impl<'a> Iterator for Numbers<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let buf = self.buf.as_bytes();
+        while self.idx < buf.len() {
+            match buf[self.idx] {
+                b'"' => self.idx = value_end(buf, self.idx)?,
+                b'-' | b'0'..=b'9' => {
+                    let start = self.idx;
+                    self.idx = value_end(buf, start)?;
+                    return Some(&self.buf[start..self.idx]);
+                }
+                _ => self.idx += 1,
+            }
+        }
+        None
+    }
+}
+
+/// Structural scan of a string or container that can be resumed if the input
+/// ends before the value does.
+pub(crate) struct Scan {
+    pos: usize,
+    depth: usize,
+    in_string: bool,
+}
+
+impl Scan {
+    pub const fn new(idx: usize) -> Self {
+        Scan {
+            pos: idx,
+            depth: 0,
+            in_string: false,
+        }
+    }
+
+    /// Adjust for `count` bytes having been removed from the start of the
+    /// input.
+    pub fn rebase(&mut self, count: usize) {
+        debug_assert!(self.pos >= count);
+        self.pos -= count;
+    }
+
+    /// Continue scanning `buf` and return the offset one past the end of the
+    /// value. `None` is returned if `buf` ends first, and this may be called
+    /// again with the same input extended.
+    pub fn resume<U: Unit>(&mut self, buf: &[U]) -> Option<usize> {
+        while self.pos < buf.len() {
+            let val = buf[self.pos].ascii();
+            if self.in_string {
+                match val {
+                    b'\\' => {
+                        if self.pos + 1 == buf.len() {
+                            return None;
+                        }
+                        self.pos += 2;
+                        continue;
+                    }
+                    b'"' => {
+                        self.in_string = false;
+                        if self.depth == 0 {
+                            self.pos += 1;
+                            return Some(self.pos);
+                        }
+                    }
+                    _ => {}
+                }
+            } else {
+                match val {
+                    b'{' | b'[' => self.depth += 1,
+                    b'}' | b']' => {
+                        debug_assert!(self.depth > 0);
+                        self.depth -= 1;
+                        if self.depth == 0 {
+                            self.pos += 1;
+                            return Some(self.pos);
+                        }
+                    }
+                    b'"' => self.in_string = true,
+                    _ => {}
+                }
+            }
+            self.pos += 1;
+        }
+        None
+    }
+}
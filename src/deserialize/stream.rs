@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::DeserializeError;
+use super::backend::{PoolBuffer, deserialize_with_pool};
+use super::scan::{Scan, skip_whitespace, value_end};
+use crate::ffi::{Py_DECREF, PyListRef, PyObject, is_valid_utf8};
+use crate::util::INVALID_STR;
+use core::ptr::NonNull;
+use std::borrow::Cow;
+
+/// Input that is buffered until it contains a complete document and the
+/// allocation used to parse each document.
+pub(crate) struct StreamDecoder {
+    buffer: Vec<u8>,
+    consumed: usize,
+    pending: Option<Scan>,
+    /// The buffered input begins with an invalid document that a call
+    /// returning the documents before it found, and this is the `last` of
+    /// that call, so that the next call raises its error.
+    failed: Option<bool>,
+    pool: PoolBuffer,
+}
+
+/// Return true if a scalar in the rest of the buffered input, `token`, may be
+/// continued by more input, e.g., `12` followed by `3`, or `1.` followed by
+/// `5`.
+fn is_pending_scalar(token: &[u8]) -> bool {
+    let literal: &[u8] = match token[0] {
+        b'-' | b'0'..=b'9' => {
+            return token
+                .iter()
+                .all(|val| matches!(val, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'));
+        }
+        b't' => b"true",
+        b'f' => b"false",
+        b'n' => b"null",
+        _ => return false,
+    };
+    token.len() < literal.len() && literal.starts_with(token)
+}
+
+/// Return true if `doc` is not valid UTF-8 only because it ends partway
+/// through a character.
+fn is_incomplete_utf8(doc: &[u8]) -> bool {
+    core::str::from_utf8(doc).is_err_and(|err| err.error_len().is_none())
+}
+
+/// Return the longest prefix of `buf` that is valid UTF-8.
+fn valid_prefix(buf: &[u8]) -> &str {
+    match core::str::from_utf8(buf) {
+        Ok(as_str) => as_str,
+        Err(err) => unsafe { core::str::from_utf8_unchecked(&buf[..err.valid_up_to()]) },
+    }
+}
+
+impl StreamDecoder {
+    pub fn new() -> Self {
+        StreamDecoder {
+            buffer: Vec::new(),
+            consumed: 0,
+            pending: None,
+            failed: None,
+            pool: PoolBuffer::new(),
+        }
+    }
+
+    /// Append `chunk` to the buffered input and return a `list` of every
+    /// document completed by it. If `last` is true, there is no more input
+    /// and any buffered content that is not a complete document is an error.
+    ///
+    /// The position of an error is relative to the start of the document
+    /// that failed. If documents before it were completed by the same call,
+    /// they are returned and the error is instead returned by the next call,
+    /// whose `chunk` is ignored.
+    pub fn feed(
+        &mut self,
+        chunk: &[u8],
+        last: bool,
+    ) -> Result<NonNull<PyObject>, DeserializeError<'_>> {
+        let (chunk, last) = match self.failed {
+            Some(failed_last) => (&[][..], failed_last),
+            None => (chunk, last),
+        };
+        self.buffer.drain(..self.consumed);
+        if let Some(scan) = self.pending.as_mut() {
+            scan.rebase(self.consumed);
+        }
+        self.consumed = 0;
+        self.buffer.extend_from_slice(chunk);
+        let buf = self.buffer.as_slice();
+        let mut values: Vec<NonNull<PyObject>> = Vec::new();
+        let mut pos = 0;
+        loop {
+            let begin = skip_whitespace(buf, pos);
+            if begin == buf.len() {
+                pos = begin;
+                break;
+            }
+            let end = if matches!(buf[begin], b'{' | b'[' | b'"') {
+                let mut scan = self.pending.take().unwrap_or(Scan::new(begin));
+                let end = scan.resume(buf);
+                if end.is_none() && !last {
+                    self.pending = Some(scan);
+                    pos = begin;
+                    break;
+                }
+                end
+            } else {
+                let end = value_end(buf, begin);
+                if !last && is_pending_scalar(&buf[begin..]) {
+                    pos = begin;
+                    break;
+                }
+                end
+            };
+            let parsed = match end {
+                None => Err(Self::error_at(&buf[begin..], &mut self.pool)),
+                Some(end) if !is_valid_utf8(&buf[begin..end]) => {
+                    cold_path!();
+                    if !last && end == buf.len() && is_incomplete_utf8(&buf[begin..end]) {
+                        pos = begin;
+                        break;
+                    }
+                    Err(DeserializeError::invalid(Cow::Borrowed(INVALID_STR)))
+                }
+                Some(end) => deserialize_with_pool(
+                    unsafe { core::str::from_utf8_unchecked(&buf[begin..end]) },
+                    &mut self.pool,
+                )
+                .map(|obj| (obj, end)),
+            };
+            match parsed {
+                Ok((obj, end)) => {
+                    values.push(obj);
+                    pos = end;
+                }
+                Err(err) if values.is_empty() => {
+                    cold_path!();
+                    return Err(err);
+                }
+                Err(_) => {
+                    cold_path!();
+                    pos = begin;
+                    self.failed = Some(last);
+                    break;
+                }
+            }
+        }
+        self.consumed = pos;
+
+        let mut list = PyListRef::with_capacity(values.len());
+        for (idx, each) in values.into_iter().enumerate() {
+            list.set(idx, each.as_ptr());
+        }
+        Ok(list.as_non_null_ptr())
+    }
+
+    /// Return the error from parsing the invalid or incomplete document at
+    /// the start of `buf`.
+    #[cold]
+    fn error_at<'a>(buf: &'a [u8], pool: &mut PoolBuffer) -> DeserializeError<'a> {
+        let data = valid_prefix(buf);
+        if data.is_empty() {
+            return DeserializeError::invalid(Cow::Borrowed(INVALID_STR));
+        }
+        match deserialize_with_pool(data, pool) {
+            Err(err) => err,
+            Ok(obj) => {
+                unsafe {
+                    Py_DECREF(obj.as_ptr());
+                }
+                #[allow(clippy::cast_possible_wrap)]
+                DeserializeError::from_yyjson(
+                    Cow::Borrowed("unexpected end of data"),
+                    data.len() as i64,
+                    data,
+                )
+            }
+        }
+    }
+}
@@ -4,7 +4,10 @@
 use core::ptr::null_mut;
 
 use crate::deserialize::DeserializeError;
-use crate::ffi::{Py_DECREF, PyErr_SetObject, PyIntRef, PyObject, PyStrRef, PyTupleRef};
+use crate::ffi::{
+    Py_DECREF, PyErr_SetObject, PyExc_IndexError, PyExc_KeyError, PyExc_TypeError,
+    PyExc_ValueError, PyIntRef, PyObject, PyStrRef, PyTupleRef,
+};
 use crate::typeref::{JsonDecodeError, JsonEncodeError};
 
 #[cold]
@@ -28,6 +31,52 @@ pub(crate) fn raise_loads_exception(err: DeserializeError) -> *mut PyObject {
     null_mut()
 }
 
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn raise_args_exception(msg: &str) -> *mut PyObject {
+    unsafe {
+        let err_msg = PyStrRef::from_str(msg);
+        PyErr_SetObject(PyExc_TypeError, err_msg.as_ptr());
+        Py_DECREF(err_msg.as_ptr());
+    }
+    null_mut()
+}
+
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn raise_key_exception(key: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        PyErr_SetObject(PyExc_KeyError, key);
+    }
+    null_mut()
+}
+
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn raise_index_exception(msg: &str) -> *mut PyObject {
+    unsafe {
+        let err_msg = PyStrRef::from_str(msg);
+        PyErr_SetObject(PyExc_IndexError, err_msg.as_ptr());
+        Py_DECREF(err_msg.as_ptr());
+    }
+    null_mut()
+}
+
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn raise_value_exception(msg: &str) -> *mut PyObject {
+    unsafe {
+        let err_msg = PyStrRef::from_str(msg);
+        PyErr_SetObject(PyExc_ValueError, err_msg.as_ptr());
+        Py_DECREF(err_msg.as_ptr());
+    }
+    null_mut()
+}
+
 #[cold]
 #[inline(never)]
 #[cfg_attr(feature = "optimize", optimize(size))]
@@ -54,8 +54,12 @@ pub(crate) use {
     pytimeref::PyTimeRef,
     pytupleref::PyTupleRef,
     pyuuidref::PyUuidRef,
+    utf8::is_valid_utf8,
 };
 
+#[cfg(CPython)]
+pub(crate) use pystrref::StrUnits;
+
 #[allow(unused_imports)]
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
 pub(crate) use {
@@ -65,39 +69,55 @@ pub(crate) use {
 
 #[allow(unused_imports)]
 pub(crate) use pyo3_ffi::{
-    METH_FASTCALL, METH_KEYWORDS, METH_O, Py_DECREF, Py_False, Py_INCREF, Py_None, Py_REFCNT,
-    Py_TPFLAGS_DEFAULT, Py_TPFLAGS_DICT_SUBCLASS, Py_TPFLAGS_IMMUTABLETYPE,
+    _PyLong_FromByteArray, _PyLong_NumBits, METH_FASTCALL, METH_KEYWORDS, METH_NOARGS, METH_O,
+    Py_DECREF, Py_EQ, Py_EnterRecursiveCall, Py_False, Py_INCREF, Py_LT, Py_LeaveRecursiveCall,
+    Py_None, Py_REFCNT, Py_TPFLAGS_DEFAULT, Py_TPFLAGS_DICT_SUBCLASS,
+    Py_TPFLAGS_DISALLOW_INSTANTIATION, Py_TPFLAGS_HEAPTYPE, Py_TPFLAGS_IMMUTABLETYPE,
     Py_TPFLAGS_LIST_SUBCLASS, Py_TPFLAGS_LONG_SUBCLASS, Py_TPFLAGS_TUPLE_SUBCLASS,
     Py_TPFLAGS_UNICODE_SUBCLASS, Py_TYPE, Py_True, Py_XDECREF, Py_buffer, Py_hash_t, Py_intptr_t,
-    Py_mod_exec, Py_ssize_t, Py_tp_dealloc, Py_tp_new, PyASCIIObject, PyBool_Type,
-    PyBuffer_IsContiguous, PyByteArray_AsString, PyByteArray_Size, PyByteArray_Type,
+    Py_mod_exec, Py_mp_length, Py_mp_subscript, Py_ssize_t, Py_tp_dealloc, Py_tp_doc, Py_tp_iter,
+    Py_tp_iternext, Py_tp_methods, Py_tp_new, PyASCIIObject, PyAnySet_Check, PyBaseObject_Type,
+    PyBool_Type, PyBuffer_IsContiguous, PyByteArray_AsString, PyByteArray_Size, PyByteArray_Type,
     PyBytes_FromStringAndSize, PyBytes_Type, PyCFunction_NewEx, PyCapsule_Import,
     PyCompactUnicodeObject, PyDateTime_CAPI, PyDateTime_DATE_GET_HOUR,
     PyDateTime_DATE_GET_MICROSECOND, PyDateTime_DATE_GET_MINUTE, PyDateTime_DATE_GET_SECOND,
     PyDateTime_DATE_GET_TZINFO, PyDateTime_DELTA_GET_DAYS, PyDateTime_DELTA_GET_SECONDS,
     PyDateTime_DateTime, PyDateTime_GET_DAY, PyDateTime_GET_MONTH, PyDateTime_GET_YEAR,
     PyDateTime_IMPORT, PyDateTime_TIME_GET_HOUR, PyDateTime_TIME_GET_MICROSECOND,
-    PyDateTime_TIME_GET_MINUTE, PyDateTime_TIME_GET_SECOND, PyDateTime_Time, PyDict_Contains,
-    PyDict_Next, PyDict_SetItem, PyDict_Type, PyDictObject, PyErr_Clear, PyErr_NewException,
-    PyErr_Occurred, PyErr_SetObject, PyExc_TypeError, PyException_SetCause, PyFloat_AS_DOUBLE,
-    PyFloat_FromDouble, PyFloat_Type, PyImport_ImportModule, PyList_GET_ITEM, PyList_New,
-    PyList_SET_ITEM, PyList_Type, PyListObject, PyLong_AsLong, PyLong_AsLongLong,
-    PyLong_AsUnsignedLongLong, PyLong_FromLongLong, PyLong_FromUnsignedLongLong, PyLong_Type,
-    PyLongObject, PyMapping_GetItemString, PyMem_Free, PyMem_Malloc, PyMem_Realloc,
-    PyMemoryView_Type, PyMethodDef, PyMethodDefPointer, PyModule_AddIntConstant,
-    PyModule_AddObject, PyModuleDef, PyModuleDef_HEAD_INIT, PyModuleDef_Init, PyModuleDef_Slot,
-    PyObject, PyObject_CallFunctionObjArgs, PyObject_CallMethodObjArgs, PyObject_GenericGetDict,
-    PyObject_GetAttr, PyObject_HasAttr, PyObject_Hash, PyObject_Vectorcall, PyTuple_New,
-    PyTuple_Type, PyTupleObject, PyType_FromSpec, PyType_Slot, PyType_Spec, PyTypeObject,
-    PyUnicode_AsUTF8AndSize, PyUnicode_FromStringAndSize, PyUnicode_InternFromString,
-    PyUnicode_New, PyUnicode_Type, PyVarObject, PyVectorcall_NARGS,
+    PyDateTime_TIME_GET_MINUTE, PyDateTime_TIME_GET_SECOND, PyDateTime_Time,
+    PyDateTime_TimeZone_UTC, PyDateTimeAPI, PyDict_Check, PyDict_Contains, PyDict_Copy,
+    PyDict_DelItem, PyDict_GetItemWithError, PyDict_Merge, PyDict_Next, PyDict_SetItem,
+    PyDict_Size, PyDict_Type, PyDictObject, PyErr_Clear, PyErr_NewException, PyErr_Occurred,
+    PyErr_SetObject, PyExc_IndexError, PyExc_KeyError, PyExc_TypeError, PyExc_ValueError,
+    PyException_SetCause, PyFloat_AS_DOUBLE, PyFloat_FromDouble, PyFloat_Type, PyFrozenSet_Type,
+    PyImport_GetModuleDict, PyImport_ImportModule, PyIter_Next, PyList_Append, PyList_Check,
+    PyList_GET_ITEM, PyList_Insert, PyList_New, PyList_SET_ITEM, PyList_SetItem, PyList_SetSlice,
+    PyList_Type, PyListObject, PyLong_AsDouble, PyLong_AsLong, PyLong_AsLongLong,
+    PyLong_AsLongLongAndOverflow, PyLong_AsUnsignedLongLong, PyLong_FromLongLong,
+    PyLong_FromString, PyLong_FromUnsignedLongLong, PyLong_Type, PyLongObject,
+    PyMapping_GetItemString, PyMem_Free, PyMem_Malloc, PyMem_Realloc, PyMemoryView_Type,
+    PyMethodDef, PyMethodDefPointer, PyModule_AddIntConstant, PyModule_AddObject, PyModuleDef,
+    PyModuleDef_HEAD_INIT, PyModuleDef_Init, PyModuleDef_Slot, PyNumber_Float, PyNumber_Invert,
+    PyNumber_Negative, PyObject, PyObject_CallFunctionObjArgs, PyObject_CallMethodObjArgs,
+    PyObject_GenericGetDict, PyObject_GetAttr, PyObject_GetItem, PyObject_GetIter,
+    PyObject_HasAttr, PyObject_Hash, PyObject_IsTrue, PyObject_RichCompareBool, PyObject_SelfIter,
+    PyObject_Size, PyObject_Str, PyObject_TypeCheck, PyObject_Vectorcall, PySequence_List,
+    PySet_Type, PyTuple_Check, PyTuple_New, PyTuple_Type, PyTupleObject, PyType_FromSpec,
+    PyType_Slot, PyType_Spec, PyTypeObject, PyUnicode_AsUTF8AndSize, PyUnicode_Check,
+    PyUnicode_Compare, PyUnicode_FromStringAndSize, PyUnicode_InternFromString,
+    PyUnicode_InternInPlace, PyUnicode_New, PyUnicode_Type, PyVarObject, PyVectorcall_NARGS,
+    PyWeakref_NewRef,
 };
 
 #[allow(unused_imports, deprecated)]
 pub(crate) use pyo3_ffi::PyErr_Restore;
 
 #[cfg(CPython)]
-pub(crate) use pyo3_ffi::{PyObject_CallMethodNoArgs, PyObject_CallMethodOneArg};
+pub(crate) use pyo3_ffi::{PyObject_CallMethodNoArgs, PyObject_CallMethodOneArg, PyWeakReference};
+
+#[cfg(not(CPython))]
+#[allow(unused_imports, deprecated)]
+pub(crate) use pyo3_ffi::PyWeakref_GetObject;
 
 #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
 pub(crate) use buffer::PyMemoryView_GET_BUFFER;
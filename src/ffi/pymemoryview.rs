@@ -7,8 +7,14 @@ pub(crate) enum PyMemoryViewRefError {
     NotType,
     NotCContiguous,
     NotSupported,
+    Released,
 }
 
+/// `_Py_MEMORYVIEW_RELEASED`, set in the flags of a `memoryview` once
+/// `release()` is called, after which its buffer must not be read.
+#[cfg(CPython)]
+const MEMORYVIEW_RELEASED: core::ffi::c_int = 0x001;
+
 #[derive(Clone)]
 #[repr(transparent)]
 pub(crate) struct PyMemoryViewRef {
@@ -31,6 +37,10 @@ impl PyMemoryViewRef {
         unsafe {
             debug_assert!(!ptr.is_null());
             if crate::ffi::PyObject_Type(ptr) == &raw mut crate::ffi::PyMemoryView_Type {
+                let flags = (*ptr.cast::<crate::ffi::buffer::PyMemoryViewObject>()).flags;
+                if flags & MEMORYVIEW_RELEASED != 0 {
+                    return Err(PyMemoryViewRefError::Released);
+                }
                 let membuf = crate::ffi::PyMemoryView_GET_BUFFER(ptr);
                 #[allow(clippy::cast_possible_wrap)]
                 if crate::ffi::PyBuffer_IsContiguous(membuf, b'C' as core::ffi::c_char) == 0 {
@@ -10,4 +10,6 @@ mod pyunicode_new;
 #[cfg(CPython)]
 mod scalar;
 
+#[cfg(CPython)]
+pub(crate) use object::StrUnits;
 pub(crate) use object::{PyStrRef, PyStrSubclassRef, set_str_create_fn};
@@ -56,6 +56,15 @@ pub(crate) enum PyStrRefError {
     NotStrType,
 }
 
+/// The code points of a `str` as it stores them.
+#[cfg(CPython)]
+pub(crate) enum StrUnits {
+    Ascii(&'static str),
+    Latin1(&'static [u8]),
+    Ucs2(&'static [u16]),
+    Ucs4(&'static [u32]),
+}
+
 #[derive(Copy, Clone)]
 #[repr(transparent)]
 pub(crate) struct PyStrRef {
@@ -199,6 +208,29 @@ impl PyStrRef {
         }
     }
 
+    /// Return the code points of the string without encoding it as UTF-8,
+    /// so that a part of it can be read in time proportional to the part.
+    #[cfg(CPython)]
+    pub fn as_units(&self) -> StrUnits {
+        unsafe {
+            let op = self.as_ptr();
+            let len = crate::util::isize_to_usize(pyo3_ffi::PyUnicode_GET_LENGTH(op));
+            let data = pyo3_ffi::PyUnicode_DATA(op);
+            match pyo3_ffi::PyUnicode_KIND(op) {
+                pyo3_ffi::PyUnicode_1BYTE_KIND if pyo3_ffi::PyUnicode_IS_ASCII(op) != 0 => {
+                    StrUnits::Ascii(str_from_slice!(data.cast::<u8>(), len))
+                }
+                pyo3_ffi::PyUnicode_1BYTE_KIND => {
+                    StrUnits::Latin1(core::slice::from_raw_parts(data.cast::<u8>(), len))
+                }
+                pyo3_ffi::PyUnicode_2BYTE_KIND => {
+                    StrUnits::Ucs2(core::slice::from_raw_parts(data.cast::<u16>(), len))
+                }
+                _ => StrUnits::Ucs4(core::slice::from_raw_parts(data.cast::<u32>(), len)),
+            }
+        }
+    }
+
     #[inline(always)]
     #[cfg(feature = "inline_str")]
     pub fn as_str(&self) -> Option<&'static str> {
@@ -47,27 +47,41 @@ extern crate unwinding;
 mod util;
 
 mod alloc;
+mod case;
 mod deserialize;
 mod exception;
 mod ffi;
 mod opt;
+mod patch;
+mod query;
 mod serialize;
 mod typeref;
 
 use core::ffi::{c_char, c_int, c_void};
 use core::ptr::{NonNull, null, null_mut};
 
-use crate::deserialize::deserialize;
+use crate::deserialize::{
+    DeserializeError, Projection, REFORMAT_OPT, deserialize, deserialize_cbor, deserialize_msgpack,
+    deserialize_selected, deserialize_with_opts, iter_items as deserialize_items,
+    parse_lazy as deserialize_lazy, raw_decode as deserialize_prefix,
+    reformat as deserialize_reformat, validate as deserialize_validate,
+};
 use crate::exception::{
-    raise_dumps_exception_dynamic, raise_dumps_exception_fixed, raise_loads_exception,
+    raise_args_exception, raise_dumps_exception_dynamic, raise_dumps_exception_fixed,
+    raise_loads_exception, raise_value_exception,
 };
 use crate::ffi::{
-    METH_KEYWORDS, METH_O, Py_SIZE, Py_ssize_t, PyCFunction_NewEx, PyIntRef, PyMethodDef,
+    METH_KEYWORDS, Py_SIZE, Py_ssize_t, PyCFunction_NewEx, PyIntRef, PyMethodDef,
     PyMethodDefPointer, PyModuleDef, PyModuleDef_HEAD_INIT, PyModuleDef_Init, PyModuleDef_Slot,
-    PyNoneRef, PyObject, PyTupleRef, PyUnicode_FromStringAndSize, PyUnicode_InternFromString,
-    PyVectorcall_NARGS,
+    PyNoneRef, PyObject, PyStrRef, PyTupleRef, PyUnicode_FromStringAndSize,
+    PyUnicode_InternFromString, PyVectorcall_NARGS,
+};
+use crate::patch::{
+    apply_patch as patch_apply, create_merge_patch as patch_create_merge, diff as patch_diff,
+    merge_patch as patch_merge, merge_patch_json as patch_merge_json,
 };
-use crate::serialize::serialize;
+use crate::query::query as query_path;
+use crate::serialize::{serialize, serialize_cbor, serialize_msgpack};
 use crate::util::{isize_to_usize, usize_to_isize};
 
 #[cfg(Py_3_13)]
@@ -133,12 +147,93 @@ pub(crate) unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
         }
 
         {
-            let loads_doc = c"loads(obj, /)\n--\n\nDeserialize JSON to Python objects.";
+            let dumps_msgpack_doc = c"dumps_msgpack(obj, /, default=None, option=None)\n--\n\nSerialize Python objects to MessagePack.";
+
+            let wrapped_dumps_msgpack = Box::new(PyMethodDef {
+                ml_name: c"dumps_msgpack".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: dumps_msgpack,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: dumps_msgpack_doc.as_ptr(),
+            });
+
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_dumps_msgpack),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"dumps_msgpack", func);
+        }
+
+        {
+            let loads_msgpack_doc = c"loads_msgpack(obj, /, option=None)\n--\n\nDeserialize MessagePack to Python objects.";
+
+            let wrapped_loads_msgpack = Box::new(PyMethodDef {
+                ml_name: c"loads_msgpack".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: loads_msgpack,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: loads_msgpack_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_loads_msgpack),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"loads_msgpack", func);
+        }
+
+        {
+            let dumps_cbor_doc = c"dumps_cbor(obj, /, default=None, option=None)\n--\n\nSerialize Python objects to CBOR.";
+
+            let wrapped_dumps_cbor = Box::new(PyMethodDef {
+                ml_name: c"dumps_cbor".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: dumps_cbor,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: dumps_cbor_doc.as_ptr(),
+            });
+
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_dumps_cbor),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"dumps_cbor", func);
+        }
+
+        {
+            let loads_cbor_doc = c"loads_cbor(obj, /)\n--\n\nDeserialize CBOR to Python objects.";
+
+            let wrapped_loads_cbor = Box::new(PyMethodDef {
+                ml_name: c"loads_cbor".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: loads_cbor,
+                },
+                ml_flags: crate::ffi::METH_O,
+                ml_doc: loads_cbor_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_loads_cbor),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"loads_cbor", func);
+        }
+
+        {
+            let loads_doc =
+                c"loads(obj, /, *, pointer=None, keys=None)\n--\n\nDeserialize JSON to Python objects.";
 
             let wrapped_loads = Box::new(PyMethodDef {
                 ml_name: c"loads".as_ptr(),
-                ml_meth: PyMethodDefPointer { PyCFunction: loads },
-                ml_flags: METH_O,
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: loads,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
                 ml_doc: loads_doc.as_ptr(),
             });
             let func = PyCFunction_NewEx(
@@ -149,13 +244,233 @@ pub(crate) unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
             add!(mptr, c"loads", func);
         }
 
+        {
+            let raw_decode_doc = c"raw_decode(obj, /, idx=0)\n--\n\nDeserialize the first JSON document in obj and return it with the index where it ends.";
+
+            let wrapped_raw_decode = Box::new(PyMethodDef {
+                ml_name: c"raw_decode".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: raw_decode,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: raw_decode_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_raw_decode),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"raw_decode", func);
+        }
+
+        {
+            let iter_items_doc = c"iter_items(obj, /, prefix='item')\n--\n\nReturn an iterator that deserializes each JSON value at prefix in obj.";
+
+            let wrapped_iter_items = Box::new(PyMethodDef {
+                ml_name: c"iter_items".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: iter_items,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: iter_items_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_iter_items),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"iter_items", func);
+        }
+
+        {
+            let parse_lazy_doc = c"parse_lazy(obj, /)\n--\n\nParse JSON and return a Node that deserializes values when they are accessed.";
+
+            let wrapped_parse_lazy = Box::new(PyMethodDef {
+                ml_name: c"parse_lazy".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: parse_lazy,
+                },
+                ml_flags: crate::ffi::METH_O,
+                ml_doc: parse_lazy_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_parse_lazy),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"parse_lazy", func);
+        }
+
+        {
+            let validate_doc =
+                c"validate(obj, /)\n--\n\nRaise JSONDecodeError if obj is not valid JSON.";
+
+            let wrapped_validate = Box::new(PyMethodDef {
+                ml_name: c"validate".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunction: validate,
+                },
+                ml_flags: crate::ffi::METH_O,
+                ml_doc: validate_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_validate),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"validate", func);
+        }
+
+        {
+            let reformat_doc = c"reformat(obj, /, option=None)\n--\n\nFormat JSON as dumps() would without deserializing it to Python objects.";
+
+            let wrapped_reformat = Box::new(PyMethodDef {
+                ml_name: c"reformat".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: reformat,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: reformat_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_reformat),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"reformat", func);
+        }
+
+        {
+            let merge_patch_doc = c"merge_patch(target, patch, /)\n--\n\nReturn the result of applying the RFC 7396 merge patch patch to target.";
+
+            let wrapped_merge_patch = Box::new(PyMethodDef {
+                ml_name: c"merge_patch".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFast: merge_patch,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL,
+                ml_doc: merge_patch_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_merge_patch),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"merge_patch", func);
+        }
+
+        {
+            let create_merge_patch_doc = c"create_merge_patch(source, target, /)\n--\n\nReturn an RFC 7396 merge patch that transforms source into target.";
+
+            let wrapped_create_merge_patch = Box::new(PyMethodDef {
+                ml_name: c"create_merge_patch".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFast: create_merge_patch,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL,
+                ml_doc: create_merge_patch_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_create_merge_patch),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"create_merge_patch", func);
+        }
+
+        {
+            let merge_patch_json_doc = c"merge_patch_json(target, patch, /, option=None)\n--\n\nApply the RFC 7396 merge patch patch to target, both JSON, without deserializing them to Python objects.";
+
+            let wrapped_merge_patch_json = Box::new(PyMethodDef {
+                ml_name: c"merge_patch_json".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFastWithKeywords: merge_patch_json,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL | METH_KEYWORDS,
+                ml_doc: merge_patch_json_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_merge_patch_json),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"merge_patch_json", func);
+        }
+
+        {
+            let apply_patch_doc = c"apply_patch(doc, ops, /)\n--\n\nReturn the result of applying the RFC 6902 JSON Patch operations ops to doc.";
+
+            let wrapped_apply_patch = Box::new(PyMethodDef {
+                ml_name: c"apply_patch".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFast: apply_patch,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL,
+                ml_doc: apply_patch_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_apply_patch),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"apply_patch", func);
+        }
+
+        {
+            let diff_doc = c"diff(a, b, /)\n--\n\nReturn a list of RFC 6902 JSON Patch operations that transform a into b.";
+
+            let wrapped_diff = Box::new(PyMethodDef {
+                ml_name: c"diff".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFast: diff,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL,
+                ml_doc: diff_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_diff),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"diff", func);
+        }
+
+        {
+            let query_doc = c"query(data, path, /)\n--\n\nReturn a list of the values in data selected by the RFC 9535 JSONPath query path.";
+
+            let wrapped_query = Box::new(PyMethodDef {
+                ml_name: c"query".as_ptr(),
+                ml_meth: PyMethodDefPointer {
+                    PyCFunctionFast: query,
+                },
+                ml_flags: crate::ffi::METH_FASTCALL,
+                ml_doc: query_doc.as_ptr(),
+            });
+            let func = PyCFunction_NewEx(
+                Box::into_raw(wrapped_query),
+                null_mut(),
+                PyUnicode_InternFromString(c"orjson".as_ptr()),
+            );
+            add!(mptr, c"query", func);
+        }
+
+        add!(mptr, c"Decoder", typeref::DECODER_TYPE.cast::<PyObject>());
+        add!(mptr, c"Node", typeref::NODE_TYPE.cast::<PyObject>());
         add!(mptr, c"Fragment", typeref::FRAGMENT_TYPE.cast::<PyObject>());
 
         opt!(mptr, c"OPT_APPEND_NEWLINE", opt::APPEND_NEWLINE);
+        opt!(mptr, c"OPT_CAMEL_CASE_KEYS", opt::CAMEL_CASE_KEYS);
+        opt!(mptr, c"OPT_CANONICAL", opt::CANONICAL);
+        opt!(mptr, c"OPT_CBOR_TYPED_ARRAY", opt::CBOR_TYPED_ARRAY);
         opt!(mptr, c"OPT_INDENT_2", opt::INDENT_2);
+        opt!(mptr, c"OPT_KEBAB_CASE_KEYS", opt::KEBAB_CASE_KEYS);
+        opt!(mptr, c"OPT_MSGPACK_TIMESTAMP", opt::MSGPACK_TIMESTAMP);
         opt!(mptr, c"OPT_NAIVE_UTC", opt::NAIVE_UTC);
         opt!(mptr, c"OPT_NON_STR_KEYS", opt::NON_STR_KEYS);
+        opt!(mptr, c"OPT_OMIT_DEFAULTS", opt::OMIT_DEFAULTS);
         opt!(mptr, c"OPT_OMIT_MICROSECONDS", opt::OMIT_MICROSECONDS);
+        opt!(mptr, c"OPT_OMIT_NONE", opt::OMIT_NONE);
+        opt!(mptr, c"OPT_PASCAL_CASE_KEYS", opt::PASCAL_CASE_KEYS);
         opt!(
             mptr,
             c"OPT_PASSTHROUGH_DATACLASS",
@@ -164,8 +479,12 @@ pub(crate) unsafe extern "C" fn orjson_init_exec(mptr: *mut PyObject) -> c_int {
         opt!(mptr, c"OPT_PASSTHROUGH_DATETIME", opt::PASSTHROUGH_DATETIME);
         opt!(mptr, c"OPT_PASSTHROUGH_SUBCLASS", opt::PASSTHROUGH_SUBCLASS);
         opt!(mptr, c"OPT_SERIALIZE_DATACLASS", opt::SERIALIZE_DATACLASS);
+        opt!(mptr, c"OPT_SERIALIZE_NAMEDTUPLE", opt::SERIALIZE_NAMEDTUPLE);
         opt!(mptr, c"OPT_SERIALIZE_NUMPY", opt::SERIALIZE_NUMPY);
+        opt!(mptr, c"OPT_SERIALIZE_OBJECT", opt::SERIALIZE_OBJECT);
+        opt!(mptr, c"OPT_SERIALIZE_UNDERSCORE", opt::SERIALIZE_UNDERSCORE);
         opt!(mptr, c"OPT_SERIALIZE_UUID", opt::SERIALIZE_UUID);
+        opt!(mptr, c"OPT_SNAKE_CASE_KEYS", opt::SNAKE_CASE_KEYS);
         opt!(mptr, c"OPT_SORT_KEYS", opt::SORT_KEYS);
         opt!(mptr, c"OPT_STRICT_INTEGER", opt::STRICT_INTEGER);
         opt!(mptr, c"OPT_UTC_Z", opt::UTC_Z);
@@ -222,11 +541,6 @@ pub(crate) unsafe extern "C" fn PyInit_orjson() -> *mut PyModuleDef {
     }
 }
 
-#[unsafe(no_mangle)]
-pub(crate) unsafe extern "C" fn loads(_self: *mut PyObject, obj: *mut PyObject) -> *mut PyObject {
-    deserialize(obj).map_or_else(raise_loads_exception, NonNull::as_ptr)
-}
-
 #[cfg(CPython)]
 macro_rules! matches_kwarg {
     ($val:expr, $ref:expr) => {
@@ -242,28 +556,134 @@ macro_rules! matches_kwarg {
 }
 
 #[unsafe(no_mangle)]
-pub(crate) unsafe extern "C" fn dumps(
+pub(crate) unsafe extern "C" fn loads(
     _self: *mut PyObject,
     args: *const *mut PyObject,
     nargs: Py_ssize_t,
     kwnames: *mut PyObject,
 ) -> *mut PyObject {
     unsafe {
-        let mut default: Option<NonNull<PyObject>> = None;
-        let mut optsptr: Option<NonNull<PyObject>> = None;
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args != 1 {
+            cold_path!();
+            if num_args == 0 {
+                return raise_args_exception(
+                    "loads() missing 1 required positional argument: 'obj'",
+                );
+            }
+            return raise_args_exception("loads() takes exactly 1 positional argument");
+        }
+        if kwnames.is_null() {
+            return deserialize(*args).map_or_else(raise_loads_exception, NonNull::as_ptr);
+        }
+
+        cold_path!();
+        let mut pointer: *mut PyObject = typeref::NONE;
+        let mut keys: *mut PyObject = typeref::NONE;
+        let mut optsptr: *mut PyObject = typeref::NONE;
+        let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+        for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+            let arg = kwob.get(i.cast_unsigned());
+            if matches_kwarg!(arg, typeref::POINTER) {
+                pointer = *args.offset(num_args + i);
+            } else if matches_kwarg!(arg, typeref::KEYS) {
+                keys = *args.offset(num_args + i);
+            } else if matches_kwarg!(arg, typeref::OPTION) {
+                optsptr = *args.offset(num_args + i);
+            } else {
+                return raise_args_exception("loads() got an unexpected keyword argument");
+            }
+        }
+
+        let mut opts = 0 as opt::Opt;
+        if !core::ptr::eq(optsptr, typeref::NONE) {
+            match PyIntRef::from_ptr(optsptr).ok().map(|val| val.as_opt()) {
+                Some(Ok(opt)) if opt & !opt::LOADS_OPT == 0 => opts = opt,
+                _ => {
+                    cold_path!();
+                    return raise_loads_exception(DeserializeError::invalid(
+                        std::borrow::Cow::Borrowed("Invalid opts"),
+                    ));
+                }
+            }
+        }
+
+        if core::ptr::eq(pointer, typeref::NONE) && core::ptr::eq(keys, typeref::NONE) {
+            return deserialize_with_opts(*args, opts)
+                .map_or_else(raise_loads_exception, NonNull::as_ptr);
+        }
+        if !core::ptr::eq(pointer, typeref::NONE) && PyStrRef::from_ptr(pointer).is_err() {
+            if !is_type!(crate::ffi::PyObject_Type(pointer), typeref::TUPLE_TYPE) {
+                cold_path!();
+                return raise_args_exception(
+                    "loads() argument 'pointer' must be a str or tuple of str",
+                );
+            }
+            let pointers = PyTupleRef::from_ptr_unchecked(pointer);
+            for idx in 0..pointers.len() {
+                if PyStrRef::from_ptr(pointers.get(idx)).is_err() {
+                    cold_path!();
+                    return raise_args_exception(
+                        "loads() argument 'pointer' must be a str or tuple of str",
+                    );
+                }
+            }
+        }
+        let projection = if core::ptr::eq(keys, typeref::NONE) {
+            Projection::All
+        } else {
+            match Projection::from_pyobject(keys) {
+                Ok(projection) => projection,
+                Err(msg) => return raise_args_exception(msg),
+            }
+        };
+        deserialize_selected(*args, pointer, &projection, opts)
+            .map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn parse_lazy(
+    _self: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    deserialize_lazy(obj).map_or_else(raise_loads_exception, NonNull::as_ptr)
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn validate(
+    _self: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    match deserialize_validate(obj) {
+        Ok(()) => PyNoneRef::none().as_ptr(),
+        Err(err) => raise_loads_exception(err),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn reformat(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut optsptr: *mut PyObject = typeref::NONE;
 
         let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
         if num_args == 0 {
             cold_path!();
-            return raise_dumps_exception_fixed(
-                "dumps() missing 1 required positional argument: 'obj'",
+            return raise_args_exception(
+                "reformat() missing 1 required positional argument: 'obj'",
             );
         }
-        if num_args & 2 == 2 {
-            default = Some(NonNull::new_unchecked(*args.offset(1)));
+        if num_args > 2 {
+            cold_path!();
+            return raise_args_exception("reformat() takes at most 2 positional arguments");
         }
-        if num_args & 3 == 3 {
-            optsptr = Some(NonNull::new_unchecked(*args.offset(2)));
+        if num_args == 2 {
+            optsptr = *args.offset(1);
         }
         if !kwnames.is_null() {
             cold_path!();
@@ -271,53 +691,543 @@ pub(crate) unsafe extern "C" fn dumps(
             for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
                 let arg = kwob.get(i.cast_unsigned());
                 if matches_kwarg!(arg, typeref::OPTION) {
-                    if num_args & 3 == 3 {
-                        cold_path!();
-                        return raise_dumps_exception_fixed(
-                            "dumps() got multiple values for argument: 'option'",
-                        );
-                    }
-                    optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
-                } else if matches_kwarg!(arg, typeref::DEFAULT) {
-                    if num_args & 2 == 2 {
+                    if num_args == 2 {
                         cold_path!();
-                        return raise_dumps_exception_fixed(
-                            "dumps() got multiple values for argument: 'default'",
+                        return raise_args_exception(
+                            "reformat() got multiple values for argument: 'option'",
                         );
                     }
-                    default = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+                    optsptr = *args.offset(num_args + i);
                 } else {
-                    return raise_dumps_exception_fixed(
-                        "dumps() got an unexpected keyword argument",
-                    );
+                    return raise_args_exception("reformat() got an unexpected keyword argument");
                 }
             }
         }
 
         let mut opts = 0 as opt::Opt;
-        if let Some(tmp) = optsptr {
-            cold_path!();
-            match PyIntRef::from_ptr(tmp.as_ptr()) {
-                Ok(val) => match val.as_opt() {
-                    Ok(opt) => {
-                        opts = opt;
-                    }
-                    Err(_) => {
-                        return raise_dumps_exception_fixed("Invalid opts");
-                    }
-                },
-                Err(_) => {
-                    if !core::ptr::eq(tmp.as_ptr(), PyNoneRef::none().as_ptr()) {
-                        cold_path!();
-                        return raise_dumps_exception_fixed("Invalid opts");
-                    }
+        if !core::ptr::eq(optsptr, typeref::NONE) {
+            match PyIntRef::from_ptr(optsptr).ok().map(|val| val.as_opt()) {
+                Some(Ok(opt)) if opt & !REFORMAT_OPT == 0 => opts = opt,
+                _ => {
+                    cold_path!();
+                    return raise_dumps_exception_fixed("Invalid opts");
                 }
             }
         }
 
-        serialize(*args, default, opts).map_or_else(
-            |err| raise_dumps_exception_dynamic(err.as_str()),
-            NonNull::as_ptr,
+        deserialize_reformat(*args, opts).map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn merge_patch(
+    _self: *mut PyObject,
+    args: *mut *mut PyObject,
+    nargs: Py_ssize_t,
+) -> *mut PyObject {
+    unsafe {
+        if isize_to_usize(nargs) != 2 {
+            cold_path!();
+            return raise_args_exception("merge_patch() takes exactly 2 positional arguments");
+        }
+        patch_merge(*args, *args.add(1))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn create_merge_patch(
+    _self: *mut PyObject,
+    args: *mut *mut PyObject,
+    nargs: Py_ssize_t,
+) -> *mut PyObject {
+    unsafe {
+        if isize_to_usize(nargs) != 2 {
+            cold_path!();
+            return raise_args_exception(
+                "create_merge_patch() takes exactly 2 positional arguments",
+            );
+        }
+        patch_create_merge(*args, *args.add(1))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn merge_patch_json(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut optsptr: *mut PyObject = typeref::NONE;
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args < 2 {
+            cold_path!();
+            return raise_args_exception(
+                "merge_patch_json() missing required positional arguments: 'target' and 'patch'",
+            );
+        }
+        if num_args > 3 {
+            cold_path!();
+            return raise_args_exception("merge_patch_json() takes at most 3 positional arguments");
+        }
+        if num_args == 3 {
+            optsptr = *args.offset(2);
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                if matches_kwarg!(arg, typeref::OPTION) {
+                    if num_args == 3 {
+                        cold_path!();
+                        return raise_args_exception(
+                            "merge_patch_json() got multiple values for argument: 'option'",
+                        );
+                    }
+                    optsptr = *args.offset(num_args + i);
+                } else {
+                    return raise_args_exception(
+                        "merge_patch_json() got an unexpected keyword argument",
+                    );
+                }
+            }
+        }
+
+        let mut opts = 0 as opt::Opt;
+        if !core::ptr::eq(optsptr, typeref::NONE) {
+            match PyIntRef::from_ptr(optsptr).ok().map(|val| val.as_opt()) {
+                Some(Ok(opt)) if opt & !REFORMAT_OPT == 0 => opts = opt,
+                _ => {
+                    cold_path!();
+                    return raise_dumps_exception_fixed("Invalid opts");
+                }
+            }
+        }
+
+        patch_merge_json(*args, *args.add(1), opts)
+            .map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn apply_patch(
+    _self: *mut PyObject,
+    args: *mut *mut PyObject,
+    nargs: Py_ssize_t,
+) -> *mut PyObject {
+    unsafe {
+        if isize_to_usize(nargs) != 2 {
+            cold_path!();
+            return raise_args_exception("apply_patch() takes exactly 2 positional arguments");
+        }
+        patch_apply(*args, *args.add(1))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn diff(
+    _self: *mut PyObject,
+    args: *mut *mut PyObject,
+    nargs: Py_ssize_t,
+) -> *mut PyObject {
+    unsafe {
+        if isize_to_usize(nargs) != 2 {
+            cold_path!();
+            return raise_args_exception("diff() takes exactly 2 positional arguments");
+        }
+        patch_diff(*args, *args.add(1))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn query(
+    _self: *mut PyObject,
+    args: *mut *mut PyObject,
+    nargs: Py_ssize_t,
+) -> *mut PyObject {
+    unsafe {
+        if isize_to_usize(nargs) != 2 {
+            cold_path!();
+            return raise_args_exception("query() takes exactly 2 positional arguments");
+        }
+        query_path(*args, *args.add(1))
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn raw_decode(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut idxptr: Option<NonNull<PyObject>> = None;
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args == 0 {
+            cold_path!();
+            return raise_args_exception(
+                "raw_decode() missing 1 required positional argument: 'obj'",
+            );
+        }
+        if num_args > 2 {
+            cold_path!();
+            return raise_args_exception("raw_decode() takes at most 2 positional arguments");
+        }
+        if num_args == 2 {
+            idxptr = Some(NonNull::new_unchecked(*args.offset(1)));
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                if matches_kwarg!(arg, typeref::IDX) {
+                    if num_args == 2 {
+                        cold_path!();
+                        return raise_args_exception(
+                            "raw_decode() got multiple values for argument: 'idx'",
+                        );
+                    }
+                    idxptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+                } else {
+                    return raise_args_exception("raw_decode() got an unexpected keyword argument");
+                }
+            }
+        }
+
+        let mut idx: i64 = 0;
+        if let Some(tmp) = idxptr {
+            if PyIntRef::from_ptr(tmp.as_ptr()).is_err() {
+                cold_path!();
+                return raise_args_exception("raw_decode() argument 'idx' must be int");
+            }
+            let mut overflow: c_int = 0;
+            idx = crate::ffi::PyLong_AsLongLongAndOverflow(tmp.as_ptr(), &raw mut overflow);
+            if overflow > 0 {
+                cold_path!();
+                idx = i64::MAX;
+            }
+            if overflow < 0 || idx < 0 {
+                cold_path!();
+                return raise_value_exception("idx cannot be negative");
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        match deserialize_prefix(*args, idx.cast_unsigned() as usize) {
+            Ok((obj, end)) => {
+                let mut ret = PyTupleRef::with_capacity(2);
+                ret.set(0, obj.as_ptr());
+                ret.set(1, PyIntRef::from_u64(end as u64).as_ptr());
+                ret.as_ptr()
+            }
+            Err(err) => raise_loads_exception(err),
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn iter_items(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut prefix = typeref::ITEM_STR;
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args == 0 {
+            cold_path!();
+            return raise_args_exception(
+                "iter_items() missing 1 required positional argument: 'obj'",
+            );
+        }
+        if num_args > 2 {
+            cold_path!();
+            return raise_args_exception("iter_items() takes at most 2 positional arguments");
+        }
+        if num_args == 2 {
+            prefix = *args.offset(1);
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                if matches_kwarg!(arg, typeref::PREFIX) {
+                    if num_args == 2 {
+                        cold_path!();
+                        return raise_args_exception(
+                            "iter_items() got multiple values for argument: 'prefix'",
+                        );
+                    }
+                    prefix = *args.offset(num_args + i);
+                } else {
+                    return raise_args_exception("iter_items() got an unexpected keyword argument");
+                }
+            }
+        }
+        if PyStrRef::from_ptr(prefix).is_err() {
+            cold_path!();
+            return raise_args_exception("iter_items() argument 'prefix' must be str");
+        }
+
+        deserialize_items(*args, prefix).map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
+
+/// Arguments of `dumps()` and the functions serializing to other formats.
+struct DumpsArgs {
+    obj: *mut PyObject,
+    default: Option<NonNull<PyObject>>,
+    opts: opt::Opt,
+}
+
+/// Parse the arguments `(obj, /, default=None, option=None)` of the
+/// function `name`, or raise an exception.
+#[inline(always)]
+unsafe fn parse_dumps_args(
+    name: &str,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> Result<DumpsArgs, *mut PyObject> {
+    unsafe {
+        let mut default: Option<NonNull<PyObject>> = None;
+        let mut optsptr: Option<NonNull<PyObject>> = None;
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args == 0 {
+            cold_path!();
+            return Err(raise_dumps_exception_fixed(&format!(
+                "{name}() missing 1 required positional argument: 'obj'"
+            )));
+        }
+        if num_args & 2 == 2 {
+            default = Some(NonNull::new_unchecked(*args.offset(1)));
+        }
+        if num_args & 3 == 3 {
+            optsptr = Some(NonNull::new_unchecked(*args.offset(2)));
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                if matches_kwarg!(arg, typeref::OPTION) {
+                    if num_args & 3 == 3 {
+                        cold_path!();
+                        return Err(raise_dumps_exception_fixed(&format!(
+                            "{name}() got multiple values for argument: 'option'"
+                        )));
+                    }
+                    optsptr = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+                } else if matches_kwarg!(arg, typeref::DEFAULT) {
+                    if num_args & 2 == 2 {
+                        cold_path!();
+                        return Err(raise_dumps_exception_fixed(&format!(
+                            "{name}() got multiple values for argument: 'default'"
+                        )));
+                    }
+                    default = Some(NonNull::new_unchecked(*args.offset(num_args + i)));
+                } else {
+                    return Err(raise_dumps_exception_fixed(&format!(
+                        "{name}() got an unexpected keyword argument"
+                    )));
+                }
+            }
+        }
+
+        let mut opts = 0 as opt::Opt;
+        if let Some(tmp) = optsptr {
+            cold_path!();
+            match PyIntRef::from_ptr(tmp.as_ptr()) {
+                Ok(val) => match val.as_opt() {
+                    Ok(opt)
+                        if opt & (opt::LOADS_MSGPACK_OPT | opt::LOADS_OPT) == 0
+                            && (opt & opt::KEY_CASE).count_ones() <= 1 =>
+                    {
+                        opts = opt;
+                    }
+                    Ok(_) => {
+                        return Err(raise_dumps_exception_fixed("Invalid opts"));
+                    }
+                    Err(_) => {
+                        return Err(raise_dumps_exception_fixed("Invalid opts"));
+                    }
+                },
+                Err(_) => {
+                    if !core::ptr::eq(tmp.as_ptr(), PyNoneRef::none().as_ptr()) {
+                        cold_path!();
+                        return Err(raise_dumps_exception_fixed("Invalid opts"));
+                    }
+                }
+            }
+        }
+
+        Ok(DumpsArgs {
+            obj: *args,
+            default: default,
+            opts: opts,
+        })
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn dumps(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut parsed = match parse_dumps_args("dumps", args, nargs, kwnames) {
+            Ok(parsed) => parsed,
+            Err(err) => return err,
+        };
+
+        if opt_enabled!(parsed.opts, opt::CBOR_ONLY) {
+            cold_path!();
+            return raise_dumps_exception_fixed("Invalid opts");
+        }
+
+        if opt_enabled!(parsed.opts, opt::CANONICAL) {
+            cold_path!();
+            if opt_enabled!(parsed.opts, opt::INDENT_2) {
+                return raise_dumps_exception_fixed("Invalid opts");
+            }
+            parsed.opts |= opt::SORT_KEYS;
+        }
+
+        serialize(parsed.obj, parsed.default, parsed.opts).map_or_else(
+            |err| raise_dumps_exception_dynamic(err.as_str()),
+            NonNull::as_ptr,
+        )
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn dumps_msgpack(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let parsed = match parse_dumps_args("dumps_msgpack", args, nargs, kwnames) {
+            Ok(parsed) => parsed,
+            Err(err) => return err,
+        };
+
+        if opt_enabled!(parsed.opts, opt::JSON_ONLY | opt::CBOR_ONLY) {
+            cold_path!();
+            return raise_dumps_exception_fixed("Invalid opts");
+        }
+
+        serialize_msgpack(parsed.obj, parsed.default, parsed.opts).map_or_else(
+            |err| raise_dumps_exception_dynamic(err.as_str()),
+            NonNull::as_ptr,
+        )
+    }
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn dumps_cbor(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let parsed = match parse_dumps_args("dumps_cbor", args, nargs, kwnames) {
+            Ok(parsed) => parsed,
+            Err(err) => return err,
+        };
+
+        if opt_enabled!(parsed.opts, opt::JSON_ONLY) {
+            cold_path!();
+            return raise_dumps_exception_fixed("Invalid opts");
+        }
+
+        serialize_cbor(parsed.obj, parsed.default, parsed.opts).map_or_else(
+            |err| raise_dumps_exception_dynamic(err.as_str()),
+            NonNull::as_ptr,
         )
     }
 }
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn loads_cbor(
+    _self: *mut PyObject,
+    obj: *mut PyObject,
+) -> *mut PyObject {
+    deserialize_cbor(obj).map_or_else(raise_loads_exception, NonNull::as_ptr)
+}
+
+#[unsafe(no_mangle)]
+pub(crate) unsafe extern "C" fn loads_msgpack(
+    _self: *mut PyObject,
+    args: *const *mut PyObject,
+    nargs: Py_ssize_t,
+    kwnames: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        let mut optsptr: *mut PyObject = typeref::NONE;
+
+        let num_args = PyVectorcall_NARGS(isize_to_usize(nargs));
+        if num_args == 0 {
+            cold_path!();
+            return raise_args_exception(
+                "loads_msgpack() missing 1 required positional argument: 'obj'",
+            );
+        }
+        if num_args > 2 {
+            cold_path!();
+            return raise_args_exception("loads_msgpack() takes at most 2 positional arguments");
+        }
+        if num_args == 2 {
+            optsptr = *args.offset(1);
+        }
+        if !kwnames.is_null() {
+            cold_path!();
+            let kwob = PyTupleRef::from_ptr_unchecked(kwnames);
+            for i in 0..=Py_SIZE(kwnames).saturating_sub(1) {
+                let arg = kwob.get(i.cast_unsigned());
+                if matches_kwarg!(arg, typeref::OPTION) {
+                    if num_args == 2 {
+                        cold_path!();
+                        return raise_args_exception(
+                            "loads_msgpack() got multiple values for argument: 'option'",
+                        );
+                    }
+                    optsptr = *args.offset(num_args + i);
+                } else {
+                    return raise_args_exception(
+                        "loads_msgpack() got an unexpected keyword argument",
+                    );
+                }
+            }
+        }
+
+        let mut opts = 0 as opt::Opt;
+        if !core::ptr::eq(optsptr, typeref::NONE) {
+            match PyIntRef::from_ptr(optsptr).ok().map(|val| val.as_opt()) {
+                Some(Ok(opt)) if opt & !opt::LOADS_MSGPACK_OPT == 0 => opts = opt,
+                _ => {
+                    cold_path!();
+                    return raise_loads_exception(DeserializeError::invalid(
+                        std::borrow::Cow::Borrowed("Invalid opts"),
+                    ));
+                }
+            }
+        }
+
+        deserialize_msgpack(*args, opts).map_or_else(raise_loads_exception, NonNull::as_ptr)
+    }
+}
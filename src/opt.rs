@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: MPL-2.0
-// Copyright ijl (2020-2025)
+// Copyright ijl (2020-2026)
 
 pub(crate) type Opt = u32;
 
@@ -15,6 +15,18 @@ pub(crate) const PASSTHROUGH_SUBCLASS: Opt = 1 << 8;
 pub(crate) const PASSTHROUGH_DATETIME: Opt = 1 << 9;
 pub(crate) const APPEND_NEWLINE: Opt = 1 << 10;
 pub(crate) const PASSTHROUGH_DATACLASS: Opt = 1 << 11;
+pub(crate) const CANONICAL: Opt = 1 << 12;
+pub(crate) const MSGPACK_TIMESTAMP: Opt = 1 << 13;
+pub(crate) const CBOR_TYPED_ARRAY: Opt = 1 << 14;
+pub(crate) const OMIT_NONE: Opt = 1 << 15;
+pub(crate) const OMIT_DEFAULTS: Opt = 1 << 16;
+pub(crate) const SERIALIZE_UNDERSCORE: Opt = 1 << 17;
+pub(crate) const CAMEL_CASE_KEYS: Opt = 1 << 18;
+pub(crate) const PASCAL_CASE_KEYS: Opt = 1 << 19;
+pub(crate) const KEBAB_CASE_KEYS: Opt = 1 << 20;
+pub(crate) const SNAKE_CASE_KEYS: Opt = 1 << 21;
+pub(crate) const SERIALIZE_OBJECT: Opt = 1 << 22;
+pub(crate) const SERIALIZE_NAMEDTUPLE: Opt = 1 << 23;
 
 // deprecated
 pub(crate) const SERIALIZE_DATACLASS: Opt = 0;
@@ -22,21 +34,57 @@ pub(crate) const SERIALIZE_UUID: Opt = 0;
 
 pub(crate) const SORT_OR_NON_STR_KEYS: Opt = SORT_KEYS | NON_STR_KEYS;
 
+/// Options of the JSON format itself, which other formats do not accept.
+pub(crate) const JSON_ONLY: Opt = APPEND_NEWLINE | CANONICAL | INDENT_2;
+
+/// Options of CBOR, which other formats do not accept.
+pub(crate) const CBOR_ONLY: Opt = CBOR_TYPED_ARRAY;
+
+/// Set by `dumps_cbor()` so that types are serialized as their CBOR tags.
+/// It is not an option given to the API.
+pub(crate) const CBOR: Opt = 1 << 31;
+
+/// Set by `dumps_msgpack()` so that errors name the format. It is not an
+/// option given to the API.
+pub(crate) const MSGPACK: Opt = 1 << 30;
+
+/// Options converting the keys of `dict` and dataclasses, of which only
+/// one may be given.
+pub(crate) const KEY_CASE: Opt = CAMEL_CASE_KEYS | KEBAB_CASE_KEYS | PASCAL_CASE_KEYS;
+
+/// Options of `loads_msgpack()`, which `dumps()` does not accept.
+pub(crate) const LOADS_MSGPACK_OPT: Opt = MSGPACK_TIMESTAMP;
+
+/// Options of `loads()`, which `dumps()` does not accept.
+pub(crate) const LOADS_OPT: Opt = SNAKE_CASE_KEYS;
+
 pub(crate) const NOT_PASSTHROUGH: Opt =
     !(PASSTHROUGH_DATETIME | PASSTHROUGH_DATACLASS | PASSTHROUGH_SUBCLASS);
 
 #[allow(clippy::cast_possible_wrap)]
 pub(crate) const MAX_OPT: i32 = (APPEND_NEWLINE
+    | CAMEL_CASE_KEYS
+    | CANONICAL
+    | CBOR_TYPED_ARRAY
     | INDENT_2
+    | KEBAB_CASE_KEYS
+    | MSGPACK_TIMESTAMP
     | NAIVE_UTC
     | NON_STR_KEYS
+    | OMIT_DEFAULTS
     | OMIT_MICROSECONDS
+    | OMIT_NONE
+    | PASCAL_CASE_KEYS
     | PASSTHROUGH_DATETIME
     | PASSTHROUGH_DATACLASS
     | PASSTHROUGH_SUBCLASS
     | SERIALIZE_DATACLASS
+    | SERIALIZE_NAMEDTUPLE
     | SERIALIZE_NUMPY
+    | SERIALIZE_OBJECT
+    | SERIALIZE_UNDERSCORE
     | SERIALIZE_UUID
+    | SNAKE_CASE_KEYS
     | SORT_KEYS
     | STRICT_INTEGER
     | UTC_Z) as i32;
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::exception::raise_dumps_exception_dynamic;
+use crate::ffi::{
+    Py_DECREF, Py_EQ, Py_EnterRecursiveCall, Py_LeaveRecursiveCall, Py_SIZE, Py_ssize_t,
+    PyDict_GetItemWithError, PyDict_Next, PyDict_Size, PyErr_Occurred, PyList_Check,
+    PyList_GET_ITEM, PyObject, PyObject_RichCompareBool, PyTuple_GET_ITEM,
+};
+use crate::opt::SERIALIZE_NUMPY;
+use crate::serialize::{ObType, pyobject_to_obtype, serialize};
+use core::ffi::c_int;
+use core::ptr::null_mut;
+
+/// Compare `a` and `b` as the JSON they serialize to. Return 1 if equal, 0 if
+/// not, or -1 with an exception set.
+///
+/// `dict`, `list`, and `tuple` are compared member by member and `str`,
+/// `int`, `bool`, `None`, and `float` by value, so that, e.g., `1` and `True`
+/// differ. `int` and `float` are both numbers, so `1` and `1.0` are equal.
+/// Other types, such as datetimes and UUIDs, are compared by their
+/// serialized form.
+pub(crate) unsafe fn json_equal(a: *mut PyObject, b: *mut PyObject) -> c_int {
+    unsafe {
+        if core::ptr::eq(a, b) {
+            return 1;
+        }
+        match (
+            pyobject_to_obtype(a, SERIALIZE_NUMPY),
+            pyobject_to_obtype(b, SERIALIZE_NUMPY),
+        ) {
+            (ObType::Dict, ObType::Dict) => members_equal(a, b),
+            (ObType::List | ObType::Tuple, ObType::List | ObType::Tuple) => elements_equal(a, b),
+            (ObType::Str, ObType::Str)
+            | (ObType::Int | ObType::Float, ObType::Int | ObType::Float)
+            | (ObType::Bool, ObType::Bool)
+            | (ObType::None, ObType::None) => PyObject_RichCompareBool(a, b, Py_EQ),
+            (
+                ObType::Str
+                | ObType::Int
+                | ObType::Bool
+                | ObType::None
+                | ObType::Float
+                | ObType::Dict
+                | ObType::List
+                | ObType::Tuple,
+                ObType::Str
+                | ObType::Int
+                | ObType::Bool
+                | ObType::None
+                | ObType::Float
+                | ObType::Dict
+                | ObType::List
+                | ObType::Tuple,
+            ) => 0,
+            _ => serialized_equal(a, b),
+        }
+    }
+}
+
+unsafe fn members_equal(a: *mut PyObject, b: *mut PyObject) -> c_int {
+    unsafe {
+        if PyDict_Size(a) != PyDict_Size(b) {
+            return 0;
+        }
+        if Py_EnterRecursiveCall(c" in JSON comparison".as_ptr()) != 0 {
+            return -1;
+        }
+        let mut ret = 1;
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while ret == 1 && PyDict_Next(a, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+            let other = PyDict_GetItemWithError(b, key);
+            ret = if other.is_null() {
+                if PyErr_Occurred().is_null() { 0 } else { -1 }
+            } else {
+                json_equal(value, other)
+            };
+        }
+        Py_LeaveRecursiveCall();
+        ret
+    }
+}
+
+unsafe fn elements_equal(a: *mut PyObject, b: *mut PyObject) -> c_int {
+    unsafe {
+        let len = Py_SIZE(a);
+        if len != Py_SIZE(b) {
+            return 0;
+        }
+        if Py_EnterRecursiveCall(c" in JSON comparison".as_ptr()) != 0 {
+            return -1;
+        }
+        let mut ret = 1;
+        for idx in 0..len {
+            ret = json_equal(sequence_item(a, idx), sequence_item(b, idx));
+            if ret != 1 {
+                break;
+            }
+        }
+        Py_LeaveRecursiveCall();
+        ret
+    }
+}
+
+unsafe fn serialized_equal(a: *mut PyObject, b: *mut PyObject) -> c_int {
+    unsafe {
+        let a_json = match serialize(a, None, SERIALIZE_NUMPY) {
+            Ok(val) => val.as_ptr(),
+            Err(err) => {
+                raise_dumps_exception_dynamic(err.as_str());
+                return -1;
+            }
+        };
+        let b_json = match serialize(b, None, SERIALIZE_NUMPY) {
+            Ok(val) => val.as_ptr(),
+            Err(err) => {
+                Py_DECREF(a_json);
+                raise_dumps_exception_dynamic(err.as_str());
+                return -1;
+            }
+        };
+        let ret = PyObject_RichCompareBool(a_json, b_json, Py_EQ);
+        Py_DECREF(a_json);
+        Py_DECREF(b_json);
+        ret
+    }
+}
+
+/// Return a borrowed reference to item `idx` of a `list` or `tuple`.
+#[inline]
+pub(crate) unsafe fn sequence_item(obj: *mut PyObject, idx: Py_ssize_t) -> *mut PyObject {
+    unsafe {
+        if PyList_Check(obj) != 0 {
+            PyList_GET_ITEM(obj, idx)
+        } else {
+            PyTuple_GET_ITEM(obj, idx)
+        }
+    }
+}
@@ -0,0 +1,642 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::equal::{json_equal, sequence_item};
+use crate::deserialize::{parse_index, parse_pointer};
+use crate::exception::{raise_args_exception, raise_dumps_exception_fixed, raise_value_exception};
+use crate::ffi::{
+    Py_DECREF, Py_EnterRecursiveCall, Py_INCREF, Py_LeaveRecursiveCall, Py_SIZE, Py_ssize_t,
+    PyDict_Check, PyDict_Copy, PyDict_DelItem, PyDict_GetItemWithError, PyDict_New, PyDict_Next,
+    PyDict_SetItem, PyErr_Occurred, PyList_Append, PyList_Check, PyList_Insert, PyList_New,
+    PyList_SetItem, PyList_SetSlice, PyObject, PySequence_List, PyStrRef, PyTuple_Check,
+};
+use crate::typeref::{FROM_STR, OP_STR, PATH_STR, VALUE_STR};
+use crate::util::usize_to_isize;
+use core::ptr::null_mut;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+enum PatchError {
+    /// A Python exception is set.
+    Raised,
+    /// The operation cannot be applied, for the reason given.
+    Invalid(String),
+}
+
+type PatchResult<T> = Result<T, PatchError>;
+
+/// `list` and `tuple` both serialize to arrays.
+#[inline]
+unsafe fn is_sequence(obj: *mut PyObject) -> bool {
+    unsafe { PyList_Check(obj) != 0 || PyTuple_Check(obj) != 0 }
+}
+
+#[cold]
+fn not_found(pointer: &str) -> PatchError {
+    PatchError::Invalid(format!("path does not exist: {pointer}"))
+}
+
+/// Return a borrowed reference to the member of the `dict`, `list`, or
+/// `tuple` `container` referred to by `token`, or `None` if there is none.
+unsafe fn child(container: *mut PyObject, token: &str) -> PatchResult<Option<*mut PyObject>> {
+    unsafe {
+        if PyDict_Check(container) != 0 {
+            let key = PyStrRef::from_str(token);
+            let value = PyDict_GetItemWithError(container, key.as_ptr());
+            Py_DECREF(key.as_ptr());
+            if value.is_null() {
+                if PyErr_Occurred().is_null() {
+                    return Ok(None);
+                }
+                return Err(PatchError::Raised);
+            }
+            Ok(Some(value))
+        } else if is_sequence(container) {
+            Ok(parse_index(token)
+                .map(usize_to_isize)
+                .filter(|idx| *idx < Py_SIZE(container))
+                .map(|idx| sequence_item(container, idx)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Set the existing member of the `dict` or `list` `container` referred to
+/// by `token` to `value`.
+unsafe fn set_child(
+    container: *mut PyObject,
+    token: &str,
+    value: *mut PyObject,
+) -> PatchResult<()> {
+    unsafe {
+        let ret = if PyDict_Check(container) != 0 {
+            let key = PyStrRef::from_str(token);
+            let ret = PyDict_SetItem(container, key.as_ptr(), value);
+            Py_DECREF(key.as_ptr());
+            ret
+        } else {
+            debug_assert!(PyList_Check(container) != 0);
+            let idx = usize_to_isize(parse_index(token).unwrap_unchecked());
+            Py_INCREF(value);
+            PyList_SetItem(container, idx, value)
+        };
+        if ret != 0 {
+            return Err(PatchError::Raised);
+        }
+        Ok(())
+    }
+}
+
+/// Return a new reference to a copy of `obj` in which every `dict`, `list`,
+/// and `tuple` is a new `dict` or `list`.
+unsafe fn deep_copy(obj: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        let dict = PyDict_Check(obj) != 0;
+        if !dict && !is_sequence(obj) {
+            Py_INCREF(obj);
+            return obj;
+        }
+        if Py_EnterRecursiveCall(c" in apply_patch()".as_ptr()) != 0 {
+            return null_mut();
+        }
+        let mut copy = if dict { PyDict_New(0) } else { PyList_New(0) };
+        if dict {
+            let mut pos = 0;
+            let mut key: *mut PyObject = null_mut();
+            let mut value: *mut PyObject = null_mut();
+            while !copy.is_null()
+                && PyDict_Next(obj, &raw mut pos, &raw mut key, &raw mut value) != 0
+            {
+                let member = deep_copy(value);
+                if member.is_null() || PyDict_SetItem(copy, key, member) != 0 {
+                    Py_DECREF(copy);
+                    copy = null_mut();
+                }
+                if !member.is_null() {
+                    Py_DECREF(member);
+                }
+            }
+        } else {
+            let mut idx = 0;
+            while !copy.is_null() && idx < Py_SIZE(obj) {
+                let member = deep_copy(sequence_item(obj, idx));
+                if member.is_null() || PyList_Append(copy, member) != 0 {
+                    Py_DECREF(copy);
+                    copy = null_mut();
+                }
+                if !member.is_null() {
+                    Py_DECREF(member);
+                }
+                idx += 1;
+            }
+        }
+        Py_LeaveRecursiveCall();
+        copy
+    }
+}
+
+/// Apply operations to a document without modifying it. A `dict`, `list`,
+/// or `tuple` is copied the first time an operation modifies it or one of
+/// its members, so the result shares what the patch does not change.
+struct Patcher {
+    root: *mut PyObject,
+    /// Containers this patcher created, each holding a reference.
+    owned: HashSet<*mut PyObject>,
+}
+
+impl Patcher {
+    unsafe fn new(root: *mut PyObject) -> Self {
+        unsafe {
+            Py_INCREF(root);
+            Patcher {
+                root: root,
+                owned: HashSet::new(),
+            }
+        }
+    }
+
+    /// Return a new reference to the patched document.
+    unsafe fn finish(self) -> *mut PyObject {
+        unsafe {
+            Py_INCREF(self.root);
+            self.root
+        }
+    }
+
+    /// Return `container`, or a `dict` or `list` copy of it this patcher may
+    /// modify.
+    unsafe fn make_owned(
+        &mut self,
+        container: *mut PyObject,
+        pointer: &str,
+    ) -> PatchResult<*mut PyObject> {
+        unsafe {
+            if self.owned.contains(&container) {
+                return Ok(container);
+            }
+            let copy = if PyDict_Check(container) != 0 {
+                PyDict_Copy(container)
+            } else if is_sequence(container) {
+                PySequence_List(container)
+            } else {
+                return Err(not_found(pointer));
+            };
+            if copy.is_null() {
+                return Err(PatchError::Raised);
+            }
+            self.owned.insert(copy);
+            Ok(copy)
+        }
+    }
+
+    /// Return a borrowed reference to the value at `tokens`.
+    unsafe fn get(&self, tokens: &[Cow<str>], pointer: &str) -> PatchResult<*mut PyObject> {
+        unsafe {
+            let mut val = self.root;
+            for token in tokens {
+                val = child(val, token)?.ok_or_else(|| not_found(pointer))?;
+            }
+            Ok(val)
+        }
+    }
+
+    /// Return a borrowed reference to the container at `tokens`, copying it
+    /// and each container above it that this patcher does not yet own.
+    unsafe fn container_mut(
+        &mut self,
+        tokens: &[Cow<str>],
+        pointer: &str,
+    ) -> PatchResult<*mut PyObject> {
+        unsafe {
+            let root = self.make_owned(self.root, pointer)?;
+            if !core::ptr::eq(root, self.root) {
+                Py_INCREF(root);
+                Py_DECREF(self.root);
+                self.root = root;
+            }
+            let mut container = root;
+            for token in tokens {
+                let val = child(container, token)?.ok_or_else(|| not_found(pointer))?;
+                let owned = self.make_owned(val, pointer)?;
+                if !core::ptr::eq(owned, val) {
+                    set_child(container, token, owned)?;
+                }
+                container = owned;
+            }
+            Ok(container)
+        }
+    }
+
+    unsafe fn set_root(&mut self, value: *mut PyObject) {
+        unsafe {
+            Py_INCREF(value);
+            Py_DECREF(self.root);
+            self.root = value;
+        }
+    }
+
+    unsafe fn add(
+        &mut self,
+        tokens: &[Cow<str>],
+        pointer: &str,
+        value: *mut PyObject,
+    ) -> PatchResult<()> {
+        unsafe {
+            let Some((last, parent)) = tokens.split_last() else {
+                self.set_root(value);
+                return Ok(());
+            };
+            let container = self.container_mut(parent, pointer)?;
+            if PyDict_Check(container) != 0 {
+                let key = PyStrRef::from_str(last);
+                let ret = PyDict_SetItem(container, key.as_ptr(), value);
+                Py_DECREF(key.as_ptr());
+                if ret != 0 {
+                    return Err(PatchError::Raised);
+                }
+                return Ok(());
+            }
+            let len = Py_SIZE(container);
+            let idx = if last == "-" {
+                len
+            } else {
+                parse_index(last)
+                    .map(usize_to_isize)
+                    .filter(|idx| *idx <= len)
+                    .ok_or_else(|| not_found(pointer))?
+            };
+            if PyList_Insert(container, idx, value) != 0 {
+                return Err(PatchError::Raised);
+            }
+            Ok(())
+        }
+    }
+
+    /// Remove the value at `tokens` and return a new reference to it.
+    unsafe fn remove(&mut self, tokens: &[Cow<str>], pointer: &str) -> PatchResult<*mut PyObject> {
+        unsafe {
+            let Some((last, parent)) = tokens.split_last() else {
+                return Err(PatchError::Invalid(String::from("cannot remove the root")));
+            };
+            let container = self.container_mut(parent, pointer)?;
+            let value = child(container, last)?.ok_or_else(|| not_found(pointer))?;
+            Py_INCREF(value);
+            let ret = if PyDict_Check(container) != 0 {
+                let key = PyStrRef::from_str(last);
+                let ret = PyDict_DelItem(container, key.as_ptr());
+                Py_DECREF(key.as_ptr());
+                ret
+            } else {
+                let idx = usize_to_isize(parse_index(last).unwrap_unchecked());
+                PyList_SetSlice(container, idx, idx + 1, null_mut())
+            };
+            if ret != 0 {
+                Py_DECREF(value);
+                return Err(PatchError::Raised);
+            }
+            Ok(value)
+        }
+    }
+
+    unsafe fn replace(
+        &mut self,
+        tokens: &[Cow<str>],
+        pointer: &str,
+        value: *mut PyObject,
+    ) -> PatchResult<()> {
+        unsafe {
+            let Some((last, parent)) = tokens.split_last() else {
+                self.set_root(value);
+                return Ok(());
+            };
+            let container = self.container_mut(parent, pointer)?;
+            if child(container, last)?.is_none() {
+                return Err(not_found(pointer));
+            }
+            set_child(container, last, value)
+        }
+    }
+
+    unsafe fn apply(&mut self, op: *mut PyObject) -> PatchResult<()> {
+        unsafe {
+            let name = str_member(op, OP_STR, "op")?;
+            let path = str_member(op, PATH_STR, "path")?;
+            let tokens = pointer_tokens(path)?;
+            match name {
+                "add" => self.add(&tokens, path, member(op, VALUE_STR, "value")?),
+                "remove" => {
+                    Py_DECREF(self.remove(&tokens, path)?);
+                    Ok(())
+                }
+                "replace" => self.replace(&tokens, path, member(op, VALUE_STR, "value")?),
+                "move" => {
+                    let from = str_member(op, FROM_STR, "from")?;
+                    let from_tokens = pointer_tokens(from)?;
+                    if from == path {
+                        self.get(&from_tokens, from)?;
+                        return Ok(());
+                    }
+                    if path.starts_with(from) && path[from.len()..].starts_with('/') {
+                        return Err(PatchError::Invalid(format!(
+                            "cannot move a value into one of its children: {from}"
+                        )));
+                    }
+                    let value = self.remove(&from_tokens, from)?;
+                    let ret = self.add(&tokens, path, value);
+                    Py_DECREF(value);
+                    ret
+                }
+                "copy" => {
+                    let from = str_member(op, FROM_STR, "from")?;
+                    let value = deep_copy(self.get(&pointer_tokens(from)?, from)?);
+                    if value.is_null() {
+                        return Err(PatchError::Raised);
+                    }
+                    let ret = self.add(&tokens, path, value);
+                    Py_DECREF(value);
+                    ret
+                }
+                "test" => {
+                    let value = member(op, VALUE_STR, "value")?;
+                    match json_equal(self.get(&tokens, path)?, value) {
+                        1 => Ok(()),
+                        0 => Err(PatchError::Invalid(format!("test failed: {path}"))),
+                        _ => Err(PatchError::Raised),
+                    }
+                }
+                _ => Err(PatchError::Invalid(format!("unknown op: {name}"))),
+            }
+        }
+    }
+}
+
+impl Drop for Patcher {
+    fn drop(&mut self) {
+        unsafe {
+            Py_DECREF(self.root);
+            for each in self.owned.drain() {
+                Py_DECREF(each);
+            }
+        }
+    }
+}
+
+/// Return a borrowed reference to the member `key` of the operation `op`.
+unsafe fn member(op: *mut PyObject, key: *mut PyObject, name: &str) -> PatchResult<*mut PyObject> {
+    unsafe {
+        let value = PyDict_GetItemWithError(op, key);
+        if value.is_null() {
+            if PyErr_Occurred().is_null() {
+                return Err(PatchError::Invalid(format!("missing member: '{name}'")));
+            }
+            return Err(PatchError::Raised);
+        }
+        Ok(value)
+    }
+}
+
+unsafe fn str_member(
+    op: *mut PyObject,
+    key: *mut PyObject,
+    name: &str,
+) -> PatchResult<&'static str> {
+    unsafe {
+        PyStrRef::from_ptr(member(op, key, name)?)
+            .ok()
+            .and_then(|val| val.as_str())
+            .ok_or_else(|| PatchError::Invalid(format!("'{name}' must be a str")))
+    }
+}
+
+fn pointer_tokens(pointer: &str) -> PatchResult<Vec<Cow<'_, str>>> {
+    parse_pointer(pointer)
+        .map_err(|_| PatchError::Invalid(format!("invalid JSON Pointer: {pointer}")))
+}
+
+/// Apply the RFC 6902 JSON Patch `ops` to `doc` and return a new reference to
+/// the result, or null with an exception set. Neither argument is modified;
+/// the result shares values the patch does not change.
+pub(crate) unsafe fn apply_patch(doc: *mut PyObject, ops: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        if !is_sequence(ops) {
+            cold_path!();
+            return raise_args_exception("apply_patch() ops must be a list of operations");
+        }
+        let mut patcher = Patcher::new(doc);
+        let mut idx: Py_ssize_t = 0;
+        while idx < Py_SIZE(ops) {
+            let op = sequence_item(ops, idx);
+            if PyDict_Check(op) == 0 {
+                cold_path!();
+                return raise_args_exception(&format!(
+                    "apply_patch() operation {idx} must be a dict"
+                ));
+            }
+            match patcher.apply(op) {
+                Ok(()) => (),
+                Err(PatchError::Raised) => return null_mut(),
+                Err(PatchError::Invalid(msg)) => {
+                    return raise_value_exception(&format!("apply_patch() operation {idx}: {msg}"));
+                }
+            }
+            idx += 1;
+        }
+        patcher.finish()
+    }
+}
+
+/// Build the operations of a JSON Patch between two values.
+struct Differ {
+    ops: *mut PyObject,
+    path: String,
+}
+
+impl Differ {
+    unsafe fn emit(&mut self, name: &str, value: Option<*mut PyObject>) -> PatchResult<()> {
+        unsafe {
+            let op = PyDict_New(0);
+            if op.is_null() {
+                return Err(PatchError::Raised);
+            }
+            let name = PyStrRef::from_str(name);
+            let path = PyStrRef::from_str(&self.path);
+            let failed = PyDict_SetItem(op, OP_STR, name.as_ptr()) != 0
+                || PyDict_SetItem(op, PATH_STR, path.as_ptr()) != 0
+                || value.is_some_and(|value| PyDict_SetItem(op, VALUE_STR, value) != 0)
+                || PyList_Append(self.ops, op) != 0;
+            Py_DECREF(name.as_ptr());
+            Py_DECREF(path.as_ptr());
+            Py_DECREF(op);
+            if failed {
+                return Err(PatchError::Raised);
+            }
+            Ok(())
+        }
+    }
+
+    fn push_token(&mut self, token: &str) -> usize {
+        let len = self.path.len();
+        self.path.push('/');
+        for each in token.chars() {
+            match each {
+                '~' => self.path.push_str("~0"),
+                '/' => self.path.push_str("~1"),
+                _ => self.path.push(each),
+            }
+        }
+        len
+    }
+
+    fn push_index(&mut self, idx: Py_ssize_t) -> usize {
+        let len = self.path.len();
+        self.path.push('/');
+        self.path.push_str(&idx.to_string());
+        len
+    }
+
+    unsafe fn diff(&mut self, a: *mut PyObject, b: *mut PyObject) -> PatchResult<()> {
+        unsafe {
+            match json_equal(a, b) {
+                1 => return Ok(()),
+                0 => (),
+                _ => return Err(PatchError::Raised),
+            }
+            let dict = PyDict_Check(a) != 0 && PyDict_Check(b) != 0;
+            if !(dict || is_sequence(a) && is_sequence(b)) {
+                return self.emit("replace", Some(b));
+            }
+            if Py_EnterRecursiveCall(c" in diff()".as_ptr()) != 0 {
+                return Err(PatchError::Raised);
+            }
+            let ret = if dict {
+                self.diff_object(a, b)
+            } else {
+                self.diff_array(a, b)
+            };
+            Py_LeaveRecursiveCall();
+            ret
+        }
+    }
+
+    unsafe fn diff_object(&mut self, a: *mut PyObject, b: *mut PyObject) -> PatchResult<()> {
+        unsafe {
+            let mut pos = 0;
+            let mut key: *mut PyObject = null_mut();
+            let mut value: *mut PyObject = null_mut();
+            while PyDict_Next(a, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+                let token = key_str(key)?;
+                let other = PyDict_GetItemWithError(b, key);
+                if other.is_null() && !PyErr_Occurred().is_null() {
+                    return Err(PatchError::Raised);
+                }
+                let len = self.push_token(token);
+                let ret = if other.is_null() {
+                    self.emit("remove", None)
+                } else {
+                    self.diff(value, other)
+                };
+                self.path.truncate(len);
+                ret?;
+            }
+            pos = 0;
+            while PyDict_Next(b, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+                let token = key_str(key)?;
+                let other = PyDict_GetItemWithError(a, key);
+                if !other.is_null() {
+                    continue;
+                }
+                if !PyErr_Occurred().is_null() {
+                    return Err(PatchError::Raised);
+                }
+                let len = self.push_token(token);
+                let ret = self.emit("add", Some(value));
+                self.path.truncate(len);
+                ret?;
+            }
+            Ok(())
+        }
+    }
+
+    unsafe fn diff_array(&mut self, a: *mut PyObject, b: *mut PyObject) -> PatchResult<()> {
+        unsafe {
+            let a_len = Py_SIZE(a);
+            let b_len = Py_SIZE(b);
+            let mut prefix = 0;
+            while prefix < a_len.min(b_len) {
+                match json_equal(sequence_item(a, prefix), sequence_item(b, prefix)) {
+                    1 => prefix += 1,
+                    0 => break,
+                    _ => return Err(PatchError::Raised),
+                }
+            }
+            let mut suffix = 0;
+            while suffix < a_len.min(b_len) - prefix {
+                match json_equal(
+                    sequence_item(a, a_len - suffix - 1),
+                    sequence_item(b, b_len - suffix - 1),
+                ) {
+                    1 => suffix += 1,
+                    0 => break,
+                    _ => return Err(PatchError::Raised),
+                }
+            }
+            let a_changed = a_len - prefix - suffix;
+            let b_changed = b_len - prefix - suffix;
+            let common = a_changed.min(b_changed);
+            for idx in prefix..prefix + common {
+                let len = self.push_index(idx);
+                let ret = self.diff(sequence_item(a, idx), sequence_item(b, idx));
+                self.path.truncate(len);
+                ret?;
+            }
+            let len = self.push_index(prefix + common);
+            for _ in b_changed..a_changed {
+                if let Err(err) = self.emit("remove", None) {
+                    self.path.truncate(len);
+                    return Err(err);
+                }
+            }
+            self.path.truncate(len);
+            for idx in prefix + common..prefix + b_changed {
+                let len = self.push_index(idx);
+                let ret = self.emit("add", Some(sequence_item(b, idx)));
+                self.path.truncate(len);
+                ret?;
+            }
+            Ok(())
+        }
+    }
+}
+
+unsafe fn key_str(key: *mut PyObject) -> PatchResult<&'static str> {
+    match PyStrRef::from_ptr(key).ok().and_then(|val| val.as_str()) {
+        Some(val) => Ok(val),
+        None => {
+            cold_path!();
+            raise_dumps_exception_fixed("Dict key must be str");
+            Err(PatchError::Raised)
+        }
+    }
+}
+
+/// Return a new reference to a `list` of RFC 6902 JSON Patch operations that
+/// transform `a` into `b`, or null with an exception set. Values are compared
+/// as JSON, as in `test` operations.
+pub(crate) unsafe fn diff(a: *mut PyObject, b: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        let ops = PyList_New(0);
+        if ops.is_null() {
+            return null_mut();
+        }
+        let mut differ = Differ {
+            ops: ops,
+            path: String::new(),
+        };
+        if differ.diff(a, b).is_err() {
+            Py_DECREF(ops);
+            return null_mut();
+        }
+        ops
+    }
+}
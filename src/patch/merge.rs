@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::equal::json_equal;
+use crate::exception::raise_value_exception;
+use crate::ffi::{
+    Py_DECREF, Py_EnterRecursiveCall, Py_INCREF, Py_LeaveRecursiveCall, PyDict_Check,
+    PyDict_Contains, PyDict_Copy, PyDict_DelItem, PyDict_GetItemWithError, PyDict_New, PyDict_Next,
+    PyDict_SetItem, PyErr_Occurred, PyObject,
+};
+use crate::typeref::NONE;
+use core::ffi::c_int;
+use core::ptr::null_mut;
+
+const UNREPRESENTABLE_NULL: &str =
+    "create_merge_patch() target has a null member, which a merge patch cannot represent";
+
+/// Apply the RFC 7396 merge patch `patch` to `target` and return a new
+/// reference to the result, or null with an exception set. Neither argument
+/// is modified; the result shares members `patch` does not change.
+pub(crate) unsafe fn merge_patch(target: *mut PyObject, patch: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        if PyDict_Check(patch) == 0 {
+            Py_INCREF(patch);
+            return patch;
+        }
+        if Py_EnterRecursiveCall(c" in merge_patch()".as_ptr()) != 0 {
+            return null_mut();
+        }
+        let ret = merge_object(target, patch);
+        Py_LeaveRecursiveCall();
+        ret
+    }
+}
+
+unsafe fn merge_object(target: *mut PyObject, patch: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        let result = if PyDict_Check(target) == 0 {
+            PyDict_New(0)
+        } else {
+            PyDict_Copy(target)
+        };
+        if result.is_null() {
+            return null_mut();
+        }
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while PyDict_Next(patch, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+            if core::ptr::eq(value, NONE) {
+                match PyDict_Contains(result, key) {
+                    0 => continue,
+                    1 if PyDict_DelItem(result, key) == 0 => continue,
+                    _ => {
+                        Py_DECREF(result);
+                        return null_mut();
+                    }
+                }
+            }
+            let mut current = PyDict_GetItemWithError(result, key);
+            if current.is_null() {
+                if !PyErr_Occurred().is_null() {
+                    Py_DECREF(result);
+                    return null_mut();
+                }
+                current = NONE;
+            }
+            let merged = merge_patch(current, value);
+            if merged.is_null() {
+                Py_DECREF(result);
+                return null_mut();
+            }
+            let ret = PyDict_SetItem(result, key, merged);
+            Py_DECREF(merged);
+            if ret != 0 {
+                Py_DECREF(result);
+                return null_mut();
+            }
+        }
+        result
+    }
+}
+
+/// Return a new reference to an RFC 7396 merge patch that transforms `source`
+/// into `target`, or null with an exception set. Values are compared as JSON
+/// so that, e.g., `1` and `True` differ.
+pub(crate) unsafe fn create_merge_patch(
+    source: *mut PyObject,
+    target: *mut PyObject,
+) -> *mut PyObject {
+    unsafe {
+        if PyDict_Check(target) == 0 {
+            Py_INCREF(target);
+            return target;
+        }
+        if Py_EnterRecursiveCall(c" in create_merge_patch()".as_ptr()) != 0 {
+            return null_mut();
+        }
+        let ret = if PyDict_Check(source) == 0 {
+            // the patch replaces source, but merging it drops null members
+            match has_null_member(target) {
+                0 => {
+                    Py_INCREF(target);
+                    target
+                }
+                1 => raise_value_exception(UNREPRESENTABLE_NULL),
+                _ => null_mut(),
+            }
+        } else {
+            diff_object(source, target)
+        };
+        Py_LeaveRecursiveCall();
+        ret
+    }
+}
+
+unsafe fn diff_object(source: *mut PyObject, target: *mut PyObject) -> *mut PyObject {
+    unsafe {
+        let patch = PyDict_New(0);
+        if patch.is_null() {
+            return null_mut();
+        }
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while PyDict_Next(source, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+            match PyDict_Contains(target, key) {
+                1 => continue,
+                0 if PyDict_SetItem(patch, key, NONE) == 0 => continue,
+                _ => {
+                    Py_DECREF(patch);
+                    return null_mut();
+                }
+            }
+        }
+        pos = 0;
+        while PyDict_Next(target, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+            let mut current = PyDict_GetItemWithError(source, key);
+            if current.is_null() {
+                if !PyErr_Occurred().is_null() {
+                    Py_DECREF(patch);
+                    return null_mut();
+                }
+                current = NONE;
+            } else {
+                match json_equal(current, value) {
+                    0 => (),
+                    1 => continue,
+                    _ => {
+                        Py_DECREF(patch);
+                        return null_mut();
+                    }
+                }
+            }
+            if core::ptr::eq(value, NONE) {
+                Py_DECREF(patch);
+                return raise_value_exception(UNREPRESENTABLE_NULL);
+            }
+            let member = create_merge_patch(current, value);
+            if member.is_null() {
+                Py_DECREF(patch);
+                return null_mut();
+            }
+            let ret = PyDict_SetItem(patch, key, member);
+            Py_DECREF(member);
+            if ret != 0 {
+                Py_DECREF(patch);
+                return null_mut();
+            }
+        }
+        patch
+    }
+}
+
+/// Return 1 if a `dict` in `obj`, not descending into arrays, has a `None`
+/// member, 0 if not, or -1 with an exception set.
+unsafe fn has_null_member(obj: *mut PyObject) -> c_int {
+    unsafe {
+        if PyDict_Check(obj) == 0 {
+            return 0;
+        }
+        if Py_EnterRecursiveCall(c" in create_merge_patch()".as_ptr()) != 0 {
+            return -1;
+        }
+        let mut ret = 0;
+        let mut pos = 0;
+        let mut key: *mut PyObject = null_mut();
+        let mut value: *mut PyObject = null_mut();
+        while PyDict_Next(obj, &raw mut pos, &raw mut key, &raw mut value) != 0 {
+            ret = if core::ptr::eq(value, NONE) {
+                1
+            } else {
+                has_null_member(value)
+            };
+            if ret != 0 {
+                break;
+            }
+        }
+        Py_LeaveRecursiveCall();
+        ret
+    }
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::deserialize::{
+    DeserializeError, Kind, PoolBuffer, Utf8Buffer, Value, ValueSerializer, read_raw,
+};
+use crate::opt::{APPEND_NEWLINE, INDENT_2, Opt, SORT_KEYS};
+use crate::serialize::writer::{BytesWriter, to_writer, to_writer_pretty};
+use core::ptr::NonNull;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// Write the result of applying the merge patch `patch` to `target`, both in
+/// documents parsed by `read_raw()`.
+struct MergeSerializer<'p> {
+    target: Option<Value<'p>>,
+    patch: Value<'p>,
+    sort_keys: bool,
+}
+
+impl<'p> MergeSerializer<'p> {
+    fn new(target: Option<Value<'p>>, patch: Value<'p>, sort_keys: bool) -> Self {
+        MergeSerializer {
+            target: target,
+            patch: patch,
+            sort_keys: sort_keys,
+        }
+    }
+}
+
+impl Serialize for MergeSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !self.patch.is_object() {
+            return ValueSerializer::new(self.patch, self.sort_keys).serialize(serializer);
+        }
+        // the last of duplicate keys wins, at the position of the first, as
+        // when deserializing to a dict
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        let mut members: Vec<(&str, Option<Value>, Option<Value>)> = Vec::new();
+        if let Some(target) = self.target.filter(|val| val.is_object()) {
+            for (key, val) in target.members() {
+                match index.entry(key) {
+                    Entry::Occupied(entry) => members[*entry.get()].1 = Some(val),
+                    Entry::Vacant(entry) => {
+                        entry.insert(members.len());
+                        members.push((key, Some(val), None));
+                    }
+                }
+            }
+        }
+        for (key, val) in self.patch.members() {
+            match index.entry(key) {
+                Entry::Occupied(entry) => members[*entry.get()].2 = Some(val),
+                Entry::Vacant(entry) => {
+                    entry.insert(members.len());
+                    members.push((key, None, Some(val)));
+                }
+            }
+        }
+        if self.sort_keys {
+            members.sort_by(|a, b| a.0.cmp(b.0));
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        for (key, target, patch) in members {
+            match patch {
+                Some(patch) if matches!(patch.kind(), Kind::Null) => (),
+                Some(patch) => {
+                    map.serialize_key(key)?;
+                    map.serialize_value(&MergeSerializer::new(target, patch, self.sort_keys))?;
+                }
+                None => {
+                    debug_assert!(target.is_some());
+                    let target = unsafe { target.unwrap_unchecked() };
+                    map.serialize_key(key)?;
+                    map.serialize_value(&ValueSerializer::new(target, self.sort_keys))?;
+                }
+            }
+        }
+        map.end()
+    }
+}
+
+/// Apply the merge patch document `patch` to the document `target` and write
+/// the result to `bytes` formatted by `opts` without creating Python objects.
+pub(crate) fn merge_patch_json(
+    target: *mut crate::ffi::PyObject,
+    patch: *mut crate::ffi::PyObject,
+    opts: Opt,
+) -> Result<NonNull<crate::ffi::PyObject>, DeserializeError<'static>> {
+    let target_buffer = Utf8Buffer::from_pyobject(target)?;
+    let patch_buffer = Utf8Buffer::from_pyobject(patch)?;
+    let mut target_pool = PoolBuffer::new();
+    let mut patch_pool = PoolBuffer::new();
+    let target_root = read_raw(target_buffer.as_str(), &mut target_pool)?;
+    let patch_root = read_raw(patch_buffer.as_str(), &mut patch_pool)?;
+    let val = MergeSerializer::new(Some(target_root), patch_root, opt_enabled!(opts, SORT_KEYS));
+    let mut buf = BytesWriter::default();
+    let res = if opt_disabled!(opts, INDENT_2) {
+        to_writer(&mut buf, &val)
+    } else {
+        to_writer_pretty(&mut buf, &val)
+    };
+    debug_assert!(res.is_ok());
+    Ok(buf.finish(opt_enabled!(opts, APPEND_NEWLINE)))
+}
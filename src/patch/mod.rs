@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+mod equal;
+mod json_patch;
+mod merge;
+mod merge_json;
+
+pub(crate) use equal::sequence_item;
+pub(crate) use json_patch::{apply_patch, diff};
+pub(crate) use merge::{create_merge_patch, merge_patch};
+pub(crate) use merge_json::merge_patch_json;
@@ -0,0 +1,411 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::node::{NodeKind, Number, QueryNode};
+use super::parser::{
+    CmpOp, Comparable, FilterQuery, Function, FunctionArg, FunctionExpr, Literal, LogicalExpr,
+    Query, Segment, Selector,
+};
+use super::regex::translate;
+use crate::ffi::{Py_EnterRecursiveCall, Py_LeaveRecursiveCall};
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// A Python exception is set.
+pub(crate) struct Raised;
+
+type EvalResult<T> = Result<T, Raised>;
+
+/// Guard recursion into descendants, which may be unbounded for Python
+/// objects that contain themselves.
+fn enter() -> EvalResult<()> {
+    if unsafe { Py_EnterRecursiveCall(c" in query()".as_ptr()) } == 0 {
+        Ok(())
+    } else {
+        Err(Raised)
+    }
+}
+
+fn leave() {
+    unsafe {
+        Py_LeaveRecursiveCall();
+    }
+}
+
+/// Value of a comparable or function argument, per RFC 9535 2.4.
+enum Operand<'q, N> {
+    Nothing,
+    Node(N),
+    Literal(&'q Literal),
+    Number(Number),
+}
+
+impl<'q, 'a: 'q, N: QueryNode<'a>> Operand<'q, N> {
+    fn kind(&self) -> Option<NodeKind<'q>> {
+        match self {
+            Operand::Nothing => None,
+            Operand::Node(node) => Some(node.kind()),
+            Operand::Literal(Literal::Null) => Some(NodeKind::Null),
+            Operand::Literal(Literal::Bool(val)) => Some(NodeKind::Bool(*val)),
+            Operand::Literal(Literal::Number(val)) | Operand::Number(val) => {
+                Some(NodeKind::Number(*val))
+            }
+            Operand::Literal(Literal::Str(val)) => Some(NodeKind::Str(val)),
+        }
+    }
+}
+
+/// Evaluate queries on the document `root`.
+pub(crate) struct Evaluator<'a, N: QueryNode<'a>> {
+    root: N,
+    /// Compiled `match()` and `search()` patterns, or `None` if invalid.
+    regexes: HashMap<(bool, String), Option<Regex>>,
+    _node: PhantomData<&'a ()>,
+}
+
+impl<'a, N: QueryNode<'a>> Evaluator<'a, N> {
+    pub fn new(root: N) -> Self {
+        Evaluator {
+            root: root,
+            regexes: HashMap::new(),
+            _node: PhantomData,
+        }
+    }
+
+    /// Return the nodes `query` selects from the root.
+    pub fn evaluate(&mut self, query: &Query) -> EvalResult<Vec<N>> {
+        self.query(query, self.root)
+    }
+
+    fn query(&mut self, query: &Query, node: N) -> EvalResult<Vec<N>> {
+        let mut nodes = vec![node];
+        for segment in &query.segments {
+            let mut selected = Vec::new();
+            for each in nodes {
+                match segment {
+                    Segment::Child(selectors) => self.select(selectors, each, &mut selected)?,
+                    Segment::Descendant(selectors) => {
+                        self.descend(selectors, each, &mut selected)?;
+                    }
+                }
+            }
+            nodes = selected;
+        }
+        Ok(nodes)
+    }
+
+    /// Apply `selectors` to `node` and each of its descendants, in document
+    /// order.
+    fn descend(
+        &mut self,
+        selectors: &[Selector],
+        node: N,
+        selected: &mut Vec<N>,
+    ) -> EvalResult<()> {
+        self.select(selectors, node, selected)?;
+        let children: Vec<N> = match node.kind() {
+            NodeKind::Array => node.elements().collect(),
+            NodeKind::Object => node.members().map(|(_, each)| each).collect(),
+            _ => return Ok(()),
+        };
+        enter()?;
+        let ret = children
+            .into_iter()
+            .try_for_each(|each| self.descend(selectors, each, selected));
+        leave();
+        ret
+    }
+
+    fn select(&mut self, selectors: &[Selector], node: N, selected: &mut Vec<N>) -> EvalResult<()> {
+        let kind = node.kind();
+        for selector in selectors {
+            match (selector, &kind) {
+                (Selector::Name(name), NodeKind::Object) => selected.extend(node.member(name)),
+                (Selector::Wildcard, NodeKind::Array) => selected.extend(node.elements()),
+                (Selector::Wildcard, NodeKind::Object) => {
+                    selected.extend(node.members().map(|(_, each)| each));
+                }
+                (Selector::Index(idx), NodeKind::Array) => {
+                    if let Some(idx) = normalize_index(*idx, node.len()) {
+                        selected.extend(node.index(idx));
+                    }
+                }
+                (Selector::Slice(start, end, step), NodeKind::Array) => {
+                    let elements: Vec<N> = node.elements().collect();
+                    for idx in slice_indices(*start, *end, *step, elements.len()) {
+                        selected.push(elements[idx]);
+                    }
+                }
+                (Selector::Filter(expr), NodeKind::Array) => {
+                    for each in node.elements() {
+                        if self.logical(expr, each)? {
+                            selected.push(each);
+                        }
+                    }
+                }
+                (Selector::Filter(expr), NodeKind::Object) => {
+                    for (_, each) in node.members() {
+                        if self.logical(expr, each)? {
+                            selected.push(each);
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn filter_query(&mut self, query: &FilterQuery, current: N) -> EvalResult<Vec<N>> {
+        let node = if query.absolute { self.root } else { current };
+        self.query(&query.query, node)
+    }
+
+    fn logical(&mut self, expr: &LogicalExpr, current: N) -> EvalResult<bool> {
+        match expr {
+            LogicalExpr::Or(exprs) => {
+                for each in exprs {
+                    if self.logical(each, current)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            LogicalExpr::And(exprs) => {
+                for each in exprs {
+                    if !self.logical(each, current)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            LogicalExpr::Not(expr) => Ok(!self.logical(expr, current)?),
+            LogicalExpr::Comparison(left, op, right) => {
+                let left = self.comparable(left, current)?;
+                let right = self.comparable(right, current)?;
+                compare(&left, *op, &right)
+            }
+            LogicalExpr::Exists(query) => Ok(!self.filter_query(query, current)?.is_empty()),
+            LogicalExpr::Function(expr) => self.logical_function(expr, current),
+        }
+    }
+
+    fn comparable<'q>(&mut self, val: &'q Comparable, current: N) -> EvalResult<Operand<'q, N>> {
+        match val {
+            Comparable::Literal(val) => Ok(Operand::Literal(val)),
+            Comparable::Query(query) => Ok(self.singular(query, current)?),
+            Comparable::Function(expr) => self.value_function(expr, current),
+        }
+    }
+
+    fn singular<'q>(&mut self, query: &FilterQuery, current: N) -> EvalResult<Operand<'q, N>> {
+        Ok(self
+            .filter_query(query, current)?
+            .first()
+            .map_or(Operand::Nothing, |node| Operand::Node(*node)))
+    }
+
+    fn value_arg<'q>(&mut self, arg: &'q FunctionArg, current: N) -> EvalResult<Operand<'q, N>> {
+        match arg {
+            FunctionArg::Literal(val) => Ok(Operand::Literal(val)),
+            FunctionArg::Query(query) => self.singular(query, current),
+            FunctionArg::Function(expr) => self.value_function(expr, current),
+        }
+    }
+
+    fn nodes_arg(&mut self, arg: &FunctionArg, current: N) -> EvalResult<Vec<N>> {
+        match arg {
+            FunctionArg::Query(query) => self.filter_query(query, current),
+            FunctionArg::Literal(_) | FunctionArg::Function(_) => Ok(Vec::new()),
+        }
+    }
+
+    fn value_function<'q>(
+        &mut self,
+        expr: &'q FunctionExpr,
+        current: N,
+    ) -> EvalResult<Operand<'q, N>> {
+        match expr.function {
+            Function::Length => {
+                let arg = self.value_arg(&expr.args[0], current)?;
+                let len = match (arg.kind(), &arg) {
+                    (Some(NodeKind::Str(val)), _) => val.chars().count(),
+                    (Some(NodeKind::Array | NodeKind::Object), Operand::Node(node)) => node.len(),
+                    _ => return Ok(Operand::Nothing),
+                };
+                Ok(Operand::Number(Number::Int(len as i128)))
+            }
+            Function::Count => {
+                let nodes = self.nodes_arg(&expr.args[0], current)?;
+                Ok(Operand::Number(Number::Int(nodes.len() as i128)))
+            }
+            Function::Value => match self.nodes_arg(&expr.args[0], current)?.as_slice() {
+                [node] => Ok(Operand::Node(*node)),
+                _ => Ok(Operand::Nothing),
+            },
+            Function::Match | Function::Search => Ok(Operand::Nothing),
+        }
+    }
+
+    fn logical_function(&mut self, expr: &FunctionExpr, current: N) -> EvalResult<bool> {
+        let full = matches!(expr.function, Function::Match);
+        let val = self.value_arg(&expr.args[0], current)?;
+        let pattern = self.value_arg(&expr.args[1], current)?;
+        let (Some(NodeKind::Str(val)), Some(NodeKind::Str(pattern))) = (val.kind(), pattern.kind())
+        else {
+            return Ok(false);
+        };
+        let regex = self
+            .regexes
+            .entry((full, String::from(pattern)))
+            .or_insert_with(|| {
+                let translated = translate(pattern)?;
+                let translated = if full {
+                    format!("^(?:{translated})$")
+                } else {
+                    translated
+                };
+                Regex::new(&translated).ok()
+            });
+        Ok(regex.as_ref().is_some_and(|regex| regex.is_match(val)))
+    }
+}
+
+/// Return the index of `idx` in an array of `len` elements, counting from
+/// the end if negative.
+fn normalize_index(idx: i64, len: usize) -> Option<usize> {
+    let len = len.cast_signed() as i64;
+    let idx = if idx < 0 { len + idx } else { idx };
+    if (0..len).contains(&idx) {
+        Some(idx.cast_unsigned() as usize)
+    } else {
+        None
+    }
+}
+
+/// Return the indices a slice selects from an array of `len` elements, per
+/// RFC 9535 2.3.4.2.2.
+fn slice_indices(
+    start: Option<i64>,
+    end: Option<i64>,
+    step: Option<i64>,
+    len: usize,
+) -> impl Iterator<Item = usize> {
+    let len = len.cast_signed() as i64;
+    let step = step.unwrap_or(1);
+    let normalize = |idx: i64| if idx < 0 { len + idx } else { idx };
+    let (mut idx, bound) = if step >= 0 {
+        let lower = normalize(start.unwrap_or(0)).clamp(0, len);
+        let upper = normalize(end.unwrap_or(len)).clamp(0, len);
+        (lower, upper)
+    } else {
+        let upper = normalize(start.unwrap_or(len - 1)).clamp(-1, len - 1);
+        let lower = normalize(end.unwrap_or(-len - 1)).clamp(-1, len - 1);
+        (upper, lower)
+    };
+    core::iter::from_fn(move || {
+        let in_range = match step.cmp(&0) {
+            Ordering::Greater => idx < bound,
+            Ordering::Less => idx > bound,
+            Ordering::Equal => false,
+        };
+        if !in_range {
+            return None;
+        }
+        let ret = idx.cast_unsigned() as usize;
+        idx += step;
+        Some(ret)
+    })
+}
+
+fn compare<'a, N: QueryNode<'a>>(
+    left: &Operand<N>,
+    op: CmpOp,
+    right: &Operand<N>,
+) -> EvalResult<bool> {
+    Ok(match op {
+        CmpOp::Eq => equal(left, right)?,
+        CmpOp::Ne => !equal(left, right)?,
+        CmpOp::Lt => less(left, right),
+        CmpOp::Le => less(left, right) || equal(left, right)?,
+        CmpOp::Gt => less(right, left),
+        CmpOp::Ge => less(right, left) || equal(left, right)?,
+    })
+}
+
+fn equal<'a, N: QueryNode<'a>>(left: &Operand<N>, right: &Operand<N>) -> EvalResult<bool> {
+    match (left, right) {
+        (Operand::Node(left), Operand::Node(right)) => node_equal(*left, *right),
+        _ => match (left.kind(), right.kind()) {
+            (None, None) => Ok(true),
+            (Some(left), Some(right)) => Ok(scalar_equal(&left, &right)),
+            _ => Ok(false),
+        },
+    }
+}
+
+fn scalar_equal(left: &NodeKind, right: &NodeKind) -> bool {
+    match (left, right) {
+        (NodeKind::Null, NodeKind::Null) => true,
+        (NodeKind::Bool(left), NodeKind::Bool(right)) => left == right,
+        (NodeKind::Number(left), NodeKind::Number(right)) => {
+            left.compare(*right) == Some(Ordering::Equal)
+        }
+        (NodeKind::Str(left), NodeKind::Str(right)) => left == right,
+        _ => false,
+    }
+}
+
+fn node_equal<'a, N: QueryNode<'a>>(left: N, right: N) -> EvalResult<bool> {
+    if left.is(right) {
+        return Ok(true);
+    }
+    match (left.kind(), right.kind()) {
+        (NodeKind::Array, NodeKind::Array) | (NodeKind::Object, NodeKind::Object) => {
+            enter()?;
+            let ret = container_equal(left, right);
+            leave();
+            ret
+        }
+        (left, right) => Ok(scalar_equal(&left, &right)),
+    }
+}
+
+fn container_equal<'a, N: QueryNode<'a>>(left: N, right: N) -> EvalResult<bool> {
+    if left.len() != right.len() {
+        return Ok(false);
+    }
+    match left.kind() {
+        NodeKind::Array => {
+            for (left, right) in left.elements().zip(right.elements()) {
+                if !node_equal(left, right)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        _ => {
+            for (name, val) in left.members() {
+                let Some(other) = name.and_then(|name| right.member(name)) else {
+                    return Ok(false);
+                };
+                if !node_equal(val, other)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+    }
+}
+
+fn less<'a, N: QueryNode<'a>>(left: &Operand<N>, right: &Operand<N>) -> bool {
+    match (left.kind(), right.kind()) {
+        (Some(NodeKind::Number(left)), Some(NodeKind::Number(right))) => {
+            left.compare(right) == Some(Ordering::Less)
+        }
+        (Some(NodeKind::Str(left)), Some(NodeKind::Str(right))) => left < right,
+        _ => false,
+    }
+}
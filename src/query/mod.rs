@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+mod eval;
+mod node;
+mod parser;
+mod regex;
+
+use crate::deserialize::{PoolBuffer, Utf8Buffer, read};
+use crate::exception::{raise_args_exception, raise_loads_exception, raise_value_exception};
+use crate::ffi::{PyListRef, PyObject, PyStrRef};
+use core::ptr::null_mut;
+use eval::Evaluator;
+use node::{PyNode, QueryNode};
+use parser::{Query, parse_query};
+
+/// Return a `list` of the nodes `query` selects from `root`.
+fn evaluate<'a, N: QueryNode<'a>>(root: N, query: &Query) -> *mut PyObject {
+    match Evaluator::new(root).evaluate(query) {
+        Ok(nodes) => {
+            let mut list = PyListRef::with_capacity(nodes.len());
+            for (idx, each) in nodes.into_iter().enumerate() {
+                list.set(idx, each.to_python());
+            }
+            list.as_ptr()
+        }
+        Err(_) => null_mut(),
+    }
+}
+
+/// Return the values in `data` selected by the RFC 9535 JSONPath `path`.
+/// A `bytes`, `bytearray`, `memoryview`, or `str` `data` is parsed as a
+/// document and only the values selected are deserialized. Any other `data`
+/// is queried as the object `dumps()` would serialize, and the values
+/// selected are returned without copying.
+pub(crate) unsafe fn query(data: *mut PyObject, path: *mut PyObject) -> *mut PyObject {
+    let Some(path) = PyStrRef::from_ptr(path).ok().and_then(|path| path.as_str()) else {
+        return raise_args_exception("query() path must be a str");
+    };
+    let query = match parse_query(path) {
+        Ok(query) => query,
+        Err(err) => return raise_value_exception(&err.to_string()),
+    };
+    if Utf8Buffer::is_input_type(data) {
+        let buffer = match Utf8Buffer::from_pyobject(data) {
+            Ok(buffer) => buffer,
+            Err(err) => return raise_loads_exception(err),
+        };
+        let mut pool = PoolBuffer::new();
+        match read(buffer.as_str(), &mut pool) {
+            Ok(root) => evaluate(root, &query),
+            Err(err) => raise_loads_exception(err),
+        }
+    } else {
+        evaluate(PyNode::new(data), &query)
+    }
+}
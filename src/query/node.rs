@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::deserialize::{TypedKind, Value};
+use crate::ffi::{
+    Py_INCREF, Py_SIZE, PyDict_GetItemWithError, PyDict_Next, PyDict_Size, PyErr_Clear,
+    PyErr_Occurred, PyFloat_AS_DOUBLE, PyLong_AsDouble, PyLong_AsLongLong,
+    PyLong_AsUnsignedLongLong, PyObject, PyStrRef, PyStrSubclassRef,
+};
+use crate::patch::sequence_item;
+use crate::serialize::{ObType, pyobject_to_obtype};
+use crate::typeref::TRUE;
+use crate::util::usize_to_isize;
+use core::cmp::Ordering;
+use core::ptr::null_mut;
+
+/// Number compared by its mathematical value, per RFC 9535 2.3.5.2.2.
+#[derive(Clone, Copy)]
+pub(crate) enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+impl Number {
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn compare(self, other: Number) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(a), Number::Int(b)) => Some(a.cmp(&b)),
+            (Number::Float(a), Number::Float(b)) => a.partial_cmp(&b),
+            (Number::Int(a), Number::Float(b)) => match (a as f64).partial_cmp(&b)? {
+                // b is integral and so exactly an i128
+                Ordering::Equal => Some(a.cmp(&(b as i128))),
+                ordering => Some(ordering),
+            },
+            (Number::Float(_), Number::Int(_)) => other.compare(self).map(Ordering::reverse),
+        }
+    }
+}
+
+/// Type of a node, with the value of strings and numbers.
+pub(crate) enum NodeKind<'a> {
+    Null,
+    Bool(bool),
+    Number(Number),
+    Str(&'a str),
+    Array,
+    Object,
+    /// A Python object that is not a JSON type.
+    Other,
+}
+
+/// Node of a document a query selects from.
+pub(crate) trait QueryNode<'a>: Copy {
+    fn kind(self) -> NodeKind<'a>;
+
+    /// Return the element at `idx` of an array.
+    fn index(self, idx: usize) -> Option<Self>;
+
+    /// Return the value of the member named `name` of an object.
+    fn member(self, name: &str) -> Option<Self>;
+
+    /// Return the number of elements in an array or members in an object.
+    fn len(self) -> usize;
+
+    fn elements(self) -> impl Iterator<Item = Self>;
+
+    /// Return the members of an object, with `None` as the name of a member
+    /// whose key is not a `str`.
+    fn members(self) -> impl Iterator<Item = (Option<&'a str>, Self)>;
+
+    /// Return whether `self` and `other` are the same node.
+    fn is(self, other: Self) -> bool;
+
+    /// Return a new reference to the node as a Python object.
+    fn to_python(self) -> *mut PyObject;
+}
+
+impl<'p> QueryNode<'p> for Value<'p> {
+    fn kind(self) -> NodeKind<'p> {
+        match self.typed_kind() {
+            TypedKind::Null => NodeKind::Null,
+            TypedKind::Bool(val) => NodeKind::Bool(val),
+            TypedKind::Uint(val) => NodeKind::Number(Number::Int(i128::from(val))),
+            TypedKind::Int(val) => NodeKind::Number(Number::Int(i128::from(val))),
+            TypedKind::Float(val) => NodeKind::Number(Number::Float(val)),
+            TypedKind::Str(val) => NodeKind::Str(val),
+            TypedKind::Array => NodeKind::Array,
+            TypedKind::Object => NodeKind::Object,
+        }
+    }
+
+    fn index(self, idx: usize) -> Option<Self> {
+        Value::index(self, idx)
+    }
+
+    fn member(self, name: &str) -> Option<Self> {
+        self.get(name)
+    }
+
+    fn len(self) -> usize {
+        Value::len(self)
+    }
+
+    fn elements(self) -> impl Iterator<Item = Self> {
+        Value::elements(self)
+    }
+
+    fn members(self) -> impl Iterator<Item = (Option<&'p str>, Self)> {
+        Value::members(self).map(|(key, val)| (Some(key), val))
+    }
+
+    fn is(self, _other: Self) -> bool {
+        false
+    }
+
+    fn to_python(self) -> *mut PyObject {
+        Value::to_python(self).as_ptr()
+    }
+}
+
+/// Python object that `dumps()` serializes as JSON.
+#[derive(Clone, Copy)]
+pub(crate) struct PyNode {
+    ptr: *mut PyObject,
+}
+
+impl PyNode {
+    pub fn new(ptr: *mut PyObject) -> Self {
+        PyNode { ptr: ptr }
+    }
+
+    fn as_number(self) -> NodeKind<'static> {
+        unsafe {
+            let val = PyLong_AsLongLong(self.ptr);
+            if val != -1 || PyErr_Occurred().is_null() {
+                return NodeKind::Number(Number::Int(i128::from(val)));
+            }
+            PyErr_Clear();
+            let val = PyLong_AsUnsignedLongLong(self.ptr);
+            if val != u64::MAX || PyErr_Occurred().is_null() {
+                return NodeKind::Number(Number::Int(i128::from(val)));
+            }
+            PyErr_Clear();
+            let val = PyLong_AsDouble(self.ptr);
+            if val == -1.0 && !PyErr_Occurred().is_null() {
+                PyErr_Clear();
+                return NodeKind::Other;
+            }
+            NodeKind::Number(Number::Float(val))
+        }
+    }
+}
+
+impl QueryNode<'static> for PyNode {
+    fn kind(self) -> NodeKind<'static> {
+        match pyobject_to_obtype(self.ptr, 0) {
+            ObType::Str => unsafe { PyStrRef::from_ptr_unchecked(self.ptr) }
+                .as_str()
+                .map_or(NodeKind::Other, NodeKind::Str),
+            ObType::StrSubclass => unsafe { PyStrSubclassRef::from_ptr_unchecked(self.ptr) }
+                .as_str()
+                .map_or(NodeKind::Other, NodeKind::Str),
+            ObType::Int => self.as_number(),
+            ObType::Bool => NodeKind::Bool(core::ptr::eq(self.ptr, unsafe { TRUE })),
+            ObType::None => NodeKind::Null,
+            ObType::Float => {
+                NodeKind::Number(Number::Float(unsafe { PyFloat_AS_DOUBLE(self.ptr) }))
+            }
+            ObType::List | ObType::Tuple => NodeKind::Array,
+            ObType::Dict => NodeKind::Object,
+            _ => NodeKind::Other,
+        }
+    }
+
+    fn index(self, idx: usize) -> Option<Self> {
+        let idx = usize_to_isize(idx);
+        if idx < unsafe { Py_SIZE(self.ptr) } {
+            Some(PyNode::new(unsafe { sequence_item(self.ptr, idx) }))
+        } else {
+            None
+        }
+    }
+
+    fn member(self, name: &str) -> Option<Self> {
+        unsafe {
+            let key = PyStrRef::from_str(name);
+            let val = PyDict_GetItemWithError(self.ptr, key.as_ptr());
+            crate::ffi::Py_DECREF(key.as_ptr());
+            if val.is_null() {
+                PyErr_Clear();
+                None
+            } else {
+                Some(PyNode::new(val))
+            }
+        }
+    }
+
+    fn len(self) -> usize {
+        let len = unsafe {
+            if matches!(pyobject_to_obtype(self.ptr, 0), ObType::Dict) {
+                PyDict_Size(self.ptr)
+            } else {
+                Py_SIZE(self.ptr)
+            }
+        };
+        len.cast_unsigned()
+    }
+
+    fn elements(self) -> impl Iterator<Item = Self> {
+        (0..unsafe { Py_SIZE(self.ptr) })
+            .map(move |idx| PyNode::new(unsafe { sequence_item(self.ptr, idx) }))
+    }
+
+    fn members(self) -> impl Iterator<Item = (Option<&'static str>, Self)> {
+        let mut pos = 0;
+        core::iter::from_fn(move || {
+            let mut key: *mut PyObject = null_mut();
+            let mut val: *mut PyObject = null_mut();
+            if unsafe { PyDict_Next(self.ptr, &raw mut pos, &raw mut key, &raw mut val) } == 0 {
+                return None;
+            }
+            let name = match PyNode::new(key).kind() {
+                NodeKind::Str(name) => Some(name),
+                _ => None,
+            };
+            Some((name, PyNode::new(val)))
+        })
+    }
+
+    fn is(self, other: Self) -> bool {
+        core::ptr::eq(self.ptr, other.ptr)
+    }
+
+    fn to_python(self) -> *mut PyObject {
+        unsafe {
+            Py_INCREF(self.ptr);
+            self.ptr
+        }
+    }
+}
@@ -0,0 +1,703 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use super::node::Number;
+use core::fmt;
+
+/// Nesting of parentheses, filters, and function arguments in a query.
+const NESTING_LIMIT: u8 = 128;
+
+/// Largest magnitude of an index or slice, per RFC 9535 2.1.
+const MAX_INT: i64 = (1 << 53) - 1;
+
+/// RFC 9535 JSONPath query, as a sequence of segments applied to the root
+/// or current node.
+pub(crate) struct Query {
+    pub segments: Vec<Segment>,
+}
+
+impl Query {
+    /// Return whether the query selects at most one node.
+    fn is_singular(&self) -> bool {
+        self.segments.iter().all(|segment| match segment {
+            Segment::Child(selectors) => {
+                matches!(
+                    selectors.as_slice(),
+                    [Selector::Name(_) | Selector::Index(_)]
+                )
+            }
+            Segment::Descendant(_) => false,
+        })
+    }
+}
+
+pub(crate) enum Segment {
+    Child(Vec<Selector>),
+    Descendant(Vec<Selector>),
+}
+
+pub(crate) enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, Option<i64>),
+    Filter(LogicalExpr),
+}
+
+pub(crate) enum LogicalExpr {
+    Or(Vec<LogicalExpr>),
+    And(Vec<LogicalExpr>),
+    Not(Box<LogicalExpr>),
+    Comparison(Box<Comparable>, CmpOp, Box<Comparable>),
+    Exists(FilterQuery),
+    Function(FunctionExpr),
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+pub(crate) enum Comparable {
+    Literal(Literal),
+    Query(FilterQuery),
+    Function(FunctionExpr),
+}
+
+/// Query in a filter, relative to the current node `@` or the root `$`.
+pub(crate) struct FilterQuery {
+    pub absolute: bool,
+    pub query: Query,
+}
+
+pub(crate) enum Literal {
+    Null,
+    Bool(bool),
+    Number(Number),
+    Str(String),
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Function {
+    Length,
+    Count,
+    Match,
+    Search,
+    Value,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "length" => Some(Function::Length),
+            "count" => Some(Function::Count),
+            "match" => Some(Function::Match),
+            "search" => Some(Function::Search),
+            "value" => Some(Function::Value),
+            _ => None,
+        }
+    }
+
+    /// Return whether the result is a value, rather than a logical result.
+    fn returns_value(self) -> bool {
+        matches!(self, Function::Length | Function::Count | Function::Value)
+    }
+}
+
+pub(crate) struct FunctionExpr {
+    pub function: Function,
+    pub args: Vec<FunctionArg>,
+}
+
+pub(crate) enum FunctionArg {
+    Literal(Literal),
+    Query(FilterQuery),
+    Function(FunctionExpr),
+}
+
+impl FunctionArg {
+    /// Return whether the argument is of the RFC 9535 ValueType.
+    fn is_value(&self) -> bool {
+        match self {
+            FunctionArg::Literal(_) => true,
+            FunctionArg::Query(query) => query.query.is_singular(),
+            FunctionArg::Function(expr) => expr.function.returns_value(),
+        }
+    }
+
+    /// Return whether the argument is of the RFC 9535 NodesType.
+    fn is_nodes(&self) -> bool {
+        matches!(self, FunctionArg::Query(_))
+    }
+}
+
+pub(crate) struct SyntaxError {
+    msg: &'static str,
+    pos: usize,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid JSONPath: {} at position {}", self.msg, self.pos)
+    }
+}
+
+/// Parse an RFC 9535 JSONPath query.
+pub(crate) fn parse_query(input: &str) -> Result<Query, SyntaxError> {
+    let mut parser = Parser {
+        input: input,
+        pos: 0,
+        depth: 0,
+    };
+    if !parser.eat(b'$') {
+        return Err(parser.error("expected '$'"));
+    }
+    let query = parser.segments()?;
+    if parser.pos != input.len() {
+        return Err(parser.error("unexpected character"));
+    }
+    Ok(query)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: u8,
+}
+
+impl Parser<'_> {
+    #[cold]
+    fn error(&self, msg: &'static str) -> SyntaxError {
+        SyntaxError {
+            msg: msg,
+            pos: self.input[..self.pos].chars().count(),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        if self.input[self.pos..].starts_with(expected) {
+            self.pos += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: u8, msg: &'static str) -> Result<(), SyntaxError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(msg))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn enter(&mut self) -> Result<(), SyntaxError> {
+        if self.depth == NESTING_LIMIT {
+            return Err(self.error("nested too deeply"));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn segments(&mut self) -> Result<Query, SyntaxError> {
+        let mut segments = Vec::new();
+        loop {
+            let start = self.pos;
+            self.skip_whitespace();
+            if self.peek() == Some(b'[') {
+                segments.push(Segment::Child(self.bracketed_selection()?));
+            } else if self.eat_str("..") {
+                let selectors = if self.peek() == Some(b'[') {
+                    self.bracketed_selection()?
+                } else {
+                    vec![self.shorthand()?]
+                };
+                segments.push(Segment::Descendant(selectors));
+            } else if self.eat(b'.') {
+                segments.push(Segment::Child(vec![self.shorthand()?]));
+            } else {
+                self.pos = start;
+                return Ok(Query { segments: segments });
+            }
+        }
+    }
+
+    /// Parse a wildcard or member name following `.` or `..`.
+    fn shorthand(&mut self) -> Result<Selector, SyntaxError> {
+        if self.eat(b'*') {
+            return Ok(Selector::Wildcard);
+        }
+        let start = self.pos;
+        match self.peek_char() {
+            Some(each) if each.is_ascii_alphabetic() || each == '_' || !each.is_ascii() => (),
+            _ => return Err(self.error("expected member name")),
+        }
+        while let Some(each) = self.peek_char() {
+            if !(each.is_ascii_alphanumeric() || each == '_' || !each.is_ascii()) {
+                break;
+            }
+            self.pos += each.len_utf8();
+        }
+        Ok(Selector::Name(String::from(&self.input[start..self.pos])))
+    }
+
+    fn bracketed_selection(&mut self) -> Result<Vec<Selector>, SyntaxError> {
+        self.expect(b'[', "expected '['")?;
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.selector()?);
+            self.skip_whitespace();
+            if self.eat(b']') {
+                return Ok(selectors);
+            }
+            self.expect(b',', "expected ',' or ']'")?;
+        }
+    }
+
+    fn selector(&mut self) -> Result<Selector, SyntaxError> {
+        match self.peek() {
+            Some(quote @ (b'\'' | b'"')) => Ok(Selector::Name(self.string_literal(quote)?)),
+            Some(b'*') => {
+                self.pos += 1;
+                Ok(Selector::Wildcard)
+            }
+            Some(b'?') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                Ok(Selector::Filter(self.logical_expr()?))
+            }
+            Some(b':' | b'-' | b'0'..=b'9') => {
+                let start = self.optional_int()?;
+                let end_of_start = self.pos;
+                self.skip_whitespace();
+                if !self.eat(b':') {
+                    self.pos = end_of_start;
+                    return match start {
+                        Some(idx) => Ok(Selector::Index(idx)),
+                        None => Err(self.error("expected index")),
+                    };
+                }
+                self.skip_whitespace();
+                let end = self.optional_int()?;
+                self.skip_whitespace();
+                let mut step = None;
+                if self.eat(b':') {
+                    self.skip_whitespace();
+                    step = self.optional_int()?;
+                }
+                Ok(Selector::Slice(start, end, step))
+            }
+            _ => Err(self.error("expected selector")),
+        }
+    }
+
+    fn optional_int(&mut self) -> Result<Option<i64>, SyntaxError> {
+        if matches!(self.peek(), Some(b'-' | b'0'..=b'9')) {
+            Ok(Some(self.int()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parse an integer without leading zeros or `-0`.
+    fn int(&mut self) -> Result<i64, SyntaxError> {
+        let start = self.pos;
+        let negative = self.eat(b'-');
+        let digits = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        let text = &self.input[digits..self.pos];
+        if text.is_empty() || (text.starts_with('0') && (negative || text.len() > 1)) {
+            self.pos = start;
+            return Err(self.error("invalid integer"));
+        }
+        match self.input[start..self.pos].parse::<i64>() {
+            Ok(val) if (-MAX_INT..=MAX_INT).contains(&val) => Ok(val),
+            _ => {
+                self.pos = start;
+                Err(self.error("integer out of range"))
+            }
+        }
+    }
+
+    fn string_literal(&mut self, quote: u8) -> Result<String, SyntaxError> {
+        self.pos += 1;
+        let mut val = String::new();
+        loop {
+            let Some(each) = self.peek_char() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.pos += each.len_utf8();
+            match each {
+                _ if each == char::from(quote) => return Ok(val),
+                '\\' => val.push(self.escape(quote)?),
+                '\u{0}'..='\u{1f}' => {
+                    self.pos -= 1;
+                    return Err(self.error("invalid character in string"));
+                }
+                _ => val.push(each),
+            }
+        }
+    }
+
+    fn escape(&mut self, quote: u8) -> Result<char, SyntaxError> {
+        let escaped = match self.peek() {
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'/') => '/',
+            Some(b'\\') => '\\',
+            Some(each) if each == quote => char::from(quote),
+            Some(b'u') => {
+                self.pos += 1;
+                let high = self.hex4()?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    if !self.eat_str("\\u") {
+                        return Err(self.error("invalid surrogate pair"));
+                    }
+                    let low = self.hex4()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error("invalid surrogate pair"));
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                return char::from_u32(code).ok_or_else(|| self.error("invalid surrogate pair"));
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+        self.pos += 1;
+        Ok(escaped)
+    }
+
+    fn hex4(&mut self) -> Result<u32, SyntaxError> {
+        let digits = self.input.get(self.pos..self.pos + 4);
+        match digits.filter(|val| val.bytes().all(|each| each.is_ascii_hexdigit())) {
+            Some(digits) => {
+                self.pos += 4;
+                Ok(u32::from_str_radix(digits, 16).unwrap_or_default())
+            }
+            None => Err(self.error("invalid unicode escape")),
+        }
+    }
+
+    fn logical_expr(&mut self) -> Result<LogicalExpr, SyntaxError> {
+        self.enter()?;
+        let mut exprs = vec![self.logical_and_expr()?];
+        loop {
+            let start = self.pos;
+            self.skip_whitespace();
+            if !self.eat_str("||") {
+                self.pos = start;
+                break;
+            }
+            self.skip_whitespace();
+            exprs.push(self.logical_and_expr()?);
+        }
+        self.depth -= 1;
+        if exprs.len() == 1 {
+            Ok(exprs.swap_remove(0))
+        } else {
+            Ok(LogicalExpr::Or(exprs))
+        }
+    }
+
+    fn logical_and_expr(&mut self) -> Result<LogicalExpr, SyntaxError> {
+        let mut exprs = vec![self.basic_expr()?];
+        loop {
+            let start = self.pos;
+            self.skip_whitespace();
+            if !self.eat_str("&&") {
+                self.pos = start;
+                break;
+            }
+            self.skip_whitespace();
+            exprs.push(self.basic_expr()?);
+        }
+        if exprs.len() == 1 {
+            Ok(exprs.swap_remove(0))
+        } else {
+            Ok(LogicalExpr::And(exprs))
+        }
+    }
+
+    fn basic_expr(&mut self) -> Result<LogicalExpr, SyntaxError> {
+        if self.eat(b'!') {
+            self.skip_whitespace();
+            let expr = if self.peek() == Some(b'(') {
+                self.paren_expr()?
+            } else {
+                match self.comparable_or_test()? {
+                    Operand::Test(expr) => expr,
+                    Operand::Comparable(_) => {
+                        return Err(self.error("expected query or function after '!'"));
+                    }
+                }
+            };
+            return Ok(LogicalExpr::Not(Box::new(expr)));
+        }
+        if self.peek() == Some(b'(') {
+            return self.paren_expr();
+        }
+        let start = self.pos;
+        let operand = self.comparable_or_test()?;
+        let end = self.pos;
+        self.skip_whitespace();
+        let Some(op) = self.comparison_op() else {
+            self.pos = end;
+            return match operand {
+                Operand::Test(expr) => Ok(expr),
+                Operand::Comparable(_) => Err(self.error("expected comparison")),
+            };
+        };
+        let left = match operand {
+            Operand::Comparable(val) => val,
+            Operand::Test(LogicalExpr::Exists(query)) if query.query.is_singular() => {
+                Comparable::Query(query)
+            }
+            Operand::Test(_) => {
+                self.pos = start;
+                return Err(self.error("not comparable"));
+            }
+        };
+        self.skip_whitespace();
+        let right = self.comparable()?;
+        Ok(LogicalExpr::Comparison(Box::new(left), op, Box::new(right)))
+    }
+
+    fn paren_expr(&mut self) -> Result<LogicalExpr, SyntaxError> {
+        self.expect(b'(', "expected '('")?;
+        self.skip_whitespace();
+        let expr = self.logical_expr()?;
+        self.skip_whitespace();
+        self.expect(b')', "expected ')'")?;
+        Ok(expr)
+    }
+
+    fn comparison_op(&mut self) -> Option<CmpOp> {
+        let op = match self.input.as_bytes().get(self.pos..self.pos + 2) {
+            Some(b"==") => CmpOp::Eq,
+            Some(b"!=") => CmpOp::Ne,
+            Some(b"<=") => CmpOp::Le,
+            Some(b">=") => CmpOp::Ge,
+            _ => {
+                let op = match self.peek() {
+                    Some(b'<') => CmpOp::Lt,
+                    Some(b'>') => CmpOp::Gt,
+                    _ => return None,
+                };
+                self.pos += 1;
+                return Some(op);
+            }
+        };
+        self.pos += 2;
+        Some(op)
+    }
+
+    /// Parse a literal, query, or function, which is compared or, if it is
+    /// a query or a function with a logical result, a test.
+    fn comparable_or_test(&mut self) -> Result<Operand, SyntaxError> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b'@' | b'$') => Ok(Operand::Test(LogicalExpr::Exists(self.filter_query()?))),
+            Some(b'a'..=b'z') => match self.name_or_keyword()? {
+                Word::Function(expr) if expr.function.returns_value() => {
+                    Ok(Operand::Comparable(Comparable::Function(expr)))
+                }
+                Word::Function(expr) => Ok(Operand::Test(LogicalExpr::Function(expr))),
+                Word::Literal(val) => Ok(Operand::Comparable(Comparable::Literal(val))),
+            },
+            Some(b'\'' | b'"' | b'-' | b'0'..=b'9') => {
+                Ok(Operand::Comparable(Comparable::Literal(self.literal()?)))
+            }
+            _ => {
+                self.pos = start;
+                Err(self.error("expected expression"))
+            }
+        }
+    }
+
+    fn comparable(&mut self) -> Result<Comparable, SyntaxError> {
+        let start = self.pos;
+        match self.comparable_or_test()? {
+            Operand::Comparable(val) => Ok(val),
+            Operand::Test(LogicalExpr::Exists(query)) if query.query.is_singular() => {
+                Ok(Comparable::Query(query))
+            }
+            Operand::Test(_) => {
+                self.pos = start;
+                Err(self.error("not comparable"))
+            }
+        }
+    }
+
+    fn filter_query(&mut self) -> Result<FilterQuery, SyntaxError> {
+        let absolute = self.peek() == Some(b'$');
+        self.pos += 1;
+        Ok(FilterQuery {
+            absolute: absolute,
+            query: self.segments()?,
+        })
+    }
+
+    /// Parse a function expression or the literal `true`, `false`, or
+    /// `null`.
+    fn name_or_keyword(&mut self) -> Result<Word, SyntaxError> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'a'..=b'z' | b'0'..=b'9' | b'_')) {
+            self.pos += 1;
+        }
+        let name = &self.input[start..self.pos];
+        if self.peek() != Some(b'(') {
+            return match name {
+                "true" => Ok(Word::Literal(Literal::Bool(true))),
+                "false" => Ok(Word::Literal(Literal::Bool(false))),
+                "null" => Ok(Word::Literal(Literal::Null)),
+                _ => {
+                    self.pos = start;
+                    Err(self.error("expected expression"))
+                }
+            };
+        }
+        let Some(function) = Function::from_name(name) else {
+            self.pos = start;
+            return Err(self.error("unknown function"));
+        };
+        self.enter()?;
+        self.pos += 1;
+        self.skip_whitespace();
+        let mut args = Vec::new();
+        if !self.eat(b')') {
+            loop {
+                args.push(self.function_arg()?);
+                self.skip_whitespace();
+                if self.eat(b')') {
+                    break;
+                }
+                self.expect(b',', "expected ',' or ')'")?;
+                self.skip_whitespace();
+            }
+        }
+        self.depth -= 1;
+        let well_typed = match function {
+            Function::Length => matches!(args.as_slice(), [arg] if arg.is_value()),
+            Function::Count | Function::Value => matches!(args.as_slice(), [arg] if arg.is_nodes()),
+            Function::Match | Function::Search => {
+                matches!(args.as_slice(), [arg, pattern] if arg.is_value() && pattern.is_value())
+            }
+        };
+        if !well_typed {
+            self.pos = start;
+            return Err(self.error("invalid function arguments"));
+        }
+        Ok(Word::Function(FunctionExpr {
+            function: function,
+            args: args,
+        }))
+    }
+
+    fn function_arg(&mut self) -> Result<FunctionArg, SyntaxError> {
+        match self.peek() {
+            Some(b'@' | b'$') => Ok(FunctionArg::Query(self.filter_query()?)),
+            Some(b'a'..=b'z') => match self.name_or_keyword()? {
+                Word::Function(expr) => Ok(FunctionArg::Function(expr)),
+                Word::Literal(val) => Ok(FunctionArg::Literal(val)),
+            },
+            Some(b'\'' | b'"' | b'-' | b'0'..=b'9') => Ok(FunctionArg::Literal(self.literal()?)),
+            _ => Err(self.error("invalid function arguments")),
+        }
+    }
+
+    /// Parse a string or number literal.
+    fn literal(&mut self) -> Result<Literal, SyntaxError> {
+        if let Some(quote @ (b'\'' | b'"')) = self.peek() {
+            return Ok(Literal::Str(self.string_literal(quote)?));
+        }
+        let start = self.pos;
+        let mut integral = true;
+        self.eat(b'-');
+        let digits = self.pos;
+        if !self.digits() || (self.input.as_bytes()[digits] == b'0' && self.pos - digits > 1) {
+            self.pos = start;
+            return Err(self.error("invalid number"));
+        }
+        if self.eat(b'.') {
+            integral = false;
+            if !self.digits() {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            integral = false;
+            if !self.eat(b'+') {
+                self.eat(b'-');
+            }
+            if !self.digits() {
+                return Err(self.error("invalid number"));
+            }
+        }
+        let text = &self.input[start..self.pos];
+        let number = match text.parse::<i64>() {
+            Ok(val) if integral => Number::Int(i128::from(val)),
+            _ => match text.parse::<f64>() {
+                Ok(val) if val.is_finite() => Number::Float(val),
+                _ => {
+                    self.pos = start;
+                    return Err(self.error("number out of range"));
+                }
+            },
+        };
+        Ok(Literal::Number(number))
+    }
+
+    fn digits(&mut self) -> bool {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+}
+
+enum Operand {
+    Comparable(Comparable),
+    Test(LogicalExpr),
+}
+
+enum Word {
+    Function(FunctionExpr),
+    Literal(Literal),
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use core::iter::Peekable;
+use core::str::Chars;
+
+/// Translate an RFC 9485 I-Regexp to the syntax of the `regex` crate, or
+/// return `None` if `pattern` is not a valid I-Regexp.
+///
+/// I-Regexp has no anchors, so `^` and `$` are literal, and `.` matches any
+/// character except `\n` and `\r`.
+pub(crate) fn translate(pattern: &str) -> Option<String> {
+    let mut out = String::with_capacity(pattern.len() + 8);
+    let mut chars = pattern.chars().peekable();
+    while let Some(each) = chars.next() {
+        match each {
+            '.' => out.push_str("[^\\n\\r]"),
+            '^' | '$' => {
+                out.push('\\');
+                out.push(each);
+            }
+            '\\' => escape(&mut chars, &mut out)?,
+            '[' => class(&mut chars, &mut out)?,
+            '(' => {
+                if chars.peek() == Some(&'?') {
+                    return None;
+                }
+                out.push('(');
+            }
+            '{' => quantifier(&mut chars, &mut out)?,
+            ']' | '}' => return None,
+            _ => out.push(each),
+        }
+    }
+    Some(out)
+}
+
+/// Translate a character class following its `[`.
+fn class(chars: &mut Peekable<Chars>, out: &mut String) -> Option<()> {
+    out.push('[');
+    if chars.peek() == Some(&'^') {
+        chars.next();
+        out.push('^');
+    }
+    let mut empty = true;
+    loop {
+        match chars.next()? {
+            ']' if !empty => {
+                out.push(']');
+                return Some(());
+            }
+            '[' | ']' => return None,
+            '\\' => escape(chars, out)?,
+            '-' => out.push('-'),
+            each if each.is_ascii_punctuation() => {
+                out.push('\\');
+                out.push(each);
+            }
+            each => out.push(each),
+        }
+        empty = false;
+    }
+}
+
+/// Translate a quantifier `{n}`, `{n,}`, or `{n,m}` following its `{`.
+fn quantifier(chars: &mut Peekable<Chars>, out: &mut String) -> Option<()> {
+    out.push('{');
+    let mut digits = false;
+    let mut comma = false;
+    loop {
+        match chars.next()? {
+            each @ '0'..='9' => {
+                digits = true;
+                out.push(each);
+            }
+            ',' if digits && !comma => {
+                comma = true;
+                out.push(',');
+            }
+            '}' if digits => {
+                out.push('}');
+                return Some(());
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Translate an escape following its `\`.
+fn escape(chars: &mut Peekable<Chars>, out: &mut String) -> Option<()> {
+    let each = chars.next()?;
+    match each {
+        '(' | ')' | '*' | '+' | '-' | '.' | '?' | '[' | '\\' | ']' | '^' | '{' | '|' | '}' => {
+            out.push('\\');
+            out.push(each);
+        }
+        'n' => out.push_str("\\n"),
+        'r' => out.push_str("\\r"),
+        't' => out.push_str("\\t"),
+        'p' | 'P' => {
+            if chars.next()? != '{' {
+                return None;
+            }
+            let mut name = String::new();
+            loop {
+                match chars.next()? {
+                    '}' => break,
+                    letter if letter.is_ascii_alphabetic() && name.len() < 2 => name.push(letter),
+                    _ => return None,
+                }
+            }
+            let mut letters = name.chars();
+            match (letters.next(), letters.next()) {
+                (Some(major), minor) if major.is_ascii_uppercase() => {
+                    if minor.is_some_and(|minor| !minor.is_ascii_lowercase()) {
+                        return None;
+                    }
+                }
+                _ => return None,
+            }
+            out.push('\\');
+            out.push(each);
+            out.push('{');
+            out.push_str(&name);
+            out.push('}');
+        }
+        _ => return None,
+    }
+    Some(())
+}
@@ -0,0 +1,1001 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::case::{changes_case, convert_case};
+use crate::ffi::{Py_DECREF, Py_INCREF, PyObject, PyStrRef, PyTupleRef, PyTypeObject};
+#[cfg(not(Py_GIL_DISABLED))]
+use crate::opt::KEY_CASE;
+use crate::opt::Opt;
+use crate::serialize::error::SerializeError;
+use crate::serialize::writer::escape_str;
+use crate::typeref::{
+    ATTRS_ATTRS_STR, ATTRS_TYPES, DATACLASS_FIELDS_STR, DATACLASS_MISSING, DEFAULT,
+    DEFAULT_FACTORY_STR, DICT_STR, DICT_TYPE, FACTORY_STR, FIELD_DEFAULTS_STR, FIELD_TYPE,
+    FIELD_TYPE_STR, FIELDS_STR, INCLUDE_STR, LIST_TYPE, METADATA_STR, NAME_STR, ORJSON_METHOD_STR,
+    ORJSON_STR, SKIP_STR, SLOTS_STR, STR_TYPE, TAKES_SELF_STR, TUPLE_TYPE, load_attrs_types,
+};
+#[cfg(not(Py_GIL_DISABLED))]
+use associative_cache::Capacity2048;
+use associative_cache::{AssociativeCache, Capacity256, HashDirectMapped, RoundRobinReplacement};
+#[cfg(not(Py_GIL_DISABLED))]
+use core::cell::OnceCell;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// What a field's value is compared to for `OPT_OMIT_DEFAULTS`.
+pub(crate) enum FieldDefault {
+    Missing,
+    Value(*mut PyObject),
+    EmptyContainer(*mut PyTypeObject),
+}
+
+impl FieldDefault {
+    /// Whether `value` equals the default. `Value` requires the same type,
+    /// so that, e.g., `False` is not the default `0`, and then compares
+    /// using `__eq__`, which may raise.
+    #[cold]
+    #[inline(never)]
+    pub fn is_default(&self, value: *mut PyObject) -> Result<bool, SerializeError> {
+        match *self {
+            FieldDefault::Missing => Ok(false),
+            FieldDefault::Value(default) => {
+                if core::ptr::eq(value, default) {
+                    return Ok(true);
+                }
+                if !core::ptr::eq(ffi!(Py_TYPE(value)), ffi!(Py_TYPE(default))) {
+                    return Ok(false);
+                }
+                match ffi!(PyObject_RichCompareBool(value, default, crate::ffi::Py_EQ)) {
+                    0 => Ok(false),
+                    1 => Ok(true),
+                    _ => Err(SerializeError::DataclassDefaultComparison),
+                }
+            }
+            FieldDefault::EmptyContainer(ob_type) => {
+                Ok(core::ptr::eq(ffi!(Py_TYPE(value)), ob_type) && ffi!(PyObject_Size(value)) == 0)
+            }
+        }
+    }
+}
+
+/// A field that is serialized. `name` is the attribute and `key` is what it
+/// is serialized as, which differs if renamed using field metadata.
+/// `escaped` is `key` as a JSON string so that it is not escaped per instance.
+pub(crate) struct DataclassField {
+    pub name: *mut PyObject,
+    pub key: String,
+    pub escaped: Vec<u8>,
+    pub default: FieldDefault,
+    /// The name begins with an underscore and the field is not included
+    /// using field metadata, so it is serialized only with
+    /// `OPT_SERIALIZE_UNDERSCORE`.
+    pub private: bool,
+    /// The key is given by field metadata, so it is not converted to the
+    /// case given by option.
+    pub renamed: bool,
+}
+
+/// The fields of a dataclass or attrs class that are serialized, in the order
+/// of `__dataclass_fields__` or `__attrs_attrs__`, the `_fields` of a named
+/// tuple, or the `__slots__` of a class serialized using
+/// `OPT_SERIALIZE_OBJECT`. It holds a weak reference to the class so that a
+/// cached plan does not keep the class alive and a plan for a class that was
+/// freed, and whose address may be reused, is not used. It also holds the
+/// version of the class so that a plan is loaded again if the class or a base
+/// is modified, such as by assigning a field's default.
+pub(crate) struct DataclassPlan {
+    class: *mut PyObject,
+    version: u32,
+    pub fields: Vec<DataclassField>,
+    /// The class defines `__slots__`, so fields are read as attributes
+    /// rather than from the instance's `__dict__`.
+    pub slots: bool,
+    /// The attributes of fields skipped using field metadata, which are not
+    /// serialized from the instance's `__dict__`.
+    pub skipped: Vec<*mut PyObject>,
+    /// The class is not of the kind the plan is for, e.g., it has
+    /// `__attrs_attrs__` but was not defined using attrs. This is cached so
+    /// that the class is not inspected again for each instance.
+    pub rejected: bool,
+}
+
+/// Whether the `__dict__` key `key` is the attribute `name`. A key is usually
+/// the same interned `str` as the name, but need not be, e.g., if the
+/// `__dict__` was updated with deserialized keys.
+#[inline]
+fn is_name(name: *mut PyObject, key: *mut PyObject) -> bool {
+    core::ptr::eq(name, key)
+        || (ffi!(PyUnicode_Check(key)) != 0 && ffi!(PyUnicode_Compare(name, key)) == 0)
+}
+
+impl DataclassPlan {
+    /// Whether the attribute `name` is a field skipped using field metadata.
+    #[cold]
+    #[inline(never)]
+    pub fn is_skipped(&self, name: *mut PyObject) -> bool {
+        self.skipped.iter().any(|skipped| is_name(*skipped, name))
+    }
+
+    /// The field whose attribute is `name`, if any.
+    #[cold]
+    #[inline(never)]
+    pub fn field(&self, name: *mut PyObject) -> Option<&DataclassField> {
+        self.fields.iter().find(|field| is_name(field.name, name))
+    }
+
+    #[inline(always)]
+    fn is_plan_of(&self, ob_type: *mut PyTypeObject) -> bool {
+        refers_to(self.class, ob_type) && self.version == type_version(ob_type)
+    }
+}
+
+/// Whether the weak reference `class` refers to `ob_type`.
+#[cfg(CPython)]
+#[inline(always)]
+fn refers_to(class: *mut PyObject, ob_type: *mut PyTypeObject) -> bool {
+    unsafe {
+        core::ptr::eq(
+            (*class.cast::<crate::ffi::PyWeakReference>()).wr_object,
+            ob_type.cast::<PyObject>(),
+        )
+    }
+}
+
+#[cfg(not(CPython))]
+#[inline(always)]
+fn refers_to(class: *mut PyObject, ob_type: *mut PyTypeObject) -> bool {
+    #[allow(deprecated)]
+    unsafe {
+        core::ptr::eq(
+            crate::ffi::PyWeakref_GetObject(class),
+            ob_type.cast::<PyObject>(),
+        )
+    }
+}
+
+unsafe impl Send for DataclassPlan {}
+unsafe impl Sync for DataclassPlan {}
+
+impl Drop for DataclassPlan {
+    fn drop(&mut self) {
+        unsafe {
+            for field in self.fields.iter() {
+                Py_DECREF(field.name);
+                if let FieldDefault::Value(default) = field.default {
+                    Py_DECREF(default);
+                }
+            }
+            for name in self.skipped.iter() {
+                Py_DECREF(*name);
+            }
+            crate::ffi::Py_XDECREF(self.class);
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn empty_container_type(factory: *mut PyObject) -> Option<*mut PyTypeObject> {
+    unsafe {
+        [
+            LIST_TYPE,
+            DICT_TYPE,
+            TUPLE_TYPE,
+            &raw mut crate::ffi::PySet_Type,
+            &raw mut crate::ffi::PyFrozenSet_Type,
+        ]
+        .into_iter()
+        .find(|ob_type| core::ptr::eq(factory, ob_type.cast::<PyObject>()))
+    }
+}
+
+/// Options of a field given by
+/// `metadata={"orjson": {"name": ..., "skip": ..., "include": ...}}`.
+struct FieldMetadata {
+    name: Option<String>,
+    skip: bool,
+    include: bool,
+}
+
+#[cold]
+#[inline(never)]
+fn field_metadata(field: *mut PyObject) -> Result<Option<FieldMetadata>, SerializeError> {
+    unsafe {
+        let metadata = crate::ffi::PyObject_GetAttr(field, METADATA_STR);
+        debug_assert!(!metadata.is_null());
+        let options = crate::ffi::PyObject_GetItem(metadata, ORJSON_STR);
+        Py_DECREF(metadata);
+        if options.is_null() {
+            crate::ffi::PyErr_Clear();
+            return Ok(None);
+        }
+        if crate::ffi::PyDict_Check(options) == 0 {
+            Py_DECREF(options);
+            return Err(SerializeError::DataclassFieldMetadata);
+        }
+        let mut ret = FieldMetadata {
+            name: None,
+            skip: false,
+            include: false,
+        };
+        let name = crate::ffi::PyDict_GetItemWithError(options, NAME_STR);
+        if !name.is_null() {
+            match PyStrRef::from_ptr(name).ok().and_then(|name| name.as_str()) {
+                Some(uni) if !uni.is_empty() => ret.name = Some(String::from(uni)),
+                _ => {
+                    Py_DECREF(options);
+                    return Err(SerializeError::DataclassFieldMetadata);
+                }
+            }
+        }
+        let skip = crate::ffi::PyDict_GetItemWithError(options, SKIP_STR);
+        if !skip.is_null() {
+            ret.skip = crate::ffi::PyObject_IsTrue(skip) == 1;
+        }
+        let include = crate::ffi::PyDict_GetItemWithError(options, INCLUDE_STR);
+        if !include.is_null() {
+            ret.include = crate::ffi::PyObject_IsTrue(include) == 1;
+        }
+        Py_DECREF(options);
+        Ok(Some(ret))
+    }
+}
+
+/// The field whose attribute is `name`, described by `field`, or `None` if
+/// it is skipped using field metadata. `name` is borrowed and `default` is
+/// `FieldDefault::Missing` until the caller sets them.
+#[cold]
+#[inline(never)]
+fn new_field(
+    plan: &mut DataclassPlan,
+    field: *mut PyObject,
+    name: *mut PyObject,
+) -> Result<Option<DataclassField>, SerializeError> {
+    let Some(name_as_str) = (unsafe { PyStrRef::from_ptr_unchecked(name).as_str() }) else {
+        return Err(SerializeError::InvalidStr);
+    };
+    let mut private = name_as_str.starts_with('_');
+    let mut renamed = false;
+    let mut key = String::from(name_as_str);
+    if let Some(metadata) = field_metadata(field)? {
+        if metadata.skip {
+            ffi!(Py_INCREF(name));
+            plan.skipped.push(name);
+            return Ok(None);
+        }
+        if let Some(name) = metadata.name {
+            renamed = true;
+            key = name;
+        }
+        if metadata.include {
+            private = false;
+        }
+    }
+    Ok(Some(DataclassField {
+        name: name,
+        escaped: escape_str(&key),
+        key: key,
+        default: FieldDefault::Missing,
+        private: private,
+        renamed: renamed,
+    }))
+}
+
+#[cold]
+#[inline(never)]
+fn load_dataclass_plan(ob_type: *mut PyTypeObject) -> Result<DataclassPlan, SerializeError> {
+    unsafe {
+        let mut plan = DataclassPlan {
+            class: core::ptr::null_mut(),
+            version: 0,
+            fields: Vec::new(),
+            slots: pydict_contains!(ob_type, SLOTS_STR),
+            skipped: Vec::new(),
+            rejected: false,
+        };
+        let dataclass_fields =
+            crate::ffi::PyObject_GetAttr(ob_type.cast::<PyObject>(), DATACLASS_FIELDS_STR);
+        debug_assert!(!dataclass_fields.is_null());
+        let mut pos = 0;
+        let mut name: *mut PyObject = core::ptr::null_mut();
+        let mut field: *mut PyObject = core::ptr::null_mut();
+        while crate::ffi::PyDict_Next(
+            dataclass_fields,
+            &raw mut pos,
+            &raw mut name,
+            &raw mut field,
+        ) != 0
+        {
+            let field_type = crate::ffi::PyObject_GetAttr(field, FIELD_TYPE_STR);
+            Py_DECREF(field_type);
+            if !core::ptr::eq(field_type.cast::<PyTypeObject>(), FIELD_TYPE) {
+                continue;
+            }
+            let mut entry = match new_field(&mut plan, field, name) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => continue,
+                Err(err) => {
+                    Py_DECREF(dataclass_fields);
+                    return Err(err);
+                }
+            };
+            let default = crate::ffi::PyObject_GetAttr(field, DEFAULT);
+            entry.default = if core::ptr::eq(default, DATACLASS_MISSING) {
+                Py_DECREF(default);
+                let factory = crate::ffi::PyObject_GetAttr(field, DEFAULT_FACTORY_STR);
+                Py_DECREF(factory);
+                match empty_container_type(factory) {
+                    Some(container) => FieldDefault::EmptyContainer(container),
+                    None => FieldDefault::Missing,
+                }
+            } else {
+                FieldDefault::Value(default)
+            };
+            Py_INCREF(name);
+            plan.fields.push(entry);
+        }
+        Py_DECREF(dataclass_fields);
+        Ok(plan)
+    }
+}
+
+/// The default of an attrs `Attribute`, which is `attr.NOTHING`, an
+/// `attr.Factory`, or a value.
+#[cold]
+#[inline(never)]
+unsafe fn attrs_default(attribute: *mut PyObject) -> FieldDefault {
+    unsafe {
+        let types = ATTRS_TYPES.get_or_init(load_attrs_types);
+        let default = crate::ffi::PyObject_GetAttr(attribute, DEFAULT);
+        if core::ptr::eq(default, types.nothing) {
+            Py_DECREF(default);
+            FieldDefault::Missing
+        } else if core::ptr::eq(crate::ffi::Py_TYPE(default), types.factory) {
+            let takes_self = crate::ffi::PyObject_GetAttr(default, TAKES_SELF_STR);
+            let factory = crate::ffi::PyObject_GetAttr(default, FACTORY_STR);
+            Py_DECREF(default);
+            Py_DECREF(takes_self);
+            Py_DECREF(factory);
+            match empty_container_type(factory) {
+                Some(container) if crate::ffi::PyObject_IsTrue(takes_self) == 0 => {
+                    FieldDefault::EmptyContainer(container)
+                }
+                _ => FieldDefault::Missing,
+            }
+        } else {
+            FieldDefault::Value(default)
+        }
+    }
+}
+
+/// Whether `attributes`, the `__attrs_attrs__` of a class, is as attrs
+/// writes it. attrs gives each class `__attrs_attrs__` of a subclass of
+/// `tuple` it generates, whose items are `attr.Attribute`, so a class whose
+/// `__attrs_attrs__` is written by hand, e.g., as `()`, is not attrs.
+unsafe fn is_attrs_attributes(attributes: *mut PyObject) -> bool {
+    unsafe {
+        if crate::ffi::PyTuple_Check(attributes) == 0
+            || is_type!(crate::ffi::Py_TYPE(attributes), TUPLE_TYPE)
+        {
+            return false;
+        }
+        // An attrs class cannot exist unless `attr` has been imported, and it
+        // is not imported here otherwise.
+        let module = crate::ffi::PyMapping_GetItemString(
+            crate::ffi::PyImport_GetModuleDict(),
+            c"attr".as_ptr(),
+        );
+        if module.is_null() {
+            crate::ffi::PyErr_Clear();
+            return false;
+        }
+        Py_DECREF(module);
+        let types = ATTRS_TYPES.get_or_init(load_attrs_types);
+        if types.attribute.is_null() {
+            return false;
+        }
+        let attributes = PyTupleRef::from_ptr_unchecked(attributes);
+        (0..attributes.len())
+            .all(|idx| crate::ffi::PyObject_TypeCheck(attributes.get(idx), types.attribute) != 0)
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn load_attrs_plan(ob_type: *mut PyTypeObject) -> Result<DataclassPlan, SerializeError> {
+    unsafe {
+        let mut plan = DataclassPlan {
+            class: core::ptr::null_mut(),
+            version: 0,
+            fields: Vec::new(),
+            slots: pydict_contains!(ob_type, SLOTS_STR),
+            skipped: Vec::new(),
+            rejected: false,
+        };
+        let attributes = crate::ffi::PyObject_GetAttr(ob_type.cast::<PyObject>(), ATTRS_ATTRS_STR);
+        if attributes.is_null() {
+            cold_path!();
+            crate::ffi::PyErr_Clear();
+            plan.rejected = true;
+            return Ok(plan);
+        }
+        if !is_attrs_attributes(attributes) {
+            Py_DECREF(attributes);
+            plan.rejected = true;
+            return Ok(plan);
+        }
+        let attributes_ref = PyTupleRef::from_ptr_unchecked(attributes);
+        for idx in 0..attributes_ref.len() {
+            let attribute = attributes_ref.get(idx);
+            let mut name = crate::ffi::PyObject_GetAttr(attribute, NAME_STR);
+            if name.is_null() || !is_class_by_type!(crate::ffi::Py_TYPE(name), STR_TYPE) {
+                crate::ffi::PyErr_Clear();
+                crate::ffi::Py_XDECREF(name);
+                Py_DECREF(attributes);
+                return Err(SerializeError::AttrsAttributes);
+            }
+            // So that it is the same object as the key in the instance's
+            // `__dict__`, which is compared by address.
+            crate::ffi::PyUnicode_InternInPlace(&raw mut name);
+            let mut entry = match new_field(&mut plan, attribute, name) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => {
+                    Py_DECREF(name);
+                    continue;
+                }
+                Err(err) => {
+                    Py_DECREF(name);
+                    Py_DECREF(attributes);
+                    return Err(err);
+                }
+            };
+            entry.default = attrs_default(attribute);
+            plan.fields.push(entry);
+        }
+        Py_DECREF(attributes);
+        Ok(plan)
+    }
+}
+
+/// The names in the `__slots__` of the class `base`, which is a `str` or an
+/// iterable of `str`, if it defines `__slots__`. The names are owned.
+#[cold]
+#[inline(never)]
+unsafe fn own_slots(base: *mut PyObject) -> Vec<*mut PyObject> {
+    unsafe {
+        let mut names = Vec::new();
+        let dict = crate::ffi::PyObject_GetAttr(base, DICT_STR);
+        if dict.is_null() {
+            crate::ffi::PyErr_Clear();
+            return names;
+        }
+        let slots = crate::ffi::PyObject_GetItem(dict, SLOTS_STR);
+        Py_DECREF(dict);
+        if slots.is_null() {
+            crate::ffi::PyErr_Clear();
+            return names;
+        }
+        if is_class_by_type!(crate::ffi::Py_TYPE(slots), STR_TYPE) {
+            names.push(slots);
+            return names;
+        }
+        let iter = crate::ffi::PyObject_GetIter(slots);
+        Py_DECREF(slots);
+        if iter.is_null() {
+            crate::ffi::PyErr_Clear();
+            return names;
+        }
+        loop {
+            let name = crate::ffi::PyIter_Next(iter);
+            if name.is_null() {
+                crate::ffi::PyErr_Clear();
+                break;
+            }
+            names.push(name);
+        }
+        Py_DECREF(iter);
+        names
+    }
+}
+
+/// `name` as it is stored by the class `base`: a name beginning, but not
+/// ending, with two underscores is prefixed by the class name.
+#[cold]
+#[inline(never)]
+unsafe fn mangle_slot(base: *mut PyTypeObject, name: &str) -> Option<String> {
+    if !name.starts_with("__") || name.ends_with("__") {
+        return None;
+    }
+    let class_name = unsafe { core::ffi::CStr::from_ptr((*base).tp_name) }
+        .to_str()
+        .ok()?;
+    let class_name = class_name.rsplit('.').next()?.trim_start_matches('_');
+    if class_name.is_empty() {
+        return None;
+    }
+    Some(format!("_{class_name}{name}"))
+}
+
+/// The plan of a class serialized using `OPT_SERIALIZE_OBJECT`, which is the
+/// `__slots__` of it and its bases, bases first. Attributes in the
+/// instance's `__dict__` are not part of the plan.
+#[cold]
+#[inline(never)]
+fn load_object_plan(ob_type: *mut PyTypeObject) -> Result<DataclassPlan, SerializeError> {
+    unsafe {
+        let mut plan = DataclassPlan {
+            class: core::ptr::null_mut(),
+            version: 0,
+            fields: Vec::new(),
+            slots: true,
+            skipped: Vec::new(),
+            rejected: false,
+        };
+        let mro = PyTupleRef::from_ptr_unchecked((*ob_type).tp_mro);
+        for idx in (0..mro.len()).rev() {
+            let base = mro.get(idx);
+            let mut names = own_slots(base).into_iter();
+            while let Some(mut name) = names.next() {
+                let Some(name_as_str) = PyStrRef::from_ptr(name).ok().and_then(|uni| uni.as_str())
+                else {
+                    Py_DECREF(name);
+                    names.for_each(|name| Py_DECREF(name));
+                    return Err(SerializeError::InvalidStr);
+                };
+                if name_as_str == "__dict__" || name_as_str == "__weakref__" {
+                    Py_DECREF(name);
+                    continue;
+                }
+                let key = match mangle_slot(base.cast::<PyTypeObject>(), name_as_str) {
+                    Some(mangled) => {
+                        Py_DECREF(name);
+                        name = crate::ffi::PyUnicode_FromStringAndSize(
+                            mangled.as_ptr().cast::<core::ffi::c_char>(),
+                            crate::util::usize_to_isize(mangled.len()),
+                        );
+                        mangled
+                    }
+                    None => String::from(name_as_str),
+                };
+                crate::ffi::PyUnicode_InternInPlace(&raw mut name);
+                plan.fields.push(DataclassField {
+                    name: name,
+                    escaped: escape_str(&key),
+                    private: key.starts_with('_'),
+                    key: key,
+                    default: FieldDefault::Missing,
+                    renamed: false,
+                });
+            }
+        }
+        Ok(plan)
+    }
+}
+
+/// The plan of a named tuple, whose fields are its `_fields` in order and
+/// whose defaults are its `_field_defaults`.
+#[cold]
+#[inline(never)]
+fn load_namedtuple_plan(ob_type: *mut PyTypeObject) -> Result<DataclassPlan, SerializeError> {
+    unsafe {
+        let mut plan = DataclassPlan {
+            class: core::ptr::null_mut(),
+            version: 0,
+            fields: Vec::new(),
+            slots: true,
+            skipped: Vec::new(),
+            rejected: false,
+        };
+        // `_fields` is looked up as an attribute rather than in the class's
+        // `__dict__` because a subclass of a named tuple inherits it. A
+        // subclass of `tuple` without `_fields` that is a tuple is not a named
+        // tuple.
+        let fields = crate::ffi::PyObject_GetAttr(ob_type.cast::<PyObject>(), FIELDS_STR);
+        if fields.is_null() {
+            crate::ffi::PyErr_Clear();
+            plan.rejected = true;
+            return Ok(plan);
+        } else if crate::ffi::PyTuple_Check(fields) == 0 {
+            Py_DECREF(fields);
+            plan.rejected = true;
+            return Ok(plan);
+        }
+        let defaults = crate::ffi::PyObject_GetAttr(ob_type.cast::<PyObject>(), FIELD_DEFAULTS_STR);
+        if defaults.is_null() {
+            crate::ffi::PyErr_Clear();
+        }
+        let fields_ref = PyTupleRef::from_ptr_unchecked(fields);
+        for idx in 0..fields_ref.len() {
+            let name = fields_ref.get(idx);
+            let Some(key) = PyStrRef::from_ptr(name).ok().and_then(|uni| uni.as_str()) else {
+                Py_DECREF(fields);
+                crate::ffi::Py_XDECREF(defaults);
+                return Err(SerializeError::NamedTupleFields);
+            };
+            let mut default = FieldDefault::Missing;
+            if !defaults.is_null() && crate::ffi::PyDict_Check(defaults) != 0 {
+                let value = crate::ffi::PyDict_GetItemWithError(defaults, name);
+                if !value.is_null() {
+                    Py_INCREF(value);
+                    default = FieldDefault::Value(value);
+                }
+            }
+            Py_INCREF(name);
+            plan.fields.push(DataclassField {
+                name: name,
+                key: String::from(key),
+                escaped: escape_str(key),
+                default: default,
+                private: false,
+                renamed: false,
+            });
+        }
+        Py_DECREF(fields);
+        crate::ffi::Py_XDECREF(defaults);
+        Ok(plan)
+    }
+}
+
+pub(crate) type DataclassPlanMap = AssociativeCache<
+    usize,
+    Arc<DataclassPlan>,
+    Capacity256,
+    HashDirectMapped,
+    RoundRobinReplacement,
+>;
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) static mut DATACLASS_PLAN_MAP: OnceCell<DataclassPlanMap> = OnceCell::new();
+
+/// Free-threaded builds share the cache between threads, so it is locked
+/// only while a plan is looked up or inserted.
+#[cfg(Py_GIL_DISABLED)]
+static DATACLASS_PLAN_MAP: std::sync::LazyLock<std::sync::Mutex<DataclassPlanMap>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(DataclassPlanMap::default()));
+
+/// Call `f` with the cache of plans, which must not run Python code because
+/// that may serialize and so use the cache again.
+#[cfg(not(Py_GIL_DISABLED))]
+#[inline(always)]
+fn with_plan_map<T>(f: impl FnOnce(&mut DataclassPlanMap) -> T) -> T {
+    unsafe {
+        f(DATACLASS_PLAN_MAP
+            .get_mut()
+            .unwrap_or_else(|| unreachable_unchecked!()))
+    }
+}
+
+#[cfg(Py_GIL_DISABLED)]
+#[inline(always)]
+fn with_plan_map<T>(f: impl FnOnce(&mut DataclassPlanMap) -> T) -> T {
+    f(&mut DATACLASS_PLAN_MAP
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner))
+}
+
+/// What a plan is for, which is part of its key in the cache so that, e.g.,
+/// the plan of a dataclass serialized using `OPT_SERIALIZE_OBJECT` is not
+/// its dataclass plan. A `PyTypeObject` is aligned to more than 4 bytes, so
+/// its address has the low bits free.
+#[repr(usize)]
+#[derive(Clone, Copy)]
+enum PlanKind {
+    Dataclass = 0,
+    Attrs = 1,
+    Object = 2,
+    NamedTuple = 3,
+}
+
+/// The plan of the dataclass `ob_type`.
+#[inline(always)]
+pub(crate) fn dataclass_plan(
+    ob_type: *mut PyTypeObject,
+) -> Result<Arc<DataclassPlan>, SerializeError> {
+    cached_plan(ob_type, PlanKind::Dataclass, load_dataclass_plan)
+}
+
+/// The plan of the attrs class `ob_type`.
+#[inline(always)]
+pub(crate) fn attrs_plan(ob_type: *mut PyTypeObject) -> Result<Arc<DataclassPlan>, SerializeError> {
+    cached_plan(ob_type, PlanKind::Attrs, load_attrs_plan)
+}
+
+/// The plan of the class `ob_type` serialized using `OPT_SERIALIZE_OBJECT`.
+#[inline(always)]
+pub(crate) fn object_plan(
+    ob_type: *mut PyTypeObject,
+) -> Result<Arc<DataclassPlan>, SerializeError> {
+    cached_plan(ob_type, PlanKind::Object, load_object_plan)
+}
+
+/// The plan of the named tuple `ob_type`.
+#[inline(always)]
+pub(crate) fn namedtuple_plan(
+    ob_type: *mut PyTypeObject,
+) -> Result<Arc<DataclassPlan>, SerializeError> {
+    cached_plan(ob_type, PlanKind::NamedTuple, load_namedtuple_plan)
+}
+
+/// The plan of `ob_type`, using `load` if it is not cached. The cache is not
+/// borrowed after this returns because serializing a field may look up
+/// another class, and an evicted plan is dropped only after the cache is no
+/// longer borrowed because dropping it may run arbitrary code.
+#[inline(always)]
+fn cached_plan(
+    ob_type: *mut PyTypeObject,
+    kind: PlanKind,
+    load: fn(*mut PyTypeObject) -> Result<DataclassPlan, SerializeError>,
+) -> Result<Arc<DataclassPlan>, SerializeError> {
+    let key = ob_type as usize | kind as usize;
+    let cached = with_plan_map(|map| {
+        map.get(&key)
+            .filter(|plan| plan.is_plan_of(ob_type))
+            .cloned()
+    });
+    if let Some(plan) = cached {
+        return Ok(plan);
+    }
+    let mut plan = load(ob_type)?;
+    plan.version = type_version(ob_type);
+    if plan.version == 0 {
+        cold_path!();
+        return Ok(Arc::new(plan));
+    }
+    plan.class = ffi!(PyWeakref_NewRef(
+        ob_type.cast::<PyObject>(),
+        core::ptr::null_mut()
+    ));
+    let plan = Arc::new(plan);
+    if plan.class.is_null() {
+        cold_path!();
+        ffi!(PyErr_Clear());
+        return Ok(plan);
+    }
+    let evicted = with_plan_map(|map| map.insert(key, plan.clone()));
+    drop(evicted);
+    Ok(plan)
+}
+
+/// Whether a class or one of its bases defines `__orjson__`. It holds a weak
+/// reference to the class, as `DataclassPlan` does, and the version of the
+/// class, which changes if it or a base is modified, such as by adding the
+/// method after the class was cached.
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) struct CachedMethod {
+    class: *mut PyObject,
+    version: u32,
+    defined: bool,
+}
+
+#[cfg(not(Py_GIL_DISABLED))]
+impl Drop for CachedMethod {
+    fn drop(&mut self) {
+        ffi!(Py_DECREF(self.class));
+    }
+}
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) type MethodMap =
+    AssociativeCache<usize, CachedMethod, Capacity256, HashDirectMapped, RoundRobinReplacement>;
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) static mut METHOD_MAP: OnceCell<MethodMap> = OnceCell::new();
+
+/// Whether `ob_type` or a class in its MRO defines `__orjson__` in its
+/// `__dict__`. Attributes of the metaclass are not methods of instances.
+#[cold]
+#[inline(never)]
+fn defines_orjson_method(ob_type: *mut PyTypeObject) -> bool {
+    unsafe {
+        let mro = (*ob_type).tp_mro;
+        if mro.is_null() || crate::ffi::PyTuple_Check(mro) == 0 {
+            return false;
+        }
+        let mro = PyTupleRef::from_ptr_unchecked(mro);
+        (0..mro.len()).any(|idx| {
+            let base = mro.get(idx).cast::<PyTypeObject>();
+            pydict_contains!(base, ORJSON_METHOD_STR)
+        })
+    }
+}
+
+/// Whether instances of `ob_type` are serialized using `__orjson__`. This is
+/// looked up for every type not otherwise serialized, so a type that does
+/// not define it is cached as well.
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) fn has_orjson_method(ob_type: *mut PyTypeObject) -> bool {
+    let key = ob_type as usize;
+    unsafe {
+        let map = METHOD_MAP
+            .get_mut()
+            .unwrap_or_else(|| unreachable_unchecked!());
+        if let Some(cached) = map.get(&key)
+            && refers_to(cached.class, ob_type)
+            && cached.version == type_version(ob_type)
+        {
+            return cached.defined;
+        }
+    }
+    let defined = defines_orjson_method(ob_type);
+    let version = type_version(ob_type);
+    if version == 0 {
+        cold_path!();
+        return defined;
+    }
+    let class = ffi!(PyWeakref_NewRef(
+        ob_type.cast::<PyObject>(),
+        core::ptr::null_mut()
+    ));
+    if class.is_null() {
+        cold_path!();
+        ffi!(PyErr_Clear());
+        return defined;
+    }
+    let evicted = unsafe {
+        METHOD_MAP
+            .get_mut()
+            .unwrap_or_else(|| unreachable_unchecked!())
+            .insert(
+                key,
+                CachedMethod {
+                    class: class,
+                    version: version,
+                    defined: defined,
+                },
+            )
+    };
+    drop(evicted);
+    defined
+}
+
+/// The version tag of `ob_type`, which is 0 if it is not assigned.
+#[cfg(CPython)]
+#[inline(always)]
+fn type_version(ob_type: *mut PyTypeObject) -> u32 {
+    unsafe { (*ob_type).tp_version_tag }
+}
+
+#[cfg(not(CPython))]
+#[inline(always)]
+fn type_version(_ob_type: *mut PyTypeObject) -> u32 {
+    1
+}
+
+#[cfg(Py_GIL_DISABLED)]
+pub(crate) fn has_orjson_method(ob_type: *mut PyTypeObject) -> bool {
+    defines_orjson_method(ob_type)
+}
+
+/// A key of a `dict` or dataclass converted to the case given by option.
+#[derive(Clone)]
+pub(crate) enum CaseKey<'a> {
+    Unchanged(&'a str),
+    Converted(Arc<str>),
+}
+
+impl core::ops::Deref for CaseKey<'_> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        match self {
+            CaseKey::Unchanged(key) => key,
+            CaseKey::Converted(key) => key,
+        }
+    }
+}
+
+impl PartialEq for CaseKey<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for CaseKey<'_> {}
+
+impl core::hash::Hash for CaseKey<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl core::borrow::Borrow<str> for CaseKey<'_> {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+/// The keys of one object serialized with a key case option. A converted key
+/// that is equal to another key is an error rather than a duplicate member.
+pub(crate) struct CaseKeys<'a> {
+    keys: HashSet<CaseKey<'a>>,
+}
+
+impl<'a> CaseKeys<'a> {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        CaseKeys {
+            keys: HashSet::with_capacity(capacity),
+        }
+    }
+
+    /// Record `key`. Keys that are equal without either being converted,
+    /// such as `1` and `"1"` with `OPT_NON_STR_KEYS`, are not an error.
+    #[inline]
+    pub(crate) fn insert(&mut self, key: &CaseKey<'a>) -> Result<(), SerializeError> {
+        match self.keys.get::<str>(key) {
+            Some(existing) => {
+                if matches!(existing, CaseKey::Converted(_)) || matches!(key, CaseKey::Converted(_))
+                {
+                    cold_path!();
+                    return Err(SerializeError::KeyCaseCollision);
+                }
+            }
+            None => {
+                self.keys.insert(key.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A converted key and the key and case it was converted from, which are
+/// compared on lookup because entries are keyed by hash.
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) struct CachedCaseKey {
+    key: String,
+    case: Opt,
+    converted: Arc<str>,
+}
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) type CaseKeyMap =
+    AssociativeCache<u64, CachedCaseKey, Capacity2048, HashDirectMapped, RoundRobinReplacement>;
+
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) static mut CASE_KEY_MAP: OnceCell<CaseKeyMap> = OnceCell::new();
+
+/// Convert `key` to the case given in `opts`. Keys of up to 64 bytes are
+/// cached.
+#[cfg(not(Py_GIL_DISABLED))]
+#[inline]
+pub(crate) fn case_key(key: &str, opts: Opt) -> CaseKey<'_> {
+    if !changes_case(key, opts) {
+        return CaseKey::Unchanged(key);
+    }
+    if key.len() > 64 {
+        cold_path!();
+        return CaseKey::Converted(Arc::from(convert_case(key, opts)));
+    }
+    let case = opts & KEY_CASE;
+    let hash = xxhash_rust::xxh3::xxh3_64_with_seed(key.as_bytes(), u64::from(case));
+    let map = unsafe {
+        CASE_KEY_MAP
+            .get_mut()
+            .unwrap_or_else(|| unreachable_unchecked!())
+    };
+    if let Some(cached) = map.get(&hash)
+        && cached.case == case
+        && cached.key == key
+    {
+        return CaseKey::Converted(cached.converted.clone());
+    }
+    let converted: Arc<str> = Arc::from(convert_case(key, opts));
+    map.insert(
+        hash,
+        CachedCaseKey {
+            key: String::from(key),
+            case: case,
+            converted: converted.clone(),
+        },
+    );
+    CaseKey::Converted(converted)
+}
+
+#[cfg(Py_GIL_DISABLED)]
+pub(crate) fn case_key(key: &str, opts: Opt) -> CaseKey<'_> {
+    if changes_case(key, opts) {
+        CaseKey::Converted(Arc::from(convert_case(key, opts)))
+    } else {
+        CaseKey::Unchanged(key)
+    }
+}
@@ -2,17 +2,30 @@
 // Copyright ijl (2021-2026)
 
 use crate::ffi::PyStrRef;
+use crate::opt::{CBOR, MSGPACK, Opt};
 use core::ffi::CStr;
 use core::ptr::NonNull;
 
 pub(crate) enum SerializeError {
+    AttrsAttributes,
+    DataclassDefaultComparison,
+    DataclassFieldMetadata,
     DatetimeLibraryUnsupported,
+    DecimalDigits,
     DefaultRecursionLimit,
+    FloatNotFinite,
+    FragmentCanonical,
+    FragmentNotJson,
     Integer53Bits,
     Integer64Bits,
     InvalidStr,
     InvalidFragment,
+    KeyCaseCollision,
     KeyMustBeStr,
+    LengthExceeds32Bits,
+    NamedTupleFields,
+    NamedTupleLength,
+    ObjectDict,
     RecursionLimit,
     TimeHasTzinfo,
     DictIntegerKey64Bit,
@@ -22,7 +35,8 @@ pub(crate) enum SerializeError {
     NumpyNotNativeEndian,
     NumpyUnsupportedDatatype,
     NumpyUnsupportedDatetimeUnit(PyStrRef),
-    UnsupportedType(NonNull<crate::ffi::PyObject>),
+    /// The object and the options of the call, which give the format.
+    UnsupportedType(NonNull<crate::ffi::PyObject>, Opt),
 }
 
 impl core::fmt::Display for SerializeError {
@@ -30,20 +44,61 @@ impl core::fmt::Display for SerializeError {
     #[cfg_attr(feature = "optimize", optimize(size))]
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
+            SerializeError::AttrsAttributes => {
+                write!(
+                    f,
+                    "attrs class __attrs_attrs__ must be a tuple of attr.Attribute"
+                )
+            }
+            SerializeError::DataclassDefaultComparison => {
+                write!(f, "dataclass field raised comparing to its default")
+            }
+            SerializeError::DataclassFieldMetadata => write!(
+                f,
+                "dataclass field metadata \"orjson\" must be a dict whose \"name\" is a non-empty str"
+            ),
             SerializeError::DatetimeLibraryUnsupported => write!(
                 f,
                 "datetime's timezone library is not supported: use datetime.timezone.utc, pendulum, pytz, or dateutil"
             ),
+            SerializeError::DecimalDigits => {
+                write!(f, "decimal.Decimal exceeds the limit of digits of int")
+            }
             SerializeError::DefaultRecursionLimit => {
                 write!(f, "default serializer exceeds recursion limit")
             }
+            SerializeError::FloatNotFinite => {
+                write!(f, "Float must be finite with OPT_CANONICAL")
+            }
+            SerializeError::FragmentCanonical => {
+                write!(f, "orjson.Fragment cannot be serialized with OPT_CANONICAL")
+            }
+            SerializeError::FragmentNotJson => {
+                write!(f, "orjson.Fragment can only be serialized to JSON")
+            }
             SerializeError::Integer53Bits => write!(f, "Integer exceeds 53-bit range"),
             SerializeError::Integer64Bits => write!(f, "Integer exceeds 64-bit range"),
             SerializeError::InvalidStr => write!(f, "{}", crate::util::INVALID_STR),
             SerializeError::InvalidFragment => {
                 write!(f, "orjson.Fragment's content is not of type bytes or str")
             }
+            SerializeError::KeyCaseCollision => {
+                write!(f, "Key converted to case collides with another key")
+            }
             SerializeError::KeyMustBeStr => write!(f, "Dict key must be str"),
+            SerializeError::LengthExceeds32Bits => {
+                write!(
+                    f,
+                    "Length of str, bytes, list, or dict exceeds 32-bit range"
+                )
+            }
+            SerializeError::NamedTupleFields => {
+                write!(f, "named tuple _fields must be a tuple of str")
+            }
+            SerializeError::NamedTupleLength => {
+                write!(f, "named tuple must have as many items as _fields")
+            }
+            SerializeError::ObjectDict => write!(f, "object __dict__ must be a dict"),
             SerializeError::RecursionLimit => write!(f, "Recursion limit reached"),
             SerializeError::TimeHasTzinfo => write!(f, "datetime.time must not have tzinfo set"),
             SerializeError::DictIntegerKey64Bit => {
@@ -66,12 +121,19 @@ impl core::fmt::Display for SerializeError {
             SerializeError::NumpyUnsupportedDatetimeUnit(msg) => {
                 write!(f, "{}", msg.as_str().unwrap())
             }
-            SerializeError::UnsupportedType(ptr) => {
+            SerializeError::UnsupportedType(ptr, opts) => {
                 let name = unsafe {
                     CStr::from_ptr((*crate::ffi::PyObject_Type(ptr.as_ptr())).tp_name)
                         .to_string_lossy()
                 };
-                write!(f, "Type is not JSON serializable: {name}")
+                let format = if opt_enabled!(opts, CBOR) {
+                    "CBOR"
+                } else if opt_enabled!(opts, MSGPACK) {
+                    "MessagePack"
+                } else {
+                    "JSON"
+                };
+                write!(f, "Type is not {format} serializable: {name}")
             }
         }
     }
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2021-2026)
 
+mod cache;
 pub(crate) mod datetime;
 mod error;
 mod numpy;
@@ -11,5 +12,10 @@ mod state;
 mod uuid;
 pub(crate) mod writer;
 
-pub(crate) use serializer::serialize;
+#[cfg(not(Py_GIL_DISABLED))]
+pub(crate) use cache::{
+    CASE_KEY_MAP, CaseKeyMap, DATACLASS_PLAN_MAP, DataclassPlanMap, METHOD_MAP, MethodMap,
+};
+pub(crate) use obtype::{ObType, pyobject_to_obtype};
+pub(crate) use serializer::{serialize, serialize_cbor, serialize_msgpack};
 pub(crate) use writer::set_str_formatter_fn;
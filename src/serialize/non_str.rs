@@ -123,12 +123,18 @@ pub(crate) fn pyobject_to_string(
             ObType::Str => non_str_str(PyStrRef::from_ptr_unchecked(key)),
             ObType::StrSubclass => non_str_str_subclass(PyStrSubclassRef::from_ptr_unchecked(key)),
             ObType::Tuple
+            | ObType::NamedTuple
             | ObType::NumpyScalar
             | ObType::NumpyArray
             | ObType::Dict
             | ObType::List
             | ObType::Dataclass
+            | ObType::Attrs
             | ObType::Fragment
+            | ObType::Bytes
+            | ObType::Decimal
+            | ObType::Protocol
+            | ObType::Object
             | ObType::Unknown => Err(SerializeError::DictKeyInvalidType),
         }
     }
@@ -172,6 +172,19 @@ impl NumpyArray {
     pub fn strides(&self) -> &[isize] {
         slice!((*self.array).strides.cast_const(), self.dimensions())
     }
+
+    /// The items of the whole array, which is C contiguous.
+    pub fn as_bytes(&self) -> &[u8] {
+        debug_assert!(self.depth == 0);
+        let itemsize = unsafe { isize_to_usize((*self.array).itemsize as isize) };
+        let len = self
+            .shape()
+            .iter()
+            .map(|val| isize_to_usize(*val))
+            .product::<usize>()
+            * itemsize;
+        slice!(unsafe { (*self.array).data.cast::<u8>().cast_const() }, len)
+    }
 }
 
 impl Drop for NumpyArray {
@@ -3,12 +3,17 @@
 
 use crate::ffi::PyType_GetFlags;
 use crate::opt::{
-    Opt, PASSTHROUGH_DATACLASS, PASSTHROUGH_DATETIME, PASSTHROUGH_SUBCLASS, SERIALIZE_NUMPY,
+    CBOR, Opt, PASSTHROUGH_DATACLASS, PASSTHROUGH_DATETIME, PASSTHROUGH_SUBCLASS,
+    SERIALIZE_NAMEDTUPLE, SERIALIZE_NUMPY, SERIALIZE_OBJECT,
+};
+use crate::serialize::cache::has_orjson_method;
+use crate::serialize::per_type::{
+    is_attrs, is_decimal, is_namedtuple, is_numpy_array, is_numpy_scalar, is_plain_object,
 };
-use crate::serialize::per_type::{is_numpy_array, is_numpy_scalar};
 use crate::typeref::{
-    BOOL_TYPE, DATACLASS_FIELDS_STR, DATE_TYPE, DATETIME_TYPE, DICT_TYPE, ENUM_TYPE, FLOAT_TYPE,
-    FRAGMENT_TYPE, INT_TYPE, LIST_TYPE, NONE_TYPE, STR_TYPE, TIME_TYPE, TUPLE_TYPE, UUID_TYPE,
+    ATTRS_ATTRS_STR, BOOL_TYPE, BYTES_TYPE, DATACLASS_FIELDS_STR, DATE_TYPE, DATETIME_TYPE,
+    DICT_TYPE, ENUM_TYPE, FLOAT_TYPE, FRAGMENT_TYPE, INT_TYPE, LIST_TYPE, NONE_TYPE, STR_TYPE,
+    TIME_TYPE, TUPLE_TYPE, UUID_TYPE,
 };
 
 #[repr(u32)]
@@ -24,13 +29,19 @@ pub(crate) enum ObType {
     Date,
     Time,
     Tuple,
+    NamedTuple,
     Uuid,
     Dataclass,
+    Attrs,
     NumpyScalar,
     NumpyArray,
     Enum,
     StrSubclass,
     Fragment,
+    Bytes,
+    Decimal,
+    Protocol,
+    Object,
     Unknown,
 }
 
@@ -70,6 +81,11 @@ pub(crate) fn pyobject_to_obtype_unlikely(
         return ObType::Tuple;
     } else if is_class_by_type!(ob_type, FRAGMENT_TYPE) {
         return ObType::Fragment;
+    } else if is_class_by_type!(ob_type, BYTES_TYPE)
+        || unsafe { core::ptr::eq(ob_type, &raw mut crate::ffi::PyByteArray_Type) }
+        || unsafe { core::ptr::eq(ob_type, &raw mut crate::ffi::PyMemoryView_Type) }
+    {
+        return ObType::Bytes;
     }
 
     if opt_disabled!(opts, PASSTHROUGH_DATETIME) {
@@ -94,13 +110,28 @@ pub(crate) fn pyobject_to_obtype_unlikely(
         }
     }
 
+    if opt_enabled!(opts, SERIALIZE_NAMEDTUPLE)
+        && is_subclass_by_flag!(tp_flags, Py_TPFLAGS_TUPLE_SUBCLASS)
+        && is_namedtuple(ob_type)
+    {
+        return ObType::NamedTuple;
+    }
+
     if is_subclass_by_type!(ob_type, ENUM_TYPE) {
         return ObType::Enum;
     }
 
-    if opt_disabled!(opts, PASSTHROUGH_DATACLASS) && pydict_contains!(ob_type, DATACLASS_FIELDS_STR)
-    {
-        return ObType::Dataclass;
+    if opt_disabled!(opts, PASSTHROUGH_DATACLASS) {
+        if pydict_contains!(ob_type, DATACLASS_FIELDS_STR) {
+            return ObType::Dataclass;
+        } else if pydict_contains!(ob_type, ATTRS_ATTRS_STR) && is_attrs(ob_type) {
+            return ObType::Attrs;
+        }
+    }
+
+    if has_orjson_method(ob_type) {
+        cold_path!();
+        return ObType::Protocol;
     }
 
     if opt_enabled!(opts, SERIALIZE_NUMPY) {
@@ -112,5 +143,15 @@ pub(crate) fn pyobject_to_obtype_unlikely(
         }
     }
 
+    if opt_enabled!(opts, CBOR) && is_decimal(ob_type) {
+        cold_path!();
+        return ObType::Decimal;
+    }
+
+    if opt_enabled!(opts, SERIALIZE_OBJECT) && is_plain_object(ob_type) {
+        cold_path!();
+        return ObType::Object;
+    }
+
     ObType::Unknown
 }
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyTypeObject;
+use crate::serialize::cache::attrs_plan;
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::dataclass::serialize_with_plan;
+use crate::serialize::serializer::PyObjectSerializer;
+
+use serde::ser::{Serialize, Serializer};
+
+/// Whether `ob_type`, which has `__attrs_attrs__` in its `__dict__`, is a
+/// class defined using attrs. This is decided when its plan is loaded, and
+/// a class that is not is cached as such.
+#[inline]
+pub(crate) fn is_attrs(ob_type: *mut PyTypeObject) -> bool {
+    // A class whose `__attrs_attrs__` is malformed is serialized as attrs so
+    // that the error is raised.
+    attrs_plan(ob_type).map_or(true, |plan| !plan.rejected)
+}
+
+/// Serialize an instance of a class defined using attrs, which has
+/// `__attrs_attrs__`, as the dataclass serializers do.
+#[repr(transparent)]
+pub(crate) struct AttrsSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> AttrsSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for AttrsSerializer<'_> {
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.recursion_limit() {
+            err!(SerializeError::RecursionLimit)
+        }
+        let ob_type = unsafe { crate::ffi::PyObject_Type(self.previous.ptr) };
+        let plan = match attrs_plan(ob_type) {
+            Ok(plan) => plan,
+            Err(err) => err!(err),
+        };
+        serialize_with_plan(self.previous, &plan, serializer)
+    }
+}
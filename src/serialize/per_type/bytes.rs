@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyBytesRef;
+#[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+use crate::ffi::{PyByteArrayRef, PyMemoryViewRef};
+use crate::serialize::per_type::DefaultSerializer;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::BYTES_TYPE;
+
+use serde::ser::{Serialize, Serializer};
+
+/// `bytes` is binary data in formats that are not human-readable and
+/// otherwise is passed to `default`. So are `bytearray` and a C-contiguous
+/// `memoryview` if the GIL is enabled.
+#[repr(transparent)]
+pub(crate) struct BytesSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> BytesSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for BytesSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            return DefaultSerializer::new(self.previous).serialize(serializer);
+        }
+        let ptr = self.previous.ptr;
+        if is_class_by_type!(ffi!(Py_TYPE(ptr)), BYTES_TYPE) {
+            let ob = unsafe { PyBytesRef::from_ptr_unchecked(ptr) };
+            return serializer.serialize_bytes(ob.as_bytes());
+        }
+        // These are mutable, so they are not read while other threads run.
+        #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+        if let Ok(ob) = PyByteArrayRef::from_ptr(ptr) {
+            return serializer.serialize_bytes(ob.as_bytes());
+        }
+        #[cfg(all(CPython, not(Py_GIL_DISABLED)))]
+        if let Ok(ob) = PyMemoryViewRef::from_ptr(ptr) {
+            return serializer.serialize_bytes(ob.as_bytes());
+        }
+        DefaultSerializer::new(self.previous).serialize(serializer)
+    }
+}
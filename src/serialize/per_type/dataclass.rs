@@ -2,13 +2,15 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::PyStrRef;
+use crate::opt::{CANONICAL, KEY_CASE, OMIT_DEFAULTS, OMIT_NONE, Opt, SERIALIZE_UNDERSCORE};
+use crate::serialize::cache::{
+    CaseKey, CaseKeys, DataclassField, DataclassPlan, case_key, dataclass_plan,
+};
 use crate::serialize::error::SerializeError;
-use crate::serialize::per_type::dict::ZeroDictSerializer;
+use crate::serialize::per_type::dict::{ZeroDictSerializer, sort_dict_items_canonical};
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::state::SerializerState;
-use crate::typeref::{
-    DATACLASS_FIELDS_STR, DICT_STR, FIELD_TYPE, FIELD_TYPE_STR, SLOTS_STR, STR_TYPE,
-};
+use crate::typeref::{DICT_STR, NONE, STR_TYPE};
 use crate::util::isize_to_usize;
 
 use serde::ser::{Serialize, SerializeMap, Serializer};
@@ -35,57 +37,111 @@ impl Serialize for DataclassGenericSerializer<'_> {
         if self.previous.state.recursion_limit() {
             err!(SerializeError::RecursionLimit)
         }
-        let dict = ffi!(PyObject_GetAttr(self.previous.ptr, DICT_STR));
         let ob_type = unsafe { crate::ffi::PyObject_Type(self.previous.ptr) };
-        if dict.is_null() {
-            cold_path!();
-            ffi!(PyErr_Clear());
-            DataclassFallbackSerializer::new(
-                self.previous.ptr,
-                self.previous.state,
-                self.previous.default,
-            )
+        let plan = match dataclass_plan(ob_type) {
+            Ok(plan) => plan,
+            Err(err) => err!(err),
+        };
+        serialize_with_plan(self.previous, &plan, serializer)
+    }
+}
+
+/// Serialize an instance of a dataclass or attrs class using the plan of
+/// its class, from its `__dict__` if possible.
+#[inline(always)]
+pub(crate) fn serialize_with_plan<S>(
+    previous: &PyObjectSerializer,
+    plan: &DataclassPlan,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if plan.slots {
+        return DataclassPlanSerializer::new(previous.ptr, plan, previous.state, previous.default)
+            .serialize(serializer);
+    }
+    let dict = ffi!(PyObject_GetAttr(previous.ptr, DICT_STR));
+    if dict.is_null() {
+        cold_path!();
+        ffi!(PyErr_Clear());
+        DataclassPlanSerializer::new(previous.ptr, plan, previous.state, previous.default)
             .serialize(serializer)
-        } else if pydict_contains!(ob_type, SLOTS_STR) {
-            let ret = DataclassFallbackSerializer::new(
-                self.previous.ptr,
-                self.previous.state,
-                self.previous.default,
-            )
+    } else {
+        let ret = DataclassFastSerializer::new(dict, plan, previous.state, previous.default)
             .serialize(serializer);
-            ffi!(Py_DECREF(dict));
-            ret
+        ffi!(Py_DECREF(dict));
+        ret
+    }
+}
+
+/// A field's key, which is written already escaped if serializing to JSON.
+#[repr(transparent)]
+pub(crate) struct DataclassKey<'a> {
+    pub field: &'a DataclassField,
+}
+
+impl Serialize for DataclassKey<'_> {
+    #[inline(always)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_bytes(&self.field.escaped)
         } else {
-            let ret =
-                DataclassFastSerializer::new(dict, self.previous.state, self.previous.default)
-                    .serialize(serializer);
-            ffi!(Py_DECREF(dict));
-            ret
+            serializer.serialize_str(&self.field.key)
         }
     }
 }
 
-pub(crate) struct DataclassFastSerializer {
+/// The key of `field`, or of an attribute that is not a field, converted to
+/// the case given by option. A key renamed using field metadata is not
+/// converted.
+#[inline(always)]
+pub(crate) fn dataclass_case_key<'a>(
+    key: &'a str,
+    field: Option<&DataclassField>,
+    opts: Opt,
+) -> CaseKey<'a> {
+    if opt_enabled!(opts, KEY_CASE) && !field.is_some_and(|field| field.renamed) {
+        cold_path!();
+        case_key(key, opts)
+    } else {
+        CaseKey::Unchanged(key)
+    }
+}
+
+/// Serialize the instance's `__dict__`. Entries for fields are expected in
+/// the order of the plan, as assigned by `__init__`, and use its
+/// pre-escaped keys, which are renamed using field metadata. A field
+/// assigned out of order, such as in `__post_init__`, is looked up in the
+/// plan, and a field skipped using field metadata is not serialized. Other
+/// entries are handled as they are found.
+pub(crate) struct DataclassFastSerializer<'a> {
     ptr: *mut crate::ffi::PyObject,
+    plan: &'a DataclassPlan,
     state: SerializerState,
     default: Option<NonNull<crate::ffi::PyObject>>,
 }
 
-impl DataclassFastSerializer {
+impl<'a> DataclassFastSerializer<'a> {
     pub fn new(
         ptr: *mut crate::ffi::PyObject,
+        plan: &'a DataclassPlan,
         state: SerializerState,
         default: Option<NonNull<crate::ffi::PyObject>>,
     ) -> Self {
         DataclassFastSerializer {
             ptr: ptr,
+            plan: plan,
             state: state.copy_for_recursive_call(),
             default: default,
         }
     }
 }
 
-impl Serialize for DataclassFastSerializer {
+impl Serialize for DataclassFastSerializer<'_> {
     #[inline(never)]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -97,6 +153,12 @@ impl Serialize for DataclassFastSerializer {
             return ZeroDictSerializer::new().serialize(serializer);
         }
         let mut map = serializer.serialize_map(None).unwrap();
+        let mut sorted: Option<Vec<(CaseKey<'_>, *mut crate::ffi::PyObject)>> =
+            opt_enabled!(self.state.opts(), CANONICAL).then(Vec::new);
+        let mut case_keys: Option<CaseKeys<'_>> =
+            opt_enabled!(self.state.opts(), KEY_CASE).then(|| CaseKeys::with_capacity(len));
+
+        let mut fields = self.plan.fields.iter().peekable();
 
         let mut pos = 0;
         let mut next_key: *mut crate::ffi::PyObject = core::ptr::null_mut();
@@ -124,111 +186,171 @@ impl Serialize for DataclassFastSerializer {
                 );
             }
 
-            let key_as_str = {
-                let key_ob_type = unsafe { crate::ffi::PyObject_Type(key) };
-                if !is_class_by_type!(key_ob_type, STR_TYPE) {
-                    cold_path!();
-                    err!(SerializeError::KeyMustBeStr)
+            let mut field = fields.next_if(|field| core::ptr::eq(field.name, key));
+            if field.is_none() {
+                cold_path!();
+                if self.plan.is_skipped(key) {
+                    continue;
                 }
-                match unsafe { PyStrRef::from_ptr_unchecked(key).as_str() } {
-                    Some(uni) => uni,
-                    None => err!(SerializeError::InvalidStr),
+                field = self.plan.field(key);
+            }
+            let key_as_str = match field {
+                Some(field) => {
+                    if field.private && opt_disabled!(self.state.opts(), SERIALIZE_UNDERSCORE) {
+                        cold_path!();
+                        continue;
+                    }
+                    field.key.as_str()
+                }
+                None => {
+                    cold_path!();
+                    let key_ob_type = unsafe { crate::ffi::PyObject_Type(key) };
+                    if !is_class_by_type!(key_ob_type, STR_TYPE) {
+                        err!(SerializeError::KeyMustBeStr)
+                    }
+                    let uni = match unsafe { PyStrRef::from_ptr_unchecked(key).as_str() } {
+                        Some(uni) => uni,
+                        None => err!(SerializeError::InvalidStr),
+                    };
+                    if uni.as_bytes()[0] == b'_'
+                        && opt_disabled!(self.state.opts(), SERIALIZE_UNDERSCORE)
+                    {
+                        continue;
+                    }
+                    uni
                 }
             };
-            if key_as_str.as_bytes()[0] == b'_' {
+            if unsafe { core::ptr::eq(value, NONE) } && opt_enabled!(self.state.opts(), OMIT_NONE) {
+                continue;
+            }
+            if let Some(field) = field
+                && opt_enabled!(self.state.opts(), OMIT_DEFAULTS)
+            {
+                match field.default.is_default(value) {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(err) => err!(err),
+                }
+            }
+            let key_as_str = dataclass_case_key(key_as_str, field, self.state.opts());
+            if let Some(case_keys) = case_keys.as_mut()
+                && let Err(err) = case_keys.insert(&key_as_str)
+            {
+                err!(err)
+            }
+            if let Some(items) = sorted.as_mut() {
                 cold_path!();
+                items.push((key_as_str, value));
                 continue;
             }
             let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
-            map.serialize_key(key_as_str).unwrap();
+            match field {
+                Some(field) if matches!(key_as_str, CaseKey::Unchanged(_)) => {
+                    map.serialize_key(&DataclassKey { field: field }).unwrap();
+                }
+                _ => map.serialize_key(&*key_as_str).unwrap(),
+            }
             map.serialize_value(&pyvalue)?;
         }
+        if let Some(mut items) = sorted {
+            sort_dict_items_canonical(&mut items);
+            for (key, value) in items {
+                let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
+                map.serialize_key(&*key).unwrap();
+                map.serialize_value(&pyvalue)?;
+            }
+        }
         map.end()
     }
 }
 
-pub(crate) struct DataclassFallbackSerializer {
+/// Serialize the fields of a dataclass using its cached plan, for classes
+/// with `__slots__`. A field that is not set, such as one with
+/// `init=False` and no default, is not serialized.
+pub(crate) struct DataclassPlanSerializer<'a> {
     ptr: *mut crate::ffi::PyObject,
+    plan: &'a DataclassPlan,
     state: SerializerState,
     default: Option<NonNull<crate::ffi::PyObject>>,
 }
 
-impl DataclassFallbackSerializer {
+impl<'a> DataclassPlanSerializer<'a> {
     pub fn new(
         ptr: *mut crate::ffi::PyObject,
+        plan: &'a DataclassPlan,
         state: SerializerState,
         default: Option<NonNull<crate::ffi::PyObject>>,
     ) -> Self {
-        DataclassFallbackSerializer {
+        DataclassPlanSerializer {
             ptr: ptr,
+            plan: plan,
             state: state.copy_for_recursive_call(),
             default: default,
         }
     }
 }
 
-impl Serialize for DataclassFallbackSerializer {
-    #[cold]
+impl Serialize for DataclassPlanSerializer<'_> {
     #[inline(never)]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let fields = ffi!(PyObject_GetAttr(self.ptr, DATACLASS_FIELDS_STR));
-        debug_assert!(ffi!(Py_REFCNT(fields)) >= 2);
-        ffi!(Py_DECREF(fields));
-        let len = isize_to_usize(ffi!(Py_SIZE(fields)));
-        if len == 0 {
-            cold_path!();
-            return ZeroDictSerializer::new().serialize(serializer);
-        }
         let mut map = serializer.serialize_map(None).unwrap();
+        let mut sorted: Option<Vec<(CaseKey<'_>, *mut crate::ffi::PyObject)>> =
+            opt_enabled!(self.state.opts(), CANONICAL).then(Vec::new);
+        let mut case_keys: Option<CaseKeys<'_>> = opt_enabled!(self.state.opts(), KEY_CASE)
+            .then(|| CaseKeys::with_capacity(self.plan.fields.len()));
 
-        let mut pos = 0;
-        let mut next_key: *mut crate::ffi::PyObject = core::ptr::null_mut();
-        let mut next_value: *mut crate::ffi::PyObject = core::ptr::null_mut();
-
-        unsafe {
-            crate::ffi::PyDict_Next(fields, &raw mut pos, &raw mut next_key, &raw mut next_value);
-        }
-
-        for _ in 0..len {
-            let attr = next_key;
-            let field = next_value;
-            unsafe {
-                crate::ffi::PyDict_Next(
-                    fields,
-                    &raw mut pos,
-                    &raw mut next_key,
-                    &raw mut next_value,
-                );
-            }
-
-            let field_type = ffi!(PyObject_GetAttr(field, FIELD_TYPE_STR));
-            debug_assert!(ffi!(Py_REFCNT(field_type)) >= 2);
-            ffi!(Py_DECREF(field_type));
-            if unsafe { !core::ptr::eq(field_type.cast::<crate::ffi::PyTypeObject>(), FIELD_TYPE) }
-            {
+        for field in self.plan.fields.iter() {
+            if field.private && opt_disabled!(self.state.opts(), SERIALIZE_UNDERSCORE) {
                 continue;
             }
-
-            let key_as_str = match unsafe { PyStrRef::from_ptr_unchecked(attr).as_str() } {
-                Some(uni) => uni,
-                None => err!(SerializeError::InvalidStr),
-            };
-            if key_as_str.as_bytes()[0] == b'_' {
+            let value = ffi!(PyObject_GetAttr(self.ptr, field.name));
+            if value.is_null() {
                 cold_path!();
+                ffi!(PyErr_Clear());
                 continue;
             }
-
-            let value = ffi!(PyObject_GetAttr(self.ptr, attr));
             debug_assert!(ffi!(Py_REFCNT(value)) >= 2);
             ffi!(Py_DECREF(value));
+            if unsafe { core::ptr::eq(value, NONE) } && opt_enabled!(self.state.opts(), OMIT_NONE) {
+                continue;
+            }
+            if opt_enabled!(self.state.opts(), OMIT_DEFAULTS) {
+                match field.default.is_default(value) {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(err) => err!(err),
+                }
+            }
+            let key_as_str = dataclass_case_key(&field.key, Some(field), self.state.opts());
+            if let Some(case_keys) = case_keys.as_mut()
+                && let Err(err) = case_keys.insert(&key_as_str)
+            {
+                err!(err)
+            }
+            if let Some(items) = sorted.as_mut() {
+                cold_path!();
+                items.push((key_as_str, value));
+                continue;
+            }
             let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
-
-            map.serialize_key(key_as_str).unwrap();
+            if matches!(key_as_str, CaseKey::Unchanged(_)) {
+                map.serialize_key(&DataclassKey { field: field }).unwrap();
+            } else {
+                map.serialize_key(&*key_as_str).unwrap();
+            }
             map.serialize_value(&pyvalue)?;
         }
+        if let Some(mut items) = sorted {
+            sort_dict_items_canonical(&mut items);
+            for (key, value) in items {
+                let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
+                map.serialize_key(&*key).unwrap();
+                map.serialize_value(&pyvalue)?;
+            }
+        }
         map.end()
     }
 }
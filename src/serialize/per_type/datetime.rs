@@ -2,10 +2,10 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::{PyDateRef, PyDateTimeRef, PyTimeRef};
-use crate::opt::Opt;
+use crate::opt::{CBOR, NAIVE_UTC, Opt};
 use crate::serialize::datetime::{write_date, write_datetime, write_time};
 use crate::serialize::error::SerializeError;
-use crate::serialize::writer::SmallFixedBuffer;
+use crate::serialize::writer::{SmallFixedBuffer, TAG_DATETIME, serialize_tagged};
 use serde::ser::{Serialize, Serializer};
 
 #[repr(transparent)]
@@ -73,6 +73,13 @@ impl Serialize for DateTime {
         if write_datetime(self.ob.clone(), self.opts, &mut buf).is_err() {
             err!(SerializeError::DatetimeLibraryUnsupported)
         }
-        serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
+        let value = str_from_slice!(buf.as_ptr(), buf.len());
+        if opt_enabled!(self.opts, CBOR) && (self.ob.has_tz() || opt_enabled!(self.opts, NAIVE_UTC))
+        {
+            // a standard date/time string requires an offset
+            serialize_tagged(serializer, TAG_DATETIME, value)
+        } else {
+            serializer.serialize_unit_struct(value)
+        }
     }
 }
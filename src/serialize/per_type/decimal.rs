@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::{
+    Py_DECREF, Py_SIZE, PyFloat_AS_DOUBLE, PyIntRef, PyLong_AsLong, PyLong_AsLongLong,
+    PyLong_FromString, PyNumber_Float, PyNumber_Negative, PyObject, PyObject_CallMethodNoArgs,
+    PyObject_Type, PyTuple_GET_ITEM,
+};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::IntSerializer;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::serialize::writer::{TAG_DECIMAL_FRACTION, serialize_tagged};
+use crate::typeref::{AS_TUPLE_STR, DECIMAL_TYPE, INT_TYPE, load_decimal_type};
+use crate::util::{isize_to_usize, usize_to_isize};
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+pub(crate) fn is_decimal(ob_type: *mut crate::ffi::PyTypeObject) -> bool {
+    let decimal_type = unsafe { DECIMAL_TYPE.get_or_init(load_decimal_type) };
+    core::ptr::eq(ob_type, decimal_type.as_ptr())
+}
+
+/// `decimal.Decimal` is a decimal fraction, tag 4, of CBOR, which is the
+/// only format that detects it. It is not finite, or is negative zero, as a
+/// float.
+#[repr(transparent)]
+pub(crate) struct DecimalSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> DecimalSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+struct DecimalFraction<'a> {
+    exponent: i64,
+    mantissa: *mut PyObject,
+    previous: &'a PyObjectSerializer,
+}
+
+impl Serialize for DecimalFraction<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(2))?;
+        seq.serialize_element(&self.exponent)?;
+        seq.serialize_element(&IntSerializer::new(
+            unsafe { PyIntRef::from_ptr_unchecked(self.mantissa) },
+            self.previous.state.opts(),
+        ))?;
+        seq.end()
+    }
+}
+
+impl Serialize for DecimalSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        unsafe {
+            // DecimalTuple(sign, digits, exponent)
+            let tuple = PyObject_CallMethodNoArgs(self.previous.ptr, AS_TUPLE_STR);
+            debug_assert!(!tuple.is_null());
+            let exponent = PyTuple_GET_ITEM(tuple, 2);
+            if !is_class_by_type!(PyObject_Type(exponent), INT_TYPE) {
+                // "n", "N", or "F"
+                Py_DECREF(tuple);
+                let float = PyNumber_Float(self.previous.ptr);
+                let value = PyFloat_AS_DOUBLE(float);
+                Py_DECREF(float);
+                return serializer.serialize_f64(value);
+            }
+            let exponent = PyLong_AsLongLong(exponent);
+            let negative = PyLong_AsLong(PyTuple_GET_ITEM(tuple, 0)) == 1;
+            let digits = PyTuple_GET_ITEM(tuple, 1);
+            let len = isize_to_usize(Py_SIZE(digits));
+            let mut buf: Vec<u8> = Vec::with_capacity(len + 1);
+            for idx in 0..len {
+                let digit = PyLong_AsLong(PyTuple_GET_ITEM(digits, usize_to_isize(idx)));
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                buf.push(b'0' + digit as u8);
+            }
+            Py_DECREF(tuple);
+            if negative && buf.iter().all(|digit| *digit == b'0') {
+                // the mantissa of -0 is 0 and has no sign
+                return serializer.serialize_f64(-0.0);
+            }
+            buf.push(0);
+            let mut mantissa = PyLong_FromString(
+                buf.as_ptr().cast::<core::ffi::c_char>(),
+                core::ptr::null_mut(),
+                10,
+            );
+            if mantissa.is_null() {
+                // sys.get_int_max_str_digits()
+                cold_path!();
+                crate::ffi::PyErr_Clear();
+                err!(SerializeError::DecimalDigits)
+            }
+            if negative {
+                let negated = PyNumber_Negative(mantissa);
+                Py_DECREF(mantissa);
+                mantissa = negated;
+            }
+            let res = serialize_tagged(
+                serializer,
+                TAG_DECIMAL_FRACTION,
+                &DecimalFraction {
+                    exponent: exponent,
+                    mantissa: mantissa,
+                    previous: self.previous,
+                },
+            );
+            Py_DECREF(mantissa);
+            res
+        }
+    }
+}
@@ -40,7 +40,10 @@ impl Serialize for DefaultSerializer<'_> {
                     )
                 };
                 if default_obj.is_null() {
-                    err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr)))
+                    err!(SerializeError::UnsupportedType(
+                        nonnull!(self.previous.ptr),
+                        self.previous.state.opts(),
+                    ))
                 } else {
                     let res = PyObjectSerializer::new(
                         default_obj,
@@ -52,7 +55,10 @@ impl Serialize for DefaultSerializer<'_> {
                     res
                 }
             }
-            None => err!(SerializeError::UnsupportedType(nonnull!(self.previous.ptr))),
+            None => err!(SerializeError::UnsupportedType(
+                nonnull!(self.previous.ptr),
+                self.previous.state.opts(),
+            )),
         }
     }
 }
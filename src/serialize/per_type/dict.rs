@@ -5,23 +5,31 @@ use crate::ffi::{
     PyBoolRef, PyDateRef, PyDateTimeRef, PyDictRef, PyFloatRef, PyFragmentRef, PyIntRef, PyListRef,
     PyStrRef, PyStrSubclassRef, PyTimeRef, PyUuidRef,
 };
-use crate::opt::{NON_STR_KEYS, NOT_PASSTHROUGH, SORT_KEYS, SORT_OR_NON_STR_KEYS};
+use crate::opt::{
+    CANONICAL, KEY_CASE, NON_STR_KEYS, NOT_PASSTHROUGH, OMIT_NONE, SORT_KEYS, SORT_OR_NON_STR_KEYS,
+};
+use crate::serialize::cache::{CaseKey, CaseKeys, case_key};
 use crate::serialize::datetime::{write_date, write_datetime, write_time};
 use crate::serialize::error::SerializeError;
 use crate::serialize::numpy::NumpyScalar;
 use crate::serialize::obtype::{ObType, pyobject_to_obtype};
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer, EnumSerializer,
-    FloatSerializer, FragmentSerializer, IntSerializer, ListTupleSerializer, NoneSerializer,
-    NumpySerializer, StrSerializer, StrSubclassSerializer, Time, UUID, ZeroListSerializer,
+    AttrsSerializer, BoolSerializer, BytesSerializer, DataclassGenericSerializer, Date, DateTime,
+    DecimalSerializer, DefaultSerializer, EnumSerializer, FloatSerializer, FragmentSerializer,
+    IntSerializer, ListTupleSerializer, NamedTupleSerializer, NoneSerializer, NumpySerializer,
+    ObjectSerializer, ProtocolSerializer, StrSerializer, StrSubclassSerializer, Time, UUID,
+    ZeroListSerializer,
 };
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::state::SerializerState;
 use crate::serialize::uuid::write_uuid;
-use crate::serialize::writer::{SmallFixedBuffer, write_integer_i64, write_integer_u64};
-use crate::typeref::{STR_TYPE, TRUE, VALUE_STR};
+use crate::serialize::writer::{
+    SmallFixedBuffer, write_float64_canonical, write_integer_i64, write_integer_u64,
+};
+use crate::typeref::{NONE, STR_TYPE, TRUE, VALUE_STR};
 use core::ptr::NonNull;
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::sync::Arc;
 
 pub(crate) struct ZeroDictSerializer;
 
@@ -37,7 +45,11 @@ impl Serialize for ZeroDictSerializer {
     where
         S: Serializer,
     {
-        serializer.serialize_bytes(b"{}")
+        if serializer.is_human_readable() {
+            serializer.serialize_bytes(b"{}")
+        } else {
+            serializer.serialize_map(Some(0))?.end()
+        }
     }
 }
 
@@ -76,12 +88,17 @@ impl Serialize for DictGenericSerializer {
         if self.dict.len() == 0 {
             cold_path!();
             ZeroDictSerializer::new().serialize(serializer)
-        } else if opt_disabled!(self.state.opts(), SORT_OR_NON_STR_KEYS) {
+        } else if opt_disabled!(self.state.opts(), SORT_OR_NON_STR_KEYS | KEY_CASE) {
             unsafe {
                 (*(core::ptr::from_ref::<DictGenericSerializer>(self)).cast::<Dict>())
                     .serialize(serializer)
             }
-        } else if opt_enabled!(self.state.opts(), NON_STR_KEYS) {
+        } else if opt_disabled!(self.state.opts(), SORT_OR_NON_STR_KEYS) {
+            unsafe {
+                (*(core::ptr::from_ref::<DictGenericSerializer>(self)).cast::<DictCaseKey>())
+                    .serialize(serializer)
+            }
+        } else if opt_enabled!(self.state.opts(), NON_STR_KEYS | KEY_CASE) {
             unsafe {
                 (*(core::ptr::from_ref::<DictGenericSerializer>(self)).cast::<DictNonStrKey>())
                     .serialize(serializer)
@@ -118,8 +135,10 @@ macro_rules! impl_serialize_entry {
                 ))?;
             }
             ObType::None => {
-                $map.serialize_key($key).unwrap();
-                $map.serialize_value(&NoneSerializer::new()).unwrap();
+                if opt_disabled!($self.state.opts(), OMIT_NONE) {
+                    $map.serialize_key($key).unwrap();
+                    $map.serialize_value(&NoneSerializer::new()).unwrap();
+                }
             }
             ObType::Float => {
                 $map.serialize_key($key).unwrap();
@@ -154,8 +173,11 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Uuid => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&UUID::new(unsafe { PyUuidRef::from_ptr_unchecked($value) }))
-                    .unwrap();
+                $map.serialize_value(&UUID::new(
+                    unsafe { PyUuidRef::from_ptr_unchecked($value) },
+                    $self.state.opts(),
+                ))
+                .unwrap();
             }
             ObType::Dict => {
                 let pyvalue = DictGenericSerializer::new(
@@ -191,6 +213,14 @@ macro_rules! impl_serialize_entry {
                     $map.serialize_value(&pyvalue)?;
                 }
             }
+            ObType::NamedTuple => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&NamedTupleSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))?;
+            }
             ObType::Dataclass => {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&DataclassGenericSerializer::new(&PyObjectSerializer::new(
@@ -199,6 +229,14 @@ macro_rules! impl_serialize_entry {
                     $self.default,
                 )))?;
             }
+            ObType::Attrs => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&AttrsSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))?;
+            }
             ObType::Enum => {
                 $map.serialize_key($key).unwrap();
                 $map.serialize_value(&EnumSerializer::new(&PyObjectSerializer::new(
@@ -221,9 +259,42 @@ macro_rules! impl_serialize_entry {
             }
             ObType::Fragment => {
                 $map.serialize_key($key).unwrap();
-                $map.serialize_value(&FragmentSerializer::new(unsafe {
-                    PyFragmentRef::from_ptr_unchecked($value)
-                }))?;
+                $map.serialize_value(&FragmentSerializer::new(
+                    unsafe { PyFragmentRef::from_ptr_unchecked($value) },
+                    $self.state.opts(),
+                ))?;
+            }
+            ObType::Bytes => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&BytesSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))?;
+            }
+            ObType::Decimal => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&DecimalSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))?;
+            }
+            ObType::Protocol => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&ProtocolSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))?;
+            }
+            ObType::Object => {
+                $map.serialize_key($key).unwrap();
+                $map.serialize_value(&ObjectSerializer::new(&PyObjectSerializer::new(
+                    $value,
+                    $self.state,
+                    $self.default,
+                )))?;
             }
             ObType::Unknown => {
                 $map.serialize_key($key).unwrap();
@@ -296,6 +367,73 @@ impl Serialize for Dict {
     }
 }
 
+pub(crate) struct DictCaseKey {
+    dict: PyDictRef,
+    state: SerializerState,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+}
+
+impl Serialize for DictCaseKey {
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut pos = 0;
+        let mut next_key: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        let mut next_value: *mut crate::ffi::PyObject = core::ptr::null_mut();
+        unsafe {
+            crate::ffi::PyDict_Next(
+                self.dict.as_ptr(),
+                &raw mut pos,
+                &raw mut next_key,
+                &raw mut next_value,
+            );
+        }
+
+        let mut map = serializer.serialize_map(None).unwrap();
+
+        let len = self.dict.len();
+        assume!(len > 0);
+
+        let mut case_keys = CaseKeys::with_capacity(len);
+
+        for _ in 0..len {
+            let key = next_key;
+            let value = next_value;
+
+            unsafe {
+                crate::ffi::PyDict_Next(
+                    self.dict.as_ptr(),
+                    &raw mut pos,
+                    &raw mut next_key,
+                    &raw mut next_value,
+                );
+            }
+
+            let uni = PyStrRef::from_ptr(key)
+                .map_err(|_| serde::ser::Error::custom(SerializeError::KeyMustBeStr))?
+                .as_str();
+            if uni.is_none() {
+                cold_path!();
+                err!(SerializeError::InvalidStr);
+            }
+            if unsafe { core::ptr::eq(value, NONE) } && opt_enabled!(self.state.opts(), OMIT_NONE) {
+                continue;
+            }
+            let key_as_str = case_key(uni.unwrap(), self.state.opts());
+            if let Err(err) = case_keys.insert(&key_as_str) {
+                err!(err)
+            }
+            let pyvalue = PyObjectSerializer::new(value, self.state, self.default);
+            map.serialize_key(&*key_as_str).unwrap();
+            map.serialize_value(&pyvalue)?;
+        }
+
+        map.end()
+    }
+}
+
 pub(crate) struct DictSortedKey {
     dict: PyDictRef,
     state: SerializerState,
@@ -348,10 +486,17 @@ impl Serialize for DictSortedKey {
             }
             let key_as_str = uni.unwrap();
 
+            if unsafe { core::ptr::eq(value, NONE) } && opt_enabled!(self.state.opts(), OMIT_NONE) {
+                continue;
+            }
             items.push((key_as_str, value));
         }
 
-        sort_dict_items(&mut items);
+        if opt_enabled!(self.state.opts(), CANONICAL) {
+            sort_dict_items_canonical(&mut items);
+        } else {
+            sort_dict_items(&mut items);
+        }
 
         let mut map = serializer.serialize_map(None).unwrap();
         for (key, val) in items.iter() {
@@ -426,10 +571,17 @@ fn non_str_uuid(key: PyUuidRef) -> Result<String, SerializeError> {
 #[allow(clippy::unnecessary_wraps)]
 #[cold]
 #[inline(never)]
-fn non_str_float(key: *mut crate::ffi::PyObject) -> Result<String, SerializeError> {
+fn non_str_float(
+    key: *mut crate::ffi::PyObject,
+    opts: crate::opt::Opt,
+) -> Result<String, SerializeError> {
     let val = ffi!(PyFloat_AS_DOUBLE(key));
     if !val.is_finite() {
         Ok(String::from("null"))
+    } else if opt_enabled!(opts, CANONICAL) {
+        let mut buf = SmallFixedBuffer::new();
+        write_float64_canonical(&mut buf, val);
+        Ok(buf.to_string())
     } else {
         Ok(String::from(zmij::Buffer::new().format_finite(val)))
     }
@@ -462,6 +614,16 @@ fn sort_dict_items(items: &mut Vec<(&str, *mut crate::ffi::PyObject)>) {
     items.sort_unstable_by(|a, b| a.0.cmp(b.0));
 }
 
+/// Sort by UTF-16 code units as RFC 8785 requires. This differs from UTF-8
+/// byte order only in that characters above U+FFFF sort before U+E000
+/// through U+FFFF.
+#[inline(never)]
+pub(crate) fn sort_dict_items_canonical<K: core::ops::Deref<Target = str>>(
+    items: &mut [(K, *mut crate::ffi::PyObject)],
+) {
+    items.sort_by(|a, b| a.0.encode_utf16().cmp(b.0.encode_utf16()));
+}
+
 pub(crate) struct DictNonStrKey {
     dict: PyDictRef,
     state: SerializerState,
@@ -484,7 +646,7 @@ impl DictNonStrKey {
                     }
                 }
                 ObType::Int => non_str_int(key),
-                ObType::Float => non_str_float(key),
+                ObType::Float => non_str_float(key, opts),
                 ObType::Datetime => non_str_datetime(PyDateTimeRef::from_ptr_unchecked(key), opts),
                 ObType::Date => non_str_date(PyDateRef::from_ptr_unchecked(key)),
                 ObType::Time => non_str_time(PyTimeRef::from_ptr_unchecked(key), opts),
@@ -501,12 +663,18 @@ impl DictNonStrKey {
                     non_str_str_subclass(PyStrSubclassRef::from_ptr_unchecked(key))
                 }
                 ObType::Tuple
+                | ObType::NamedTuple
                 | ObType::NumpyScalar
                 | ObType::NumpyArray
                 | ObType::Dict
                 | ObType::List
                 | ObType::Dataclass
+                | ObType::Attrs
                 | ObType::Fragment
+                | ObType::Bytes
+                | ObType::Decimal
+                | ObType::Protocol
+                | ObType::Object
                 | ObType::Unknown => Err(SerializeError::DictKeyInvalidType),
             }
         }
@@ -537,7 +705,7 @@ impl Serialize for DictNonStrKey {
         let len = self.dict.len();
         assume!(len > 0);
 
-        let mut items: Vec<(String, *mut crate::ffi::PyObject)> = Vec::with_capacity(len);
+        let mut items: Vec<(String, bool, *mut crate::ffi::PyObject)> = Vec::with_capacity(len);
 
         for _ in 0..len {
             let key = next_key;
@@ -552,26 +720,55 @@ impl Serialize for DictNonStrKey {
                 );
             }
 
-            match PyStrRef::from_ptr(key) {
+            let mut key_as_str = match PyStrRef::from_ptr(key) {
                 Ok(pystr) => match pystr.as_str() {
-                    Some(uni) => {
-                        items.push((String::from(uni), value));
-                    }
+                    Some(uni) => String::from(uni),
                     None => err!(SerializeError::InvalidStr),
                 },
+                Err(_) if opt_disabled!(opts, NON_STR_KEYS) => {
+                    err!(SerializeError::KeyMustBeStr)
+                }
                 Err(_) => match Self::pyobject_to_string(key, opts) {
-                    Ok(key_as_str) => items.push((key_as_str, value)),
+                    Ok(key_as_str) => key_as_str,
                     Err(err) => err!(err),
                 },
+            };
+            let mut converted = false;
+            if opt_enabled!(opts, KEY_CASE)
+                && let CaseKey::Converted(key) = case_key(&key_as_str, opts)
+            {
+                key_as_str = String::from(&*key);
+                converted = true;
+            }
+            if unsafe { core::ptr::eq(value, NONE) } && opt_enabled!(opts, OMIT_NONE) {
+                continue;
+            }
+            items.push((key_as_str, converted, value));
+        }
+
+        if opt_enabled!(opts, KEY_CASE) {
+            cold_path!();
+            let mut case_keys = CaseKeys::with_capacity(items.len());
+            for (key, converted, _) in items.iter() {
+                let key = if *converted {
+                    CaseKey::Converted(Arc::from(key.as_str()))
+                } else {
+                    CaseKey::Unchanged(key.as_str())
+                };
+                if let Err(err) = case_keys.insert(&key) {
+                    err!(err)
+                }
             }
         }
 
         let mut items_as_str: Vec<(&str, *mut crate::ffi::PyObject)> = Vec::with_capacity(len);
         items
             .iter()
-            .for_each(|(key, val)| items_as_str.push(((*key).as_str(), *val)));
+            .for_each(|(key, _, val)| items_as_str.push(((*key).as_str(), *val)));
 
-        if opt_enabled!(opts, SORT_KEYS) {
+        if opt_enabled!(opts, CANONICAL) {
+            sort_dict_items_canonical(&mut items_as_str);
+        } else if opt_enabled!(opts, SORT_KEYS) {
             sort_dict_items(&mut items_as_str);
         }
 
@@ -2,18 +2,19 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::{PyFragmentRef, PyFragmentRefError};
+use crate::opt::{CANONICAL, Opt};
 use crate::serialize::error::SerializeError;
 
 use serde::ser::{Serialize, Serializer};
 
-#[repr(transparent)]
 pub(crate) struct FragmentSerializer {
     ob: PyFragmentRef,
+    opts: Opt,
 }
 
 impl FragmentSerializer {
-    pub fn new(ob: PyFragmentRef) -> Self {
-        FragmentSerializer { ob: ob }
+    pub fn new(ob: PyFragmentRef, opts: Opt) -> Self {
+        FragmentSerializer { ob: ob, opts: opts }
     }
 }
 
@@ -24,6 +25,13 @@ impl Serialize for FragmentSerializer {
     where
         S: Serializer,
     {
+        if !serializer.is_human_readable() {
+            err!(SerializeError::FragmentNotJson)
+        }
+        // The content is not parsed, so it cannot be made canonical.
+        if opt_enabled!(self.opts, CANONICAL) {
+            err!(SerializeError::FragmentCanonical)
+        }
         match self.ob.value() {
             Ok(buffer) => serializer.serialize_bytes(buffer),
             Err(PyFragmentRefError::InvalidStr) => err!(SerializeError::InvalidStr),
@@ -2,8 +2,11 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::PyIntRef;
-use crate::opt::{Opt, STRICT_INTEGER};
+use crate::opt::{CBOR, Opt, STRICT_INTEGER};
 use crate::serialize::error::SerializeError;
+use crate::serialize::writer::{
+    ByteString, TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM, serialize_tagged,
+};
 use serde::ser::{Serialize, Serializer};
 
 // https://tools.ietf.org/html/rfc7159#section-6
@@ -22,6 +25,55 @@ impl IntSerializer {
     }
 }
 
+impl IntSerializer {
+    /// Serialize an integer that exceeds 64 bits. CBOR has bignums and
+    /// negative integers to -2**64.
+    #[cold]
+    #[inline(never)]
+    fn serialize_overflow<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if opt_disabled!(self.opts, CBOR) {
+            err!(SerializeError::Integer64Bits)
+        }
+        unsafe {
+            let ptr = self.ob.as_ptr();
+            let zero = crate::ffi::PyLong_FromLongLong(0);
+            let negative = crate::ffi::PyObject_RichCompareBool(ptr, zero, crate::ffi::Py_LT) == 1;
+            crate::ffi::Py_DECREF(zero);
+            // the magnitude of a negative integer n is written as -1 - n
+            let magnitude = if negative {
+                crate::ffi::PyNumber_Invert(ptr)
+            } else {
+                crate::ffi::Py_INCREF(ptr);
+                ptr
+            };
+            let len = crate::ffi::_PyLong_NumBits(magnitude).div_ceil(8);
+            if negative && len <= 8 {
+                let value = crate::ffi::PyLong_AsUnsignedLongLong(magnitude);
+                crate::ffi::Py_DECREF(magnitude);
+                return serializer.serialize_i128(-1 - i128::from(value));
+            }
+            let mut buf: Vec<u8> = vec![0; len];
+            crate::ffi::PyLong_AsByteArray(
+                magnitude.cast::<crate::ffi::PyLongObject>(),
+                buf.as_mut_ptr(),
+                crate::util::usize_to_isize(len),
+                0,
+                0,
+            );
+            crate::ffi::Py_DECREF(magnitude);
+            let tag = if negative {
+                TAG_NEGATIVE_BIGNUM
+            } else {
+                TAG_POSITIVE_BIGNUM
+            };
+            serialize_tagged(serializer, tag, &ByteString(&buf))
+        }
+    }
+}
+
 impl Serialize for IntSerializer {
     #[inline(always)]
     #[cfg(feature = "inline_int")]
@@ -34,10 +86,9 @@ impl Serialize for IntSerializer {
                 crate::ffi::PyIntKind::I32 => serializer.serialize_i32(self.ob.as_i32()),
                 crate::ffi::PyIntKind::U32 => serializer.serialize_u32(self.ob.as_u32()),
                 crate::ffi::PyIntKind::I64 => {
-                    let value = self
-                        .ob
-                        .as_i64()
-                        .map_err(|_| serde::ser::Error::custom(SerializeError::Integer64Bits))?;
+                    let Ok(value) = self.ob.as_i64() else {
+                        return self.serialize_overflow(serializer);
+                    };
                     if opt_enabled!(self.opts, STRICT_INTEGER)
                         && !(STRICT_INT_MIN..=STRICT_INT_MAX).contains(&value)
                     {
@@ -47,10 +98,9 @@ impl Serialize for IntSerializer {
                     serializer.serialize_i64(value)
                 }
                 crate::ffi::PyIntKind::U64 => {
-                    let value = self
-                        .ob
-                        .as_u64()
-                        .map_err(|_| serde::ser::Error::custom(SerializeError::Integer64Bits))?;
+                    let Ok(value) = self.ob.as_u64() else {
+                        return self.serialize_overflow(serializer);
+                    };
                     if opt_enabled!(self.opts, STRICT_INTEGER) && value > STRICT_INT_MAX as u64 {
                         cold_path!();
                         err!(SerializeError::Integer53Bits);
@@ -87,7 +137,7 @@ impl Serialize for IntSerializer {
                         }
                         serializer.serialize_u64(value)
                     }
-                    Err(_) => err!(SerializeError::Integer64Bits),
+                    Err(_) => self.serialize_overflow(serializer),
                 },
             }
         }
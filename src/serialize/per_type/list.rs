@@ -9,9 +9,10 @@ use crate::serialize::error::SerializeError;
 use crate::serialize::numpy::NumpyScalar;
 use crate::serialize::obtype::{ObType, pyobject_to_obtype};
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer,
-    DictGenericSerializer, EnumSerializer, FloatSerializer, FragmentSerializer, IntSerializer,
-    NoneSerializer, NumpySerializer, StrSerializer, StrSubclassSerializer, Time, UUID,
+    AttrsSerializer, BoolSerializer, BytesSerializer, DataclassGenericSerializer, Date, DateTime,
+    DecimalSerializer, DefaultSerializer, DictGenericSerializer, EnumSerializer, FloatSerializer,
+    FragmentSerializer, IntSerializer, NamedTupleSerializer, NoneSerializer, NumpySerializer,
+    ObjectSerializer, ProtocolSerializer, StrSerializer, StrSubclassSerializer, Time, UUID,
 };
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::state::SerializerState;
@@ -35,7 +36,11 @@ impl Serialize for ZeroListSerializer {
     where
         S: Serializer,
     {
-        serializer.serialize_bytes(b"[]")
+        if serializer.is_human_readable() {
+            serializer.serialize_bytes(b"[]")
+        } else {
+            serializer.serialize_seq(Some(0))?.end()
+        }
     }
 }
 
@@ -146,9 +151,10 @@ impl Serialize for ListTupleSerializer {
                     ))?;
                 }
                 ObType::Uuid => {
-                    seq.serialize_element(&UUID::new(unsafe {
-                        PyUuidRef::from_ptr_unchecked(value)
-                    }))
+                    seq.serialize_element(&UUID::new(
+                        unsafe { PyUuidRef::from_ptr_unchecked(value) },
+                        self.state.opts(),
+                    ))
                     .unwrap();
                 }
                 ObType::Dict => {
@@ -180,11 +186,25 @@ impl Serialize for ListTupleSerializer {
                         seq.serialize_element(&pyvalue)?;
                     }
                 }
+                ObType::NamedTuple => {
+                    seq.serialize_element(&NamedTupleSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))?;
+                }
                 ObType::Dataclass => {
                     seq.serialize_element(&DataclassGenericSerializer::new(
                         &PyObjectSerializer::new(value, self.state, self.default),
                     ))?;
                 }
+                ObType::Attrs => {
+                    seq.serialize_element(&AttrsSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))?;
+                }
                 ObType::Enum => {
                     seq.serialize_element(&EnumSerializer::new(&PyObjectSerializer::new(
                         value,
@@ -203,9 +223,38 @@ impl Serialize for ListTupleSerializer {
                     seq.serialize_element(&NumpyScalar::new(value, self.state.opts()))?;
                 }
                 ObType::Fragment => {
-                    seq.serialize_element(&FragmentSerializer::new(unsafe {
-                        PyFragmentRef::from_ptr_unchecked(value)
-                    }))?;
+                    seq.serialize_element(&FragmentSerializer::new(
+                        unsafe { PyFragmentRef::from_ptr_unchecked(value) },
+                        self.state.opts(),
+                    ))?;
+                }
+                ObType::Bytes => {
+                    seq.serialize_element(&BytesSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))?;
+                }
+                ObType::Decimal => {
+                    seq.serialize_element(&DecimalSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))?;
+                }
+                ObType::Protocol => {
+                    seq.serialize_element(&ProtocolSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))?;
+                }
+                ObType::Object => {
+                    seq.serialize_element(&ObjectSerializer::new(&PyObjectSerializer::new(
+                        value,
+                        self.state,
+                        self.default,
+                    )))?;
                 }
                 ObType::Unknown => {
                     seq.serialize_element(&DefaultSerializer::new(&PyObjectSerializer::new(
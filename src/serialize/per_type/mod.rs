@@ -1,31 +1,43 @@
 // SPDX-License-Identifier: MPL-2.0
 // Copyright ijl (2023-2026)
 
+mod attrs;
+mod bytes;
 mod dataclass;
 mod datetime;
+mod decimal;
 mod default;
 mod dict;
 mod float;
 mod fragment;
 mod int;
 mod list;
+mod namedtuple;
 mod none;
 mod numpy;
+mod object;
+mod protocol;
 mod pybool;
 mod pyenum;
 mod unicode;
 mod uuid;
 
+pub(crate) use attrs::{AttrsSerializer, is_attrs};
+pub(crate) use bytes::BytesSerializer;
 pub(crate) use dataclass::DataclassGenericSerializer;
 pub(crate) use datetime::{Date, DateTime, Time};
+pub(crate) use decimal::{DecimalSerializer, is_decimal};
 pub(crate) use default::DefaultSerializer;
 pub(crate) use dict::DictGenericSerializer;
 pub(crate) use float::FloatSerializer;
 pub(crate) use fragment::FragmentSerializer;
 pub(crate) use int::IntSerializer;
 pub(crate) use list::{ListTupleSerializer, ZeroListSerializer};
+pub(crate) use namedtuple::{NamedTupleSerializer, is_namedtuple};
 pub(crate) use none::NoneSerializer;
 pub(crate) use numpy::{NumpySerializer, is_numpy_array, is_numpy_scalar};
+pub(crate) use object::{ObjectSerializer, is_plain_object};
+pub(crate) use protocol::ProtocolSerializer;
 pub(crate) use pybool::BoolSerializer;
 pub(crate) use pyenum::EnumSerializer;
 pub(crate) use unicode::{StrSerializer, StrSubclassSerializer};
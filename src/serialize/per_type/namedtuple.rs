@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::{PyTupleRef, PyTypeObject};
+use crate::opt::{CANONICAL, KEY_CASE, OMIT_DEFAULTS, OMIT_NONE};
+use crate::serialize::cache::{CaseKey, CaseKeys, namedtuple_plan};
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::dataclass::{DataclassKey, dataclass_case_key};
+use crate::serialize::per_type::dict::sort_dict_items_canonical;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::NONE;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Whether `ob_type`, a subclass of `tuple`, is a named tuple, which has
+/// `_fields` that is a tuple. This is decided when its plan is loaded, and a
+/// class that is not is cached as such.
+#[inline]
+pub(crate) fn is_namedtuple(ob_type: *mut PyTypeObject) -> bool {
+    // A class whose `_fields` is not of `str` is serialized as a named tuple
+    // so that the error is raised.
+    namedtuple_plan(ob_type).map_or(true, |plan| !plan.rejected)
+}
+
+/// Serialize a named tuple as a map of its `_fields` to its items.
+#[repr(transparent)]
+pub(crate) struct NamedTupleSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> NamedTupleSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for NamedTupleSerializer<'_> {
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.recursion_limit() {
+            err!(SerializeError::RecursionLimit)
+        }
+        let ob_type = unsafe { crate::ffi::PyObject_Type(self.previous.ptr) };
+        let plan = match namedtuple_plan(ob_type) {
+            Ok(plan) => plan,
+            Err(err) => err!(err),
+        };
+        let state = self.previous.state.copy_for_recursive_call();
+        let opts = state.opts();
+        let items = unsafe { PyTupleRef::from_ptr_unchecked(self.previous.ptr) };
+        if items.len() != plan.fields.len() {
+            cold_path!();
+            err!(SerializeError::NamedTupleLength)
+        }
+
+        let mut map = serializer.serialize_map(None).unwrap();
+        let mut sorted: Option<Vec<(CaseKey<'_>, *mut crate::ffi::PyObject)>> =
+            opt_enabled!(opts, CANONICAL).then(Vec::new);
+        let mut case_keys: Option<CaseKeys<'_>> =
+            opt_enabled!(opts, KEY_CASE).then(|| CaseKeys::with_capacity(plan.fields.len()));
+
+        for (idx, field) in plan.fields.iter().enumerate() {
+            let value = items.get(idx);
+            if unsafe { core::ptr::eq(value, NONE) } && opt_enabled!(opts, OMIT_NONE) {
+                continue;
+            }
+            if opt_enabled!(opts, OMIT_DEFAULTS) {
+                match field.default.is_default(value) {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(err) => err!(err),
+                }
+            }
+            let key_as_str = dataclass_case_key(&field.key, Some(field), opts);
+            if let Some(case_keys) = case_keys.as_mut()
+                && let Err(err) = case_keys.insert(&key_as_str)
+            {
+                err!(err)
+            }
+            if let Some(items) = sorted.as_mut() {
+                cold_path!();
+                items.push((key_as_str, value));
+                continue;
+            }
+            let pyvalue = PyObjectSerializer::new(value, state, self.previous.default);
+            if matches!(key_as_str, CaseKey::Unchanged(_)) {
+                map.serialize_key(&DataclassKey { field: field }).unwrap();
+            } else {
+                map.serialize_key(&*key_as_str).unwrap();
+            }
+            map.serialize_value(&pyvalue)?;
+        }
+        if let Some(mut items) = sorted {
+            sort_dict_items_canonical(&mut items);
+            for (key, value) in items {
+                let pyvalue = PyObjectSerializer::new(value, state, self.previous.default);
+                map.serialize_key(&*key).unwrap();
+                map.serialize_value(&pyvalue)?;
+            }
+        }
+        map.end()
+    }
+}
@@ -6,6 +6,7 @@ use crate::ffi::{
     NumpyFloat64, NumpyInt8, NumpyInt16, NumpyInt32, NumpyInt64, NumpyUint8, NumpyUint16,
     NumpyUint32, NumpyUint64, PyTypeObject,
 };
+use crate::opt::CBOR_TYPED_ARRAY;
 use crate::serialize::error::SerializeError;
 use crate::serialize::numpy::{
     ItemType, NumpyArray, NumpyBoolArray, NumpyDatetime64Array, NumpyF16Array, NumpyF32Array,
@@ -16,8 +17,11 @@ use crate::serialize::numpy::{
 use crate::serialize::per_type::{DefaultSerializer, ZeroListSerializer};
 use crate::serialize::serializer::PyObjectSerializer;
 use crate::serialize::writer::SmallFixedBuffer;
-use crate::serialize::writer::f16_to_f32;
+use crate::serialize::writer::{
+    ByteString, TAG_MULTI_DIMENSIONAL_ARRAY, TAG_TYPED_ARRAY, f16_to_f32, serialize_tagged,
+};
 use crate::typeref::{NUMPY_TYPES, load_numpy_types};
+use crate::util::isize_to_usize;
 use serde::ser::{Serialize, SerializeSeq, Serializer};
 
 #[repr(transparent)]
@@ -100,6 +104,84 @@ pub(crate) fn is_numpy_array(ob_type: *mut PyTypeObject) -> bool {
     }
 }
 
+/// Tag of the RFC 8746 typed array of `kind` in native byte order, if any.
+fn typed_array_tag(kind: ItemType) -> Option<u32> {
+    // (float, signed, log2 of the size of an item less that of the smallest)
+    let (float, signed, size) = match kind {
+        ItemType::U8 => (0, 0, 0),
+        ItemType::U16 => (0, 0, 1),
+        ItemType::U32 => (0, 0, 2),
+        ItemType::U64 => (0, 0, 3),
+        ItemType::I8 => (0, 1, 0),
+        ItemType::I16 => (0, 1, 1),
+        ItemType::I32 => (0, 1, 2),
+        ItemType::I64 => (0, 1, 3),
+        ItemType::F16 => (1, 0, 0),
+        ItemType::F32 => (1, 0, 1),
+        ItemType::F64 => (1, 0, 2),
+        ItemType::BOOL | ItemType::DATETIME64(_) => return None,
+    };
+    // single bytes have no byte order and the bit means clamped instead
+    let little_endian = u32::from(cfg!(target_endian = "little") && (float == 1 || size != 0));
+    Some(TAG_TYPED_ARRAY | float << 4 | signed << 3 | little_endian << 2 | size)
+}
+
+#[repr(transparent)]
+struct NumpyShape<'a> {
+    shape: &'a [isize],
+}
+
+impl Serialize for NumpyShape<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.shape.len()))?;
+        for &each in self.shape {
+            seq.serialize_element(&(isize_to_usize(each) as u64))?;
+        }
+        seq.end()
+    }
+}
+
+struct NumpyTypedArray<'a> {
+    array: &'a NumpyArray,
+    tag: u32,
+}
+
+impl Serialize for NumpyTypedArray<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_tagged(serializer, self.tag, &ByteString(self.array.as_bytes()))
+    }
+}
+
+/// A typed array of more than one dimension is in a multi-dimensional
+/// array, tag 40, of its shape and items in row-major order.
+struct NumpyMultiDimensionalArray<'a> {
+    array: &'a NumpyArray,
+    tag: u32,
+}
+
+impl Serialize for NumpyMultiDimensionalArray<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(2))?;
+        seq.serialize_element(&NumpyShape {
+            shape: self.array.shape(),
+        })?;
+        seq.serialize_element(&NumpyTypedArray {
+            array: self.array,
+            tag: self.tag,
+        })?;
+        seq.end()
+    }
+}
+
 impl Serialize for NumpyArray {
     #[cold]
     #[inline(never)]
@@ -108,6 +190,26 @@ impl Serialize for NumpyArray {
     where
         S: Serializer,
     {
+        if self.depth == 0
+            && opt_enabled!(self.opts, CBOR_TYPED_ARRAY)
+            && let Some(tag) = typed_array_tag(self.kind)
+        {
+            if self.dimensions() == 1 {
+                return NumpyTypedArray {
+                    array: self,
+                    tag: tag,
+                }
+                .serialize(serializer);
+            }
+            return serialize_tagged(
+                serializer,
+                TAG_MULTI_DIMENSIONAL_ARRAY,
+                &NumpyMultiDimensionalArray {
+                    array: self,
+                    tag: tag,
+                },
+            );
+        }
         if !(self.depth >= self.dimensions() || self.shape()[self.depth] != 0) {
             cold_path!();
             ZeroListSerializer::new().serialize(serializer)
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::{PyBaseObject_Type, PyTupleRef, PyType_GetFlags, PyTypeObject};
+use crate::serialize::cache::object_plan;
+use crate::serialize::error::SerializeError;
+use crate::serialize::per_type::dataclass::{DataclassFastSerializer, DataclassPlanSerializer};
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::{ATTRS_ATTRS_STR, DATACLASS_FIELDS_STR, DICT_STR, UUID_TYPE};
+
+use serde::ser::{Serialize, Serializer};
+
+/// Whether `ob_type` is a class defined in Python whose bases are all
+/// classes defined in Python or `object`, and which is not a dataclass or
+/// attrs class.
+///
+/// Extension types created using `PyType_FromSpec()` are heap types, as
+/// classes defined in Python are, so a class is identified as defined in
+/// Python by being deallocated by `subtype_dealloc`, as `uuid.UUID` is.
+#[cold]
+#[inline(never)]
+pub(crate) fn is_plain_object(ob_type: *mut PyTypeObject) -> bool {
+    unsafe {
+        if pydict_contains!(ob_type, DATACLASS_FIELDS_STR)
+            || pydict_contains!(ob_type, ATTRS_ATTRS_STR)
+        {
+            return false;
+        }
+        let mro = (*ob_type).tp_mro;
+        if mro.is_null() || crate::ffi::PyTuple_Check(mro) == 0 {
+            return false;
+        }
+        let python_dealloc = (*UUID_TYPE).tp_dealloc.map(|dealloc| dealloc as usize);
+        let is_python = |base: *mut PyTypeObject| {
+            is_subclass_by_flag!(PyType_GetFlags(base), Py_TPFLAGS_HEAPTYPE)
+                && (*base).tp_dealloc.map(|dealloc| dealloc as usize) == python_dealloc
+        };
+        // `object` itself is not, so `object()` is not serialized as `{}`.
+        if !is_python(ob_type) {
+            return false;
+        }
+        let mro = PyTupleRef::from_ptr_unchecked(mro);
+        (0..mro.len()).all(|idx| {
+            let base = mro.get(idx).cast::<PyTypeObject>();
+            core::ptr::eq(base, &raw mut PyBaseObject_Type) || is_python(base)
+        })
+    }
+}
+
+/// Serialize an instance of a class using `OPT_SERIALIZE_OBJECT`, as a map
+/// of its `__slots__` and then its `__dict__`.
+#[repr(transparent)]
+pub(crate) struct ObjectSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> ObjectSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for ObjectSerializer<'_> {
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.recursion_limit() {
+            err!(SerializeError::RecursionLimit)
+        }
+        let ob_type = unsafe { crate::ffi::PyObject_Type(self.previous.ptr) };
+        let plan = match object_plan(ob_type) {
+            Ok(plan) => plan,
+            Err(err) => err!(err),
+        };
+        let dict = ffi!(PyObject_GetAttr(self.previous.ptr, DICT_STR));
+        if dict.is_null() {
+            ffi!(PyErr_Clear());
+            return DataclassPlanSerializer::new(
+                self.previous.ptr,
+                &plan,
+                self.previous.state,
+                self.previous.default,
+            )
+            .serialize(serializer);
+        }
+        if ffi!(PyDict_Check(dict)) == 0 {
+            cold_path!();
+            ffi!(Py_DECREF(dict));
+            err!(SerializeError::ObjectDict)
+        }
+        if plan.fields.is_empty() {
+            let ret = DataclassFastSerializer::new(
+                dict,
+                &plan,
+                self.previous.state,
+                self.previous.default,
+            )
+            .serialize(serializer);
+            ffi!(Py_DECREF(dict));
+            return ret;
+        }
+        // A class with both `__slots__` and `__dict__`, such as a subclass
+        // without `__slots__` of a class with them, is serialized from a
+        // `dict` of both.
+        let merged = unsafe { crate::ffi::PyDict_New(0) };
+        for field in plan.fields.iter() {
+            let value = ffi!(PyObject_GetAttr(self.previous.ptr, field.name));
+            if value.is_null() {
+                ffi!(PyErr_Clear());
+                continue;
+            }
+            ffi!(PyDict_SetItem(merged, field.name, value));
+            ffi!(Py_DECREF(value));
+        }
+        ffi!(PyDict_Merge(merged, dict, 1));
+        ffi!(Py_DECREF(dict));
+        let ret =
+            DataclassFastSerializer::new(merged, &plan, self.previous.state, self.previous.default)
+                .serialize(serializer);
+        ffi!(Py_DECREF(merged));
+        ret
+    }
+}
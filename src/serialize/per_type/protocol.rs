@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+use crate::ffi::PyObject_CallMethodNoArgs;
+use crate::serialize::error::SerializeError;
+use crate::serialize::serializer::PyObjectSerializer;
+use crate::typeref::ORJSON_METHOD_STR;
+
+use serde::ser::{Serialize, Serializer};
+
+/// Serialize an instance of a class defining `__orjson__` as what it
+/// returns. This is counted as a call of `default` so that an object
+/// returning itself raises.
+#[repr(transparent)]
+pub(crate) struct ProtocolSerializer<'a> {
+    previous: &'a PyObjectSerializer,
+}
+
+impl<'a> ProtocolSerializer<'a> {
+    pub fn new(previous: &'a PyObjectSerializer) -> Self {
+        Self { previous: previous }
+    }
+}
+
+impl Serialize for ProtocolSerializer<'_> {
+    #[cold]
+    #[inline(never)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.previous.state.default_calls_limit() {
+            cold_path!();
+            err!(SerializeError::DefaultRecursionLimit)
+        }
+        let obj = unsafe { PyObject_CallMethodNoArgs(self.previous.ptr, ORJSON_METHOD_STR) };
+        if obj.is_null() {
+            err!(SerializeError::UnsupportedType(
+                nonnull!(self.previous.ptr),
+                self.previous.state.opts(),
+            ))
+        }
+        let res = PyObjectSerializer::new(
+            obj,
+            self.previous.state.copy_for_default_call(),
+            self.previous.default,
+        )
+        .serialize(serializer);
+        ffi!(Py_DECREF(obj));
+        res
+    }
+}
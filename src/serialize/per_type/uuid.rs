@@ -2,18 +2,22 @@
 // Copyright ijl (2018-2026)
 
 use crate::ffi::PyUuidRef;
+use crate::opt::{CBOR, Opt};
 use crate::serialize::uuid::write_uuid;
-use crate::serialize::writer::SmallFixedBuffer;
+use crate::serialize::writer::{ByteString, SmallFixedBuffer, TAG_UUID, serialize_tagged};
 use serde::ser::{Serialize, Serializer};
 
-#[repr(transparent)]
 pub(crate) struct UUID {
     ob: PyUuidRef,
+    opts: Opt,
 }
 
 impl UUID {
-    pub fn new(ptr: PyUuidRef) -> Self {
-        UUID { ob: ptr }
+    pub fn new(ptr: PyUuidRef, opts: Opt) -> Self {
+        UUID {
+            ob: ptr,
+            opts: opts,
+        }
     }
 }
 
@@ -23,6 +27,12 @@ impl Serialize for UUID {
     where
         S: Serializer,
     {
+        if opt_enabled!(self.opts, CBOR) {
+            cold_path!();
+            let mut value = [0u8; 16];
+            self.ob.value(&mut value);
+            return serialize_tagged(serializer, TAG_UUID, &ByteString(&value));
+        }
         let mut buf = SmallFixedBuffer::new();
         write_uuid(self.ob.clone(), &mut buf);
         serializer.serialize_unit_struct(str_from_slice!(buf.as_ptr(), buf.len()))
@@ -5,17 +5,21 @@ use crate::ffi::{
     PyBoolRef, PyDateRef, PyDateTimeRef, PyDictRef, PyFloatRef, PyFragmentRef, PyIntRef, PyListRef,
     PyStrRef, PyStrSubclassRef, PyTimeRef, PyUuidRef,
 };
-use crate::opt::{APPEND_NEWLINE, INDENT_2, Opt};
+use crate::opt::{APPEND_NEWLINE, CANONICAL, CBOR, INDENT_2, MSGPACK, Opt};
 use crate::serialize::numpy::NumpyScalar;
 use crate::serialize::obtype::{ObType, pyobject_to_obtype};
 use crate::serialize::per_type::{
-    BoolSerializer, DataclassGenericSerializer, Date, DateTime, DefaultSerializer,
-    DictGenericSerializer, EnumSerializer, FloatSerializer, FragmentSerializer, IntSerializer,
-    ListTupleSerializer, NoneSerializer, NumpySerializer, StrSerializer, StrSubclassSerializer,
+    AttrsSerializer, BoolSerializer, BytesSerializer, DataclassGenericSerializer, Date, DateTime,
+    DecimalSerializer, DefaultSerializer, DictGenericSerializer, EnumSerializer, FloatSerializer,
+    FragmentSerializer, IntSerializer, ListTupleSerializer, NamedTupleSerializer, NoneSerializer,
+    NumpySerializer, ObjectSerializer, ProtocolSerializer, StrSerializer, StrSubclassSerializer,
     Time, UUID, ZeroListSerializer,
 };
 use crate::serialize::state::SerializerState;
-use crate::serialize::writer::{BytesWriter, to_writer, to_writer_pretty};
+use crate::serialize::writer::{
+    BytesWriter, to_writer, to_writer_canonical, to_writer_cbor, to_writer_msgpack,
+    to_writer_pretty,
+};
 use core::ptr::NonNull;
 use serde::ser::{Serialize, Serializer};
 
@@ -26,7 +30,9 @@ pub(crate) fn serialize(
 ) -> Result<NonNull<crate::ffi::PyObject>, String> {
     let mut buf = BytesWriter::default();
     let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts), default);
-    let res = if opt_disabled!(opts, INDENT_2) {
+    let res = if opt_enabled!(opts, CANONICAL) {
+        to_writer_canonical(&mut buf, &obj)
+    } else if opt_disabled!(opts, INDENT_2) {
         to_writer(&mut buf, &obj)
     } else {
         to_writer_pretty(&mut buf, &obj)
@@ -40,6 +46,42 @@ pub(crate) fn serialize(
     }
 }
 
+/// Serialize `ptr` as MessagePack. `bytes`, `bytearray`, and `memoryview` are
+/// written as binary data.
+pub(crate) fn serialize_msgpack(
+    ptr: *mut crate::ffi::PyObject,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+    opts: Opt,
+) -> Result<NonNull<crate::ffi::PyObject>, String> {
+    let mut buf = BytesWriter::default();
+    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts | MSGPACK), default);
+    match to_writer_msgpack(&mut buf, &obj) {
+        Ok(()) => Ok(buf.finish(false)),
+        Err(err) => {
+            buf.abort();
+            Err(err.to_string())
+        }
+    }
+}
+
+/// Serialize `ptr` as CBOR. Types that have a standard tag are written as
+/// it.
+pub(crate) fn serialize_cbor(
+    ptr: *mut crate::ffi::PyObject,
+    default: Option<NonNull<crate::ffi::PyObject>>,
+    opts: Opt,
+) -> Result<NonNull<crate::ffi::PyObject>, String> {
+    let mut buf = BytesWriter::default();
+    let obj = PyObjectSerializer::new(ptr, SerializerState::new(opts | CBOR), default);
+    match to_writer_cbor(&mut buf, &obj) {
+        Ok(()) => Ok(buf.finish(false)),
+        Err(err) => {
+            buf.abort();
+            Err(err.to_string())
+        }
+    }
+}
+
 pub(crate) struct PyObjectSerializer {
     pub ptr: *mut crate::ffi::PyObject,
     pub state: SerializerState,
@@ -97,7 +139,8 @@ impl Serialize for PyObjectSerializer {
                         .serialize(serializer)
                 }
                 ObType::Uuid => {
-                    UUID::new(PyUuidRef::from_ptr_unchecked(self.ptr)).serialize(serializer)
+                    UUID::new(PyUuidRef::from_ptr_unchecked(self.ptr), self.state.opts())
+                        .serialize(serializer)
                 }
                 ObType::Dict => DictGenericSerializer::new(
                     PyDictRef::from_ptr_unchecked(self.ptr),
@@ -125,16 +168,23 @@ impl Serialize for PyObjectSerializer {
                             .serialize(serializer)
                     }
                 }
+                ObType::NamedTuple => NamedTupleSerializer::new(self).serialize(serializer),
                 ObType::Dataclass => DataclassGenericSerializer::new(self).serialize(serializer),
+                ObType::Attrs => AttrsSerializer::new(self).serialize(serializer),
                 ObType::Enum => EnumSerializer::new(self).serialize(serializer),
                 ObType::NumpyArray => NumpySerializer::new(self).serialize(serializer),
                 ObType::NumpyScalar => {
                     NumpyScalar::new(self.ptr, self.state.opts()).serialize(serializer)
                 }
-                ObType::Fragment => {
-                    FragmentSerializer::new(unsafe { PyFragmentRef::from_ptr_unchecked(self.ptr) })
-                        .serialize(serializer)
-                }
+                ObType::Fragment => FragmentSerializer::new(
+                    unsafe { PyFragmentRef::from_ptr_unchecked(self.ptr) },
+                    self.state.opts(),
+                )
+                .serialize(serializer),
+                ObType::Bytes => BytesSerializer::new(self).serialize(serializer),
+                ObType::Decimal => DecimalSerializer::new(self).serialize(serializer),
+                ObType::Protocol => ProtocolSerializer::new(self).serialize(serializer),
+                ObType::Object => ObjectSerializer::new(self).serialize(serializer),
                 ObType::Unknown => DefaultSerializer::new(self).serialize(serializer),
             }
         }
@@ -1,33 +1,35 @@
 // SPDX-License-Identifier: MPL-2.0
-// Copyright ijl (2024-2025)
+// Copyright ijl (2024-2026)
 
 use crate::opt::Opt;
 
-const RECURSION_SHIFT: usize = 24;
-const RECURSION_MASK: u32 = 255 << RECURSION_SHIFT;
+const RECURSION_SHIFT: usize = 40;
+const RECURSION_MASK: u64 = 255 << RECURSION_SHIFT;
 
-const DEFAULT_SHIFT: usize = 16;
-const DEFAULT_MASK: u32 = 255 << DEFAULT_SHIFT;
+const DEFAULT_SHIFT: usize = 32;
+const DEFAULT_MASK: u64 = 255 << DEFAULT_SHIFT;
 
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 pub(crate) struct SerializerState {
     // recursion: u8,
     // default_calls: u8,
-    // opts: u16,
-    state: u32,
+    // opts: u32,
+    state: u64,
 }
 
 impl SerializerState {
     #[inline(always)]
     pub fn new(opts: Opt) -> Self {
-        debug_assert!(opts < u32::from(u16::MAX));
-        Self { state: opts }
+        Self {
+            state: u64::from(opts),
+        }
     }
 
     #[inline(always)]
-    pub fn opts(self) -> u32 {
-        self.state
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn opts(self) -> Opt {
+        self.state as Opt
     }
 
     #[inline(always)]
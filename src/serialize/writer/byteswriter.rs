@@ -94,6 +94,27 @@ impl BytesWriter {
         }
     }
 
+    /// Return the number of bytes written.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.len
+    }
+
+    /// Replace the `len` bytes written at `pos` with `src`, which is no
+    /// longer, moving the bytes that follow them back.
+    pub fn splice(&mut self, pos: usize, len: usize, src: &[u8]) {
+        debug_assert!(src.len() <= len);
+        debug_assert!(pos + len <= self.len);
+        unsafe {
+            let start = self.buffer_ptr().sub(self.len).add(pos);
+            core::ptr::copy_nonoverlapping(src.as_ptr(), start, src.len());
+            if src.len() < len {
+                core::ptr::copy(start.add(len), start.add(src.len()), self.len - pos - len);
+                self.len -= len - src.len();
+            }
+        }
+    }
+
     #[cfg(CPython)]
     #[inline]
     fn buffer_ptr(&self) -> *mut u8 {
@@ -264,3 +285,27 @@ impl WriteExt for &mut BytesWriter {
         self.put_slice(b"null");
     }
 }
+
+impl WriteExt for Vec<u8> {
+    #[inline(always)]
+    fn as_mut_buffer_ptr(&mut self) -> *mut u8 {
+        unsafe { self.as_mut_ptr().add(self.len()) }
+    }
+
+    #[inline]
+    fn reserve(&mut self, len: usize) {
+        Vec::reserve(self, len);
+    }
+
+    fn reserve_minimum(&mut self) {
+        unimplemented!()
+    }
+
+    fn put_bool(&mut self, _val: bool) {
+        unimplemented!()
+    }
+
+    fn put_null(&mut self) {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,470 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+// https://www.rfc-editor.org/rfc/rfc8949
+
+use crate::serialize::writer::{BytesWriter, WriteExt};
+use bytes::BufMut;
+use serde::ser::{self, Impossible, Serialize};
+use serde_json::error::{Error, Result};
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1 << 5;
+const BYTES: u8 = 2 << 5;
+const TEXT: u8 = 3 << 5;
+const ARRAY: u8 = 4 << 5;
+const MAP: u8 = 5 << 5;
+const TAG: u8 = 6 << 5;
+
+const FALSE: u8 = 0xf4;
+const TRUE: u8 = 0xf5;
+const NULL: u8 = 0xf6;
+const FLOAT32: u8 = 0xfa;
+const FLOAT64: u8 = 0xfb;
+
+pub(crate) const TAG_DATETIME: u32 = 0;
+pub(crate) const TAG_EPOCH_DATETIME: u32 = 1;
+pub(crate) const TAG_POSITIVE_BIGNUM: u32 = 2;
+pub(crate) const TAG_NEGATIVE_BIGNUM: u32 = 3;
+pub(crate) const TAG_DECIMAL_FRACTION: u32 = 4;
+pub(crate) const TAG_UUID: u32 = 37;
+pub(crate) const TAG_MULTI_DIMENSIONAL_ARRAY: u32 = 40;
+
+/// Tag of the first typed array of RFC 8746. The tag of each is this with
+/// bits for whether it is of floats, signed, little-endian, and the size of
+/// its elements.
+pub(crate) const TAG_TYPED_ARRAY: u32 = 64;
+
+/// Name given to `serialize_newtype_variant()` by `serialize_tagged()`.
+const TAG_NAME: &str = "orjson.cbor.tag";
+
+/// Serialize `value` as the content of the tag `tag`. Only the CBOR writer
+/// may be given this.
+#[inline(never)]
+pub(crate) fn serialize_tagged<S, T>(
+    serializer: S,
+    tag: u32,
+    value: &T,
+) -> core::result::Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: ?Sized + Serialize,
+{
+    serializer.serialize_newtype_variant(TAG_NAME, tag, TAG_NAME, value)
+}
+
+/// A byte string, as the content of a tag.
+#[repr(transparent)]
+pub(crate) struct ByteString<'a>(pub &'a [u8]);
+
+impl Serialize for ByteString<'_> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Length of the largest head.
+const HEADER_MAX: usize = 9;
+
+struct Header {
+    buf: [u8; HEADER_MAX],
+    len: usize,
+}
+
+impl Header {
+    /// Return the smallest head of `major` with the argument `value`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(major: u8, value: u64) -> Self {
+        let mut buf = [0; HEADER_MAX];
+        let len = if value < 24 {
+            buf[0] = major | value as u8;
+            1
+        } else if value <= u64::from(u8::MAX) {
+            buf[0] = major | 24;
+            buf[1] = value as u8;
+            2
+        } else if value <= u64::from(u16::MAX) {
+            buf[0] = major | 25;
+            buf[1..3].copy_from_slice(&(value as u16).to_be_bytes());
+            3
+        } else if value <= u64::from(u32::MAX) {
+            buf[0] = major | 26;
+            buf[1..5].copy_from_slice(&(value as u32).to_be_bytes());
+            5
+        } else {
+            buf[0] = major | 27;
+            buf[1..9].copy_from_slice(&value.to_be_bytes());
+            9
+        };
+        Header { buf: buf, len: len }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+pub(crate) struct Serializer<'w> {
+    writer: &'w mut BytesWriter,
+}
+
+impl<'w> Serializer<'w> {
+    #[inline]
+    pub fn new(writer: &'w mut BytesWriter) -> Self {
+        Serializer { writer: writer }
+    }
+
+    #[inline]
+    fn write_header(&mut self, major: u8, value: u64) {
+        self.writer.reserve(HEADER_MAX * 2);
+        self.writer.put_slice(Header::new(major, value).as_slice());
+    }
+
+    #[inline]
+    fn write_with_marker(&mut self, marker: u8, value: &[u8]) {
+        self.writer.reserve(HEADER_MAX * 2 + value.len());
+        self.writer.put_u8(marker);
+        self.writer.put_slice(value);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, value: i64) {
+        if value >= 0 {
+            self.write_header(UNSIGNED, value.cast_unsigned());
+        } else {
+            self.write_header(NEGATIVE, (-1 - value).cast_unsigned());
+        }
+    }
+
+    #[inline]
+    fn write_sized(&mut self, major: u8, value: &[u8]) {
+        let header = Header::new(major, value.len() as u64);
+        self.writer.reserve(HEADER_MAX * 2 + value.len());
+        self.writer.put_slice(header.as_slice());
+        self.writer.put_slice(value);
+    }
+
+    /// Begin an array or map of `len` elements, or, if it is not known,
+    /// write the largest head to be replaced when it is.
+    #[inline]
+    fn begin<'a>(&'a mut self, major: u8, len: Option<usize>) -> Compound<'a, 'w> {
+        self.writer.reserve(HEADER_MAX * 2);
+        let start = match len {
+            Some(len) => {
+                self.writer
+                    .put_slice(Header::new(major, len as u64).as_slice());
+                None
+            }
+            None => {
+                let start = self.writer.position();
+                self.writer.put_u8(major | 27);
+                self.writer.put_bytes(0, HEADER_MAX - 1);
+                Some(start)
+            }
+        };
+        Compound {
+            ser: self,
+            major: major,
+            start: start,
+            len: 0,
+        }
+    }
+}
+
+impl<'a, 'w> ser::Serializer for &'a mut Serializer<'w> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Compound<'a, 'w>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Compound<'a, 'w>;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        self.writer.reserve(HEADER_MAX);
+        self.writer
+            .put_u8(core::hint::select_unpredictable(value, TRUE, FALSE));
+        Ok(())
+    }
+
+    fn serialize_i8(self, _value: i8) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_i16(self, _value: i16) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.write_i64(i64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        self.write_i64(value);
+        Ok(())
+    }
+
+    /// Serialize a negative integer less than `i64::MIN` that is written as
+    /// it is rather than as a bignum.
+    #[allow(clippy::cast_possible_truncation)]
+    fn serialize_i128(self, value: i128) -> Result<()> {
+        debug_assert!(value < 0 && -1 - value <= i128::from(u64::MAX));
+        self.write_header(NEGATIVE, (-1 - value).cast_unsigned() as u64);
+        Ok(())
+    }
+
+    fn serialize_u8(self, _value: u8) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_u16(self, _value: u16) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.write_header(UNSIGNED, u64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.write_header(UNSIGNED, value);
+        Ok(())
+    }
+
+    fn serialize_u128(self, _value: u128) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.write_with_marker(FLOAT32, &value.to_be_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        self.write_with_marker(FLOAT64, &value.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, _value: char) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline(always)]
+    fn serialize_str(self, value: &str) -> Result<()> {
+        self.write_sized(TEXT, value.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        self.write_sized(BYTES, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<()> {
+        self.writer.reserve(HEADER_MAX);
+        self.writer.put_u8(NULL);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn serialize_unit_struct(self, name: &'static str) -> Result<()> {
+        self.write_sized(TEXT, name.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        unreachable!();
+    }
+
+    #[cold]
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        debug_assert!(name == TAG_NAME);
+        self.write_header(TAG, u64::from(variant_index));
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    #[inline]
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    #[inline(always)]
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(self.begin(ARRAY, len))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        unreachable!();
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        unreachable!();
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unreachable!();
+    }
+
+    #[inline(always)]
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(self.begin(MAP, len))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        unreachable!();
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unreachable!();
+    }
+}
+
+pub(crate) struct Compound<'a, 'w> {
+    ser: &'a mut Serializer<'w>,
+    major: u8,
+    /// Position of the head to replace, if the length was not known.
+    start: Option<usize>,
+    len: usize,
+}
+
+impl Compound<'_, '_> {
+    #[inline]
+    fn end(self) {
+        if let Some(start) = self.start {
+            let header = Header::new(self.major, self.len as u64);
+            self.ser.writer.splice(start, HEADER_MAX, header.as_slice());
+        }
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.len += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Compound::end(self);
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_entry<K, V>(&mut self, _key: &K, _value: &V) -> Result<()>
+    where
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
+    {
+        unreachable!()
+    }
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.len += 1;
+        key.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Compound::end(self);
+        Ok(())
+    }
+}
+
+#[inline]
+pub(crate) fn to_writer_cbor<T>(writer: &mut BytesWriter, value: &T) -> Result<()>
+where
+    T: ?Sized + Serialize,
+{
+    let mut ser = Serializer::new(writer);
+    value.serialize(&mut ser)
+}
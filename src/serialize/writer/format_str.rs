@@ -16,6 +16,14 @@ macro_rules! reserve_str {
 ))]
 type StrFormatter = unsafe fn(*mut u8, *const u8, usize) -> usize;
 
+/// The escaped and quoted representation of `value`, for strings that are
+/// escaped once and written many times.
+pub(crate) fn escape_str(value: &str) -> Vec<u8> {
+    let mut buf = Vec::new();
+    format_escaped_str(&mut buf, value);
+    buf
+}
+
 pub(crate) fn set_str_formatter_fn() {
     unsafe {
         #[cfg(all(
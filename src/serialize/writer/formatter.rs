@@ -3,9 +3,10 @@
 // This is an adaptation of `src/value/ser.rs` from serde-json.
 
 use super::{
-    write_float32, write_float64, write_integer_i32, write_integer_i64, write_integer_u32,
-    write_integer_u64,
+    write_float32, write_float64, write_float64_canonical, write_integer_i32, write_integer_i64,
+    write_integer_u32, write_integer_u64,
 };
+use crate::serialize::error::SerializeError;
 use crate::serialize::writer::WriteExt;
 use std::io;
 
@@ -96,6 +97,15 @@ pub(crate) trait Formatter {
         Ok(())
     }
 
+    /// Write a NaN or infinite float, which JSON cannot represent.
+    #[inline]
+    fn write_non_finite<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + WriteExt + bytes::BufMut,
+    {
+        self.write_null(writer)
+    }
+
     #[inline]
     fn begin_array<W>(&mut self, writer: &mut W) -> io::Result<()>
     where
@@ -199,6 +209,77 @@ pub(crate) struct CompactFormatter;
 
 impl Formatter for CompactFormatter {}
 
+// https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2.3
+const CANONICAL_INT_MIN: i64 = -9007199254740991;
+const CANONICAL_INT_MAX: i64 = 9007199254740991;
+
+/// Write RFC 8785 canonical JSON. Numbers are IEEE 754 doubles formatted as
+/// ECMAScript does, so integers that a double cannot represent exactly and
+/// non-finite floats are errors rather than being written inexactly.
+pub(crate) struct CanonicalFormatter;
+
+impl CanonicalFormatter {
+    #[cold]
+    #[inline(never)]
+    fn error(err: &SerializeError) -> io::Error {
+        io::Error::other(err.to_string())
+    }
+}
+
+impl Formatter for CanonicalFormatter {
+    #[inline]
+    fn write_i64<W>(&mut self, writer: &mut W, value: i64) -> io::Result<()>
+    where
+        W: ?Sized + WriteExt + bytes::BufMut,
+    {
+        if !(CANONICAL_INT_MIN..=CANONICAL_INT_MAX).contains(&value) {
+            return Err(Self::error(&SerializeError::Integer53Bits));
+        }
+        writer.reserve_minimum();
+        write_integer_i64(writer, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_u64<W>(&mut self, writer: &mut W, value: u64) -> io::Result<()>
+    where
+        W: ?Sized + WriteExt + bytes::BufMut,
+    {
+        if value > CANONICAL_INT_MAX.cast_unsigned() {
+            return Err(Self::error(&SerializeError::Integer53Bits));
+        }
+        writer.reserve_minimum();
+        write_integer_u64(writer, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_f32<W>(&mut self, writer: &mut W, value: f32) -> io::Result<()>
+    where
+        W: ?Sized + WriteExt + bytes::BufMut,
+    {
+        self.write_f64(writer, f64::from(value))
+    }
+
+    #[inline]
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + WriteExt + bytes::BufMut,
+    {
+        writer.reserve_minimum();
+        write_float64_canonical(writer, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_non_finite<W>(&mut self, _writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + WriteExt + bytes::BufMut,
+    {
+        Err(Self::error(&SerializeError::FloatNotFinite))
+    }
+}
+
 pub(crate) struct PrettyFormatter {
     current_indent: usize,
     has_value: bool,
@@ -4,7 +4,9 @@
 
 use super::format_str::format_escaped_str;
 use crate::serialize::writer::WriteExt;
-use crate::serialize::writer::formatter::{CompactFormatter, Formatter, PrettyFormatter};
+use crate::serialize::writer::formatter::{
+    CanonicalFormatter, CompactFormatter, Formatter, PrettyFormatter,
+};
 use serde::ser::{self, Impossible, Serialize};
 use serde_json::error::{Error, Result};
 
@@ -123,7 +125,9 @@ where
     fn serialize_f32(self, value: f32) -> Result<()> {
         if value.is_infinite() || value.is_nan() {
             cold_path!();
-            self.serialize_unit()
+            self.formatter
+                .write_non_finite(&mut self.writer)
+                .map_err(Error::io)
         } else {
             self.formatter
                 .write_f32(&mut self.writer, value)
@@ -134,7 +138,9 @@ where
     fn serialize_f64(self, value: f64) -> Result<()> {
         if value.is_infinite() || value.is_nan() {
             cold_path!();
-            self.serialize_unit()
+            self.formatter
+                .write_non_finite(&mut self.writer)
+                .map_err(Error::io)
         } else {
             self.formatter
                 .write_f64(&mut self.writer, value)
@@ -482,8 +488,9 @@ where
         unreachable!();
     }
 
-    fn serialize_bytes(self, _value: &[u8]) -> Result<()> {
-        unreachable!();
+    #[inline(always)]
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        self.ser.serialize_bytes(value)
     }
 
     fn serialize_unit(self) -> Result<()> {
@@ -582,3 +589,13 @@ where
     let mut ser = Serializer::pretty(writer);
     value.serialize(&mut ser)
 }
+
+#[inline]
+pub(crate) fn to_writer_canonical<W, T>(writer: W, value: &T) -> Result<()>
+where
+    W: WriteExt + bytes::BufMut,
+    T: ?Sized + Serialize,
+{
+    let mut ser = Serializer::with_formatter(writer, CanonicalFormatter);
+    value.serialize(&mut ser)
+}
@@ -2,22 +2,30 @@
 // Copyright ijl (2024-2026)
 
 mod byteswriter;
+mod cbor;
 mod format_str;
 mod formatter;
 mod half;
 mod json;
+mod msgpack;
 mod num;
 mod smallfixedbuffer;
 mod str;
 mod uuid;
 
 pub(crate) use byteswriter::{BytesWriter, WriteExt};
-pub(crate) use format_str::set_str_formatter_fn;
+pub(crate) use cbor::{
+    ByteString, TAG_DATETIME, TAG_DECIMAL_FRACTION, TAG_EPOCH_DATETIME,
+    TAG_MULTI_DIMENSIONAL_ARRAY, TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM, TAG_TYPED_ARRAY,
+    TAG_UUID, serialize_tagged, to_writer_cbor,
+};
+pub(crate) use format_str::{escape_str, set_str_formatter_fn};
 pub(crate) use half::f16_to_f32;
-pub(crate) use json::{to_writer, to_writer_pretty};
+pub(crate) use json::{to_writer, to_writer_canonical, to_writer_pretty};
+pub(crate) use msgpack::to_writer_msgpack;
 pub(crate) use num::{
-    write_float32, write_float64, write_integer_i32, write_integer_i64, write_integer_u32,
-    write_integer_u64,
+    write_float32, write_float64, write_float64_canonical, write_integer_i32, write_integer_i64,
+    write_integer_u32, write_integer_u64,
 };
 pub(crate) use smallfixedbuffer::SmallFixedBuffer;
 pub(crate) use uuid::format_hyphenated;
@@ -0,0 +1,489 @@
+// SPDX-License-Identifier: MPL-2.0
+// Copyright ijl (2026)
+
+// https://github.com/msgpack/msgpack/blob/master/spec.md
+
+use crate::serialize::error::SerializeError;
+use crate::serialize::writer::{BytesWriter, WriteExt};
+use bytes::BufMut;
+use serde::ser::{self, Impossible, Serialize};
+use serde_json::error::{Error, Result};
+
+const NIL: u8 = 0xc0;
+const FALSE: u8 = 0xc2;
+const TRUE: u8 = 0xc3;
+const FLOAT32: u8 = 0xca;
+const FLOAT64: u8 = 0xcb;
+const UINT8: u8 = 0xcc;
+const UINT16: u8 = 0xcd;
+const UINT32: u8 = 0xce;
+const UINT64: u8 = 0xcf;
+const INT8: u8 = 0xd0;
+const INT16: u8 = 0xd1;
+const INT32: u8 = 0xd2;
+const INT64: u8 = 0xd3;
+
+/// Formats of a type whose header gives its length.
+struct Family {
+    /// Marker of the fixed format, and the greatest length it holds.
+    fix: Option<(u8, usize)>,
+    marker8: Option<u8>,
+    marker16: u8,
+    marker32: u8,
+}
+
+const STR: Family = Family {
+    fix: Some((0xa0, 31)),
+    marker8: Some(0xd9),
+    marker16: 0xda,
+    marker32: 0xdb,
+};
+
+const BIN: Family = Family {
+    fix: None,
+    marker8: Some(0xc4),
+    marker16: 0xc5,
+    marker32: 0xc6,
+};
+
+const ARRAY: Family = Family {
+    fix: Some((0x90, 15)),
+    marker8: None,
+    marker16: 0xdc,
+    marker32: 0xdd,
+};
+
+const MAP: Family = Family {
+    fix: Some((0x80, 15)),
+    marker8: None,
+    marker16: 0xde,
+    marker32: 0xdf,
+};
+
+/// Length of the largest header.
+const HEADER_MAX: usize = 5;
+
+struct Header {
+    buf: [u8; HEADER_MAX],
+    len: usize,
+}
+
+impl Header {
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Family {
+    /// Return the smallest header of a value of `len` bytes or elements.
+    #[allow(clippy::cast_possible_truncation)]
+    fn header(&self, len: usize) -> Result<Header> {
+        let mut buf = [0; HEADER_MAX];
+        let header_len = match (self.fix, self.marker8) {
+            (Some((marker, max)), _) if len <= max => {
+                buf[0] = marker | len as u8;
+                1
+            }
+            (_, Some(marker)) if len <= usize::from(u8::MAX) => {
+                buf[0] = marker;
+                buf[1] = len as u8;
+                2
+            }
+            _ if len <= usize::from(u16::MAX) => {
+                buf[0] = self.marker16;
+                buf[1..3].copy_from_slice(&(len as u16).to_be_bytes());
+                3
+            }
+            _ => {
+                let Ok(len) = u32::try_from(len) else {
+                    cold_path!();
+                    return Err(ser::Error::custom(SerializeError::LengthExceeds32Bits));
+                };
+                buf[0] = self.marker32;
+                buf[1..5].copy_from_slice(&len.to_be_bytes());
+                5
+            }
+        };
+        Ok(Header {
+            buf: buf,
+            len: header_len,
+        })
+    }
+}
+
+pub(crate) struct Serializer<'w> {
+    writer: &'w mut BytesWriter,
+}
+
+impl<'w> Serializer<'w> {
+    #[inline]
+    pub fn new(writer: &'w mut BytesWriter) -> Self {
+        Serializer { writer: writer }
+    }
+
+    #[inline]
+    fn write_marker(&mut self, marker: u8) {
+        self.writer.reserve(HEADER_MAX * 2);
+        self.writer.put_u8(marker);
+    }
+
+    #[inline]
+    fn write_with_marker(&mut self, marker: u8, value: &[u8]) {
+        self.writer.reserve(HEADER_MAX * 2 + value.len());
+        self.writer.put_u8(marker);
+        self.writer.put_slice(value);
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_u64(&mut self, value: u64) {
+        if value < 0x80 {
+            self.write_marker(value as u8);
+        } else if value <= u64::from(u8::MAX) {
+            self.write_with_marker(UINT8, &[value as u8]);
+        } else if value <= u64::from(u16::MAX) {
+            self.write_with_marker(UINT16, &(value as u16).to_be_bytes());
+        } else if value <= u64::from(u32::MAX) {
+            self.write_with_marker(UINT32, &(value as u32).to_be_bytes());
+        } else {
+            self.write_with_marker(UINT64, &value.to_be_bytes());
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_i64(&mut self, value: i64) {
+        if value >= 0 {
+            self.write_u64(value.cast_unsigned());
+        } else if value >= -32 {
+            // negative fixint
+            self.write_marker((value as i8).cast_unsigned());
+        } else if value >= i64::from(i8::MIN) {
+            self.write_with_marker(INT8, &(value as i8).to_be_bytes());
+        } else if value >= i64::from(i16::MIN) {
+            self.write_with_marker(INT16, &(value as i16).to_be_bytes());
+        } else if value >= i64::from(i32::MIN) {
+            self.write_with_marker(INT32, &(value as i32).to_be_bytes());
+        } else {
+            self.write_with_marker(INT64, &value.to_be_bytes());
+        }
+    }
+
+    #[inline]
+    fn write_sized(&mut self, family: &Family, value: &[u8]) -> Result<()> {
+        let header = family.header(value.len())?;
+        self.writer.reserve(HEADER_MAX * 2 + value.len());
+        self.writer.put_slice(header.as_slice());
+        self.writer.put_slice(value);
+        Ok(())
+    }
+
+    /// Begin an array or map of `len` elements, or, if it is not known,
+    /// write the largest header to be replaced when it is.
+    #[inline]
+    fn begin<'a>(
+        &'a mut self,
+        family: &'static Family,
+        len: Option<usize>,
+    ) -> Result<Compound<'a, 'w>> {
+        self.writer.reserve(HEADER_MAX * 2);
+        let start = match len {
+            Some(len) => {
+                let header = family.header(len)?;
+                self.writer.put_slice(header.as_slice());
+                None
+            }
+            None => {
+                let start = self.writer.position();
+                self.writer.put_u8(family.marker32);
+                self.writer.put_bytes(0, HEADER_MAX - 1);
+                Some(start)
+            }
+        };
+        Ok(Compound {
+            ser: self,
+            family: family,
+            start: start,
+            len: 0,
+        })
+    }
+}
+
+impl<'a, 'w> ser::Serializer for &'a mut Serializer<'w> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Compound<'a, 'w>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Compound<'a, 'w>;
+    type SerializeStruct = Impossible<(), Error>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        self.write_marker(core::hint::select_unpredictable(value, TRUE, FALSE));
+        Ok(())
+    }
+
+    fn serialize_i8(self, _value: i8) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_i16(self, _value: i16) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.write_i64(i64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        self.write_i64(value);
+        Ok(())
+    }
+
+    fn serialize_i128(self, _value: i128) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_u8(self, _value: u8) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_u16(self, _value: u16) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.write_u64(u64::from(value));
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.write_u64(value);
+        Ok(())
+    }
+
+    fn serialize_u128(self, _value: u128) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.write_with_marker(FLOAT32, &value.to_be_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        self.write_with_marker(FLOAT64, &value.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, _value: char) -> Result<()> {
+        unreachable!();
+    }
+
+    #[inline(always)]
+    fn serialize_str(self, value: &str) -> Result<()> {
+        self.write_sized(&STR, value.as_bytes())
+    }
+
+    #[inline]
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        self.write_sized(&BIN, value)
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<()> {
+        self.write_marker(NIL);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn serialize_unit_struct(self, name: &'static str) -> Result<()> {
+        self.write_sized(&STR, name.as_bytes())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        unreachable!();
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        unreachable!();
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        unreachable!();
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    #[inline]
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    #[inline(always)]
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        self.begin(&ARRAY, len)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        unreachable!();
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        unreachable!();
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unreachable!();
+    }
+
+    #[inline(always)]
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        self.begin(&MAP, len)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        unreachable!();
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unreachable!();
+    }
+}
+
+pub(crate) struct Compound<'a, 'w> {
+    ser: &'a mut Serializer<'w>,
+    family: &'static Family,
+    /// Position of the header to replace, if the length was not known.
+    start: Option<usize>,
+    len: usize,
+}
+
+impl Compound<'_, '_> {
+    #[inline]
+    fn end(self) -> Result<()> {
+        if let Some(start) = self.start {
+            let header = self.family.header(self.len)?;
+            self.ser.writer.splice(start, HEADER_MAX, header.as_slice());
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.len += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_entry<K, V>(&mut self, _key: &K, _value: &V) -> Result<()>
+    where
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
+    {
+        unreachable!()
+    }
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.len += 1;
+        key.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Compound::end(self)
+    }
+}
+
+#[inline]
+pub(crate) fn to_writer_msgpack<T>(writer: &mut BytesWriter, value: &T) -> Result<()>
+where
+    T: ?Sized + Serialize,
+{
+    let mut ser = Serializer::new(writer);
+    value.serialize(&mut ser)
+}
@@ -2,6 +2,7 @@
 // Copyright ijl (2026)
 
 use crate::serialize::writer::WriteExt;
+use crate::util::{isize_to_usize, usize_to_isize};
 use bytes::BufMut;
 
 #[inline]
@@ -85,3 +86,93 @@ where
         buf.advance_mut(res.len());
     }
 }
+
+/// Write a finite `f64` as ECMAScript's `Number.prototype.toString()` does,
+/// which RFC 8785 requires: the shortest round-trip digits, no fraction or
+/// exponent for integral values below 1e21, and a signed exponent otherwise
+/// outside `[1e-6, 1e21)`.
+pub(crate) fn write_float64_canonical<B>(buf: &mut B, val: f64)
+where
+    B: ?Sized + WriteExt + BufMut,
+{
+    debug_assert!(val.is_finite());
+    debug_assert!(buf.remaining_mut() >= 40);
+    if val == 0.0 {
+        // including -0.0
+        buf.put_u8(b'0');
+        return;
+    }
+    if val.is_sign_negative() {
+        buf.put_u8(b'-');
+    }
+    let mut shortest = zmij::Buffer::new();
+    let text = shortest.format_finite(val.abs()).as_bytes();
+    let (mantissa, exponent) = match text.iter().position(|&ch| ch == b'e') {
+        Some(idx) => (&text[..idx], parse_exponent(&text[idx + 1..])),
+        None => (text, 0),
+    };
+
+    // significant digits without leading or trailing zeros and the number of
+    // digits before the decimal point, which is negative for leading zeros
+    // in the fraction
+    let mut digits = [0u8; 32];
+    let mut len = 0;
+    let mut point: isize = 0;
+    let mut fraction = false;
+    for &ch in mantissa {
+        if ch == b'.' {
+            fraction = true;
+        } else if len == 0 && ch == b'0' {
+            if fraction {
+                point -= 1;
+            }
+        } else {
+            digits[len] = ch;
+            len += 1;
+            if !fraction {
+                point += 1;
+            }
+        }
+    }
+    while digits[len - 1] == b'0' {
+        len -= 1;
+    }
+    let digits = &digits[..len];
+    let k = usize_to_isize(len);
+    let n = point + exponent;
+
+    if k <= n && n <= 21 {
+        buf.put_slice(digits);
+        buf.put_bytes(b'0', isize_to_usize(n - k));
+    } else if 0 < n && n <= 21 {
+        let (integral, fractional) = digits.split_at(isize_to_usize(n));
+        buf.put_slice(integral);
+        buf.put_u8(b'.');
+        buf.put_slice(fractional);
+    } else if -6 < n && n <= 0 {
+        buf.put_slice(b"0.");
+        buf.put_bytes(b'0', isize_to_usize(-n));
+        buf.put_slice(digits);
+    } else {
+        buf.put_u8(digits[0]);
+        if len > 1 {
+            buf.put_u8(b'.');
+            buf.put_slice(&digits[1..]);
+        }
+        buf.put_u8(b'e');
+        buf.put_u8(if n < 1 { b'-' } else { b'+' });
+        write_integer(buf, (n - 1).unsigned_abs());
+    }
+}
+
+fn parse_exponent(text: &[u8]) -> isize {
+    let (negative, text) = match text.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, text),
+    };
+    let val = text
+        .iter()
+        .fold(0, |acc, &ch| acc * 10 + isize::from(ch - b'0'));
+    if negative { -val } else { val }
+}
@@ -6,6 +6,9 @@ use core::ptr::{NonNull, null_mut};
 use once_cell::race::OnceBox;
 use std::sync::OnceLock;
 
+use crate::deserialize::{
+    orjson_decodertype_new, orjson_itemsiteratortype_new, orjson_nodetype_new,
+};
 use crate::ffi::{
     Py_DECREF, Py_False, Py_INCREF, Py_None, Py_True, Py_XDECREF, PyBool_Type, PyBytes_Type,
     PyDict_Type, PyErr_Clear, PyErr_NewException, PyExc_TypeError, PyFloat_Type,
@@ -16,11 +19,16 @@ use crate::ffi::{
 
 pub(crate) static mut DEFAULT: *mut PyObject = null_mut();
 pub(crate) static mut OPTION: *mut PyObject = null_mut();
+pub(crate) static mut IDX: *mut PyObject = null_mut();
+pub(crate) static mut PREFIX: *mut PyObject = null_mut();
+pub(crate) static mut POINTER: *mut PyObject = null_mut();
+pub(crate) static mut KEYS: *mut PyObject = null_mut();
 
 pub(crate) static mut NONE: *mut PyObject = null_mut();
 pub(crate) static mut TRUE: *mut PyObject = null_mut();
 pub(crate) static mut FALSE: *mut PyObject = null_mut();
 pub(crate) static mut EMPTY_UNICODE: *mut PyObject = null_mut();
+pub(crate) static mut DATACLASS_MISSING: *mut PyObject = null_mut();
 
 pub(crate) static mut BYTES_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut STR_TYPE: *mut PyTypeObject = null_mut();
@@ -38,6 +46,9 @@ pub(crate) static mut UUID_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut ENUM_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut FIELD_TYPE: *mut PyTypeObject = null_mut();
 pub(crate) static mut FRAGMENT_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut DECODER_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut NODE_TYPE: *mut PyTypeObject = null_mut();
+pub(crate) static mut ITEMS_ITERATOR_TYPE: *mut PyTypeObject = null_mut();
 
 pub(crate) static mut ZONEINFO_TYPE: *mut PyTypeObject = null_mut();
 
@@ -48,13 +59,30 @@ pub(crate) static mut DST_STR: *mut PyObject = null_mut();
 
 pub(crate) static mut DICT_STR: *mut PyObject = null_mut();
 pub(crate) static mut DATACLASS_FIELDS_STR: *mut PyObject = null_mut();
+pub(crate) static mut ATTRS_ATTRS_STR: *mut PyObject = null_mut();
 pub(crate) static mut SLOTS_STR: *mut PyObject = null_mut();
 pub(crate) static mut FIELD_TYPE_STR: *mut PyObject = null_mut();
+pub(crate) static mut DEFAULT_FACTORY_STR: *mut PyObject = null_mut();
+pub(crate) static mut FACTORY_STR: *mut PyObject = null_mut();
+pub(crate) static mut TAKES_SELF_STR: *mut PyObject = null_mut();
+pub(crate) static mut FIELDS_STR: *mut PyObject = null_mut();
+pub(crate) static mut FIELD_DEFAULTS_STR: *mut PyObject = null_mut();
+pub(crate) static mut METADATA_STR: *mut PyObject = null_mut();
+pub(crate) static mut ORJSON_STR: *mut PyObject = null_mut();
+pub(crate) static mut ORJSON_METHOD_STR: *mut PyObject = null_mut();
+pub(crate) static mut INCLUDE_STR: *mut PyObject = null_mut();
+pub(crate) static mut NAME_STR: *mut PyObject = null_mut();
+pub(crate) static mut SKIP_STR: *mut PyObject = null_mut();
 pub(crate) static mut ARRAY_STRUCT_STR: *mut PyObject = null_mut();
 pub(crate) static mut DTYPE_STR: *mut PyObject = null_mut();
 pub(crate) static mut DESCR_STR: *mut PyObject = null_mut();
 pub(crate) static mut VALUE_STR: *mut PyObject = null_mut();
 pub(crate) static mut INT_ATTR_STR: *mut PyObject = null_mut();
+pub(crate) static mut ITEM_STR: *mut PyObject = null_mut();
+pub(crate) static mut OP_STR: *mut PyObject = null_mut();
+pub(crate) static mut PATH_STR: *mut PyObject = null_mut();
+pub(crate) static mut FROM_STR: *mut PyObject = null_mut();
+pub(crate) static mut AS_TUPLE_STR: *mut PyObject = null_mut();
 
 #[allow(non_upper_case_globals)]
 pub(crate) static mut JsonEncodeError: *mut PyObject = null_mut();
@@ -113,7 +141,7 @@ pub(crate) fn init_typerefs() {
 #[cfg_attr(feature = "optimize", optimize(size))]
 fn _init_typerefs_impl() -> bool {
     unsafe {
-        debug_assert!(crate::opt::MAX_OPT < i32::from(u16::MAX));
+        debug_assert!(crate::opt::MAX_OPT.cast_unsigned() & crate::opt::CBOR == 0);
 
         #[cfg(not(Py_GIL_DISABLED))]
         assert!(
@@ -121,6 +149,30 @@ fn _init_typerefs_impl() -> bool {
                 .set(crate::deserialize::KeyMap::default())
                 .is_ok()
         );
+        #[cfg(not(Py_GIL_DISABLED))]
+        assert!(
+            crate::serialize::DATACLASS_PLAN_MAP
+                .set(crate::serialize::DataclassPlanMap::default())
+                .is_ok()
+        );
+        #[cfg(not(Py_GIL_DISABLED))]
+        assert!(
+            crate::serialize::METHOD_MAP
+                .set(crate::serialize::MethodMap::default())
+                .is_ok()
+        );
+        #[cfg(not(Py_GIL_DISABLED))]
+        assert!(
+            crate::serialize::CASE_KEY_MAP
+                .set(crate::serialize::CaseKeyMap::default())
+                .is_ok()
+        );
+        #[cfg(not(Py_GIL_DISABLED))]
+        assert!(
+            crate::deserialize::SNAKE_CASE_KEY_MAP
+                .set(crate::deserialize::KeyMap::default())
+                .is_ok()
+        );
 
         crate::serialize::set_str_formatter_fn();
         crate::ffi::set_str_create_fn();
@@ -145,8 +197,12 @@ fn _init_typerefs_impl() -> bool {
         UUID_TYPE = look_up_type_object(c"uuid", c"UUID");
         ENUM_TYPE = look_up_type_object(c"enum", c"EnumMeta");
         FIELD_TYPE = look_up_type_object(c"dataclasses", c"_FIELD");
+        DATACLASS_MISSING = look_up_type_object(c"dataclasses", c"MISSING").cast::<PyObject>();
 
         FRAGMENT_TYPE = orjson_fragmenttype_new();
+        DECODER_TYPE = orjson_decodertype_new();
+        NODE_TYPE = orjson_nodetype_new();
+        ITEMS_ITERATOR_TYPE = orjson_itemsiteratortype_new();
 
         INT_ATTR_STR = PyUnicode_InternFromString(c"int".as_ptr());
         UTCOFFSET_METHOD_STR = PyUnicode_InternFromString(c"utcoffset".as_ptr());
@@ -155,14 +211,35 @@ fn _init_typerefs_impl() -> bool {
         DST_STR = PyUnicode_InternFromString(c"dst".as_ptr());
         DICT_STR = PyUnicode_InternFromString(c"__dict__".as_ptr());
         DATACLASS_FIELDS_STR = PyUnicode_InternFromString(c"__dataclass_fields__".as_ptr());
+        ATTRS_ATTRS_STR = PyUnicode_InternFromString(c"__attrs_attrs__".as_ptr());
         SLOTS_STR = PyUnicode_InternFromString(c"__slots__".as_ptr());
         FIELD_TYPE_STR = PyUnicode_InternFromString(c"_field_type".as_ptr());
+        DEFAULT_FACTORY_STR = PyUnicode_InternFromString(c"default_factory".as_ptr());
+        FACTORY_STR = PyUnicode_InternFromString(c"factory".as_ptr());
+        TAKES_SELF_STR = PyUnicode_InternFromString(c"takes_self".as_ptr());
+        FIELDS_STR = PyUnicode_InternFromString(c"_fields".as_ptr());
+        FIELD_DEFAULTS_STR = PyUnicode_InternFromString(c"_field_defaults".as_ptr());
+        METADATA_STR = PyUnicode_InternFromString(c"metadata".as_ptr());
+        ORJSON_STR = PyUnicode_InternFromString(c"orjson".as_ptr());
+        ORJSON_METHOD_STR = PyUnicode_InternFromString(c"__orjson__".as_ptr());
+        INCLUDE_STR = PyUnicode_InternFromString(c"include".as_ptr());
+        NAME_STR = PyUnicode_InternFromString(c"name".as_ptr());
+        SKIP_STR = PyUnicode_InternFromString(c"skip".as_ptr());
         ARRAY_STRUCT_STR = PyUnicode_InternFromString(c"__array_struct__".as_ptr());
         DTYPE_STR = PyUnicode_InternFromString(c"dtype".as_ptr());
         DESCR_STR = PyUnicode_InternFromString(c"descr".as_ptr());
         VALUE_STR = PyUnicode_InternFromString(c"value".as_ptr());
         DEFAULT = PyUnicode_InternFromString(c"default".as_ptr());
         OPTION = PyUnicode_InternFromString(c"option".as_ptr());
+        IDX = PyUnicode_InternFromString(c"idx".as_ptr());
+        PREFIX = PyUnicode_InternFromString(c"prefix".as_ptr());
+        POINTER = PyUnicode_InternFromString(c"pointer".as_ptr());
+        KEYS = PyUnicode_InternFromString(c"keys".as_ptr());
+        ITEM_STR = PyUnicode_InternFromString(c"item".as_ptr());
+        OP_STR = PyUnicode_InternFromString(c"op".as_ptr());
+        PATH_STR = PyUnicode_InternFromString(c"path".as_ptr());
+        FROM_STR = PyUnicode_InternFromString(c"from".as_ptr());
+        AS_TUPLE_STR = PyUnicode_InternFromString(c"as_tuple".as_ptr());
 
         JsonEncodeError = PyExc_TypeError;
         Py_INCREF(JsonEncodeError);
@@ -243,3 +320,34 @@ pub(crate) fn load_numpy_types() -> Box<Option<NonNull<NumpyTypes>>> {
         Box::new(Some(nonnull!(Box::<NumpyTypes>::into_raw(types))))
     }
 }
+
+pub(crate) static mut DECIMAL_TYPE: OnceBox<NonNull<PyTypeObject>> = OnceBox::new();
+
+/// `decimal` is imported only once CBOR needs it.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn load_decimal_type() -> Box<NonNull<PyTypeObject>> {
+    unsafe { Box::new(nonnull!(look_up_type_object(c"decimal", c"Decimal"))) }
+}
+
+pub(crate) struct AttrsTypes {
+    pub nothing: *mut PyObject,
+    pub factory: *mut PyTypeObject,
+    pub attribute: *mut PyTypeObject,
+}
+
+pub(crate) static mut ATTRS_TYPES: OnceBox<AttrsTypes> = OnceBox::new();
+
+/// `attr` is imported only once an attrs class is serialized, by which time
+/// it has already been imported.
+#[cold]
+#[cfg_attr(feature = "optimize", optimize(size))]
+pub(crate) fn load_attrs_types() -> Box<AttrsTypes> {
+    unsafe {
+        Box::new(AttrsTypes {
+            nothing: look_up_type_object(c"attr", c"NOTHING").cast::<PyObject>(),
+            factory: look_up_type_object(c"attr", c"Factory"),
+            attribute: look_up_type_object(c"attr", c"Attribute"),
+        })
+    }
+}
@@ -0,0 +1,311 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import gc
+import weakref
+
+import pytest
+
+import orjson
+
+from .util import attrs
+
+if attrs is not None:
+    import attr
+
+    @attrs.define
+    class SlotsClass:
+        a: int
+        b: str = "b"
+        _c: int = 3
+
+    @attrs.define(slots=False)
+    class DictClass:
+        a: int
+        b: str = "b"
+        _c: int = 3
+
+    @attrs.frozen
+    class FrozenClass:
+        a: int
+        nested: object = None
+
+    @attrs.define(slots=False)
+    class InitFalseClass:
+        a: int
+        b: int = attrs.field(init=False, default=2)
+        c: int = attrs.field(init=False)
+
+    @attrs.define
+    class InitFalseSlotsClass:
+        a: int
+        c: int = attrs.field(init=False)
+
+    @attrs.define(slots=False)
+    class PostInitClass:
+        a: int
+
+        def __attrs_post_init__(self):
+            self.extra = 1
+
+    @attrs.define
+    class MetadataClass:
+        user_id: int = attrs.field(metadata={"orjson": {"name": "userId"}})
+        password: str = attrs.field(default="", metadata={"orjson": {"skip": True}})
+        _token: str = attrs.field(default="t", metadata={"orjson": {"include": True}})
+
+    @attrs.define
+    class DefaultsClass:
+        a: int = 1
+        b: list = attrs.field(factory=list)
+        c: dict = attrs.Factory(dict)
+        d: object = None
+
+    @attrs.define
+    class Base:
+        a: int
+
+    @attrs.define
+    class Derived(Base):
+        b: int
+
+    @attr.s
+    class ClassicClass:
+        a = attr.ib()
+        _b = attr.ib(default=2)
+
+
+class InvalidAttributes:
+    __attrs_attrs__ = 5
+
+    def __init__(self):
+        self.a = 1
+
+
+class EmptyAttributes:
+    __attrs_attrs__ = ()
+
+    def __init__(self):
+        self.a = 1
+
+
+class TupleAttributes(tuple):
+    __attrs_attrs__ = (1, 2)
+
+
+@pytest.mark.skipif(attrs is None, reason="attrs is not installed")
+class TestAttrs:
+    def test_attrs_slots(self):
+        """
+        attrs class using __slots__
+        """
+        assert orjson.dumps(SlotsClass(1)) == b'{"a":1,"b":"b"}'
+
+    def test_attrs_dict(self):
+        """
+        attrs class using __dict__
+        """
+        assert orjson.dumps(DictClass(1)) == b'{"a":1,"b":"b"}'
+        assert orjson.dumps(DictClass(1, "c", 4)) == b'{"a":1,"b":"c"}'
+
+    def test_attrs_frozen(self):
+        """
+        frozen attrs class and nesting
+        """
+        obj = FrozenClass(1, [SlotsClass(2), {"k": DictClass(3)}])
+        assert orjson.dumps(obj) == (
+            b'{"a":1,"nested":[{"a":2,"b":"b"},{"k":{"a":3,"b":"b"}}]}'
+        )
+
+    def test_attrs_classic(self):
+        """
+        attrs class using attr.s and attr.ib
+        """
+        assert orjson.dumps(ClassicClass(1)) == b'{"a":1}'
+
+    def test_attrs_inheritance(self):
+        """
+        attrs class fields of the base class are first
+        """
+        assert orjson.dumps(Derived(1, 2)) == b'{"a":1,"b":2}'
+
+    def test_attrs_init_false(self):
+        """
+        attrs field with init=False is serialized if it is set
+        """
+        obj = InitFalseClass(1)
+        assert orjson.dumps(obj) == b'{"a":1,"b":2}'
+        obj.c = 3
+        assert orjson.dumps(obj) == b'{"a":1,"b":2,"c":3}'
+
+    def test_attrs_init_false_slots(self):
+        """
+        attrs class using __slots__ with a field with init=False
+        """
+        obj = InitFalseSlotsClass(1)
+        assert orjson.dumps(obj) == b'{"a":1}'
+        obj.c = 3
+        assert orjson.dumps(obj) == b'{"a":1,"c":3}'
+
+    def test_attrs_post_init(self):
+        """
+        attrs class attribute that is not a field
+        """
+        assert orjson.dumps(PostInitClass(1)) == b'{"a":1,"extra":1}'
+
+    def test_attrs_underscore(self):
+        """
+        attrs field whose name begins with an underscore
+        """
+        assert (
+            orjson.dumps(SlotsClass(1), option=orjson.OPT_SERIALIZE_UNDERSCORE)
+            == b'{"a":1,"b":"b","_c":3}'
+        )
+        assert (
+            orjson.dumps(DictClass(1), option=orjson.OPT_SERIALIZE_UNDERSCORE)
+            == b'{"a":1,"b":"b","_c":3}'
+        )
+
+    def test_attrs_metadata(self):
+        """
+        attrs field renamed, skipped, or included using metadata
+        """
+        assert (
+            orjson.dumps(MetadataClass(1, "hunter2"))
+            == b'{"userId":1,"_token":"t"}'
+        )
+
+    def test_attrs_metadata_invalid(self):
+        """
+        attrs field metadata that is not a dict
+        """
+
+        @attrs.define
+        class InvalidMetadataClass:
+            a: int = attrs.field(metadata={"orjson": "a"})
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(InvalidMetadataClass(1))
+
+    def test_attrs_omit_defaults(self):
+        """
+        attrs class OPT_OMIT_DEFAULTS
+        """
+        assert orjson.dumps(DefaultsClass()) == b'{"a":1,"b":[],"c":{},"d":null}'
+        assert orjson.dumps(DefaultsClass(), option=orjson.OPT_OMIT_DEFAULTS) == b"{}"
+        assert (
+            orjson.dumps(DefaultsClass(2, [1], {}, 0), option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"a":2,"b":[1],"d":0}'
+        )
+
+    def test_attrs_options(self):
+        """
+        attrs class OPT_OMIT_NONE, OPT_CAMEL_CASE_KEYS, OPT_CANONICAL
+        """
+        assert (
+            orjson.dumps(FrozenClass(1), option=orjson.OPT_OMIT_NONE) == b'{"a":1}'
+        )
+        assert (
+            orjson.dumps(MetadataClass(1), option=orjson.OPT_CAMEL_CASE_KEYS)
+            == b'{"userId":1,"_token":"t"}'
+        )
+        assert (
+            orjson.dumps(FrozenClass(1, 2), option=orjson.OPT_CANONICAL)
+            == b'{"a":1,"nested":2}'
+        )
+        assert (
+            orjson.dumps(DictClass(1), option=orjson.OPT_CANONICAL)
+            == b'{"a":1,"b":"b"}'
+        )
+
+    def test_attrs_passthrough(self):
+        """
+        attrs class OPT_PASSTHROUGH_DATACLASS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(SlotsClass(1), option=orjson.OPT_PASSTHROUGH_DATACLASS)
+        assert (
+            orjson.dumps(
+                SlotsClass(1),
+                option=orjson.OPT_PASSTHROUGH_DATACLASS,
+                default=lambda obj: obj.a,
+            )
+            == b"1"
+        )
+
+    def test_attrs_invalid_attributes(self):
+        """
+        class with __attrs_attrs__ not a tuple is not an attrs class
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(InvalidAttributes())
+        assert orjson.dumps(InvalidAttributes(), default=vars) == b'{"a":1}'
+
+    def test_attrs_hand_written_attributes(self):
+        """
+        class with __attrs_attrs__ not of attr.Attribute is not an attrs class
+        """
+        for obj in (EmptyAttributes(), TupleAttributes()):
+            with pytest.raises(orjson.JSONEncodeError, match="not JSON serializable"):
+                orjson.dumps(obj)
+            assert orjson.dumps(obj, default=lambda obj: "default") == b'"default"'
+
+    def test_attrs_hand_written_attributes_modified(self):
+        """
+        class found not to be an attrs class is inspected again if modified
+        """
+
+        class Modified:
+            __attrs_attrs__ = ()
+
+            def __init__(self):
+                self.a = 1
+                self.b = "b"
+
+        for _ in range(2):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(Modified())
+        Modified.__attrs_attrs__ = DictClass.__attrs_attrs__  # type: ignore
+        assert orjson.dumps(Modified()) == b'{"a":1,"b":"b"}'
+
+    def test_attrs_empty(self):
+        """
+        attrs class without fields
+        """
+
+        @attrs.define
+        class Empty:
+            pass
+
+        assert orjson.dumps(Empty()) == b"{}"
+
+    def test_attrs_msgpack(self):
+        """
+        dumps_msgpack() attrs class
+        """
+        assert orjson.loads_msgpack(orjson.dumps_msgpack(DictClass(1))) == {
+            "a": 1,
+            "b": "b",
+        }
+
+    def test_attrs_non_str_key(self):
+        """
+        attrs instance as a dict key with OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({FrozenClass(1): 1}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_attrs_class_freed(self):
+        """
+        attrs class is not kept alive by serializing it
+        """
+
+        @attrs.define
+        class Temporary:
+            a: int
+
+        assert orjson.dumps(Temporary(1)) == b'{"a":1}'
+        ref = weakref.ref(Temporary)
+        del Temporary
+        gc.collect()
+        assert ref() is None
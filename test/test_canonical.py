@@ -1,8 +1,47 @@
 # SPDX-License-Identifier: MPL-2.0
 # Copyright ijl (2019-2022)
 
+import dataclasses
+import struct
+
+import pytest
+
 import orjson
 
+from .util import numpy
+
+# https://www.rfc-editor.org/rfc/rfc8785#appendix-B
+NUMBERS = (
+    (0x0000000000000000, b"0"),
+    (0x8000000000000000, b"0"),
+    (0x0000000000000001, b"5e-324"),
+    (0x8000000000000001, b"-5e-324"),
+    (0x7FEFFFFFFFFFFFFF, b"1.7976931348623157e+308"),
+    (0xFFEFFFFFFFFFFFFF, b"-1.7976931348623157e+308"),
+    (0x4340000000000000, b"9007199254740992"),
+    (0xC340000000000000, b"-9007199254740992"),
+    (0x4430000000000000, b"295147905179352830000"),
+    (0x44B52D02C7E14AF5, b"9.999999999999997e+22"),
+    (0x44B52D02C7E14AF6, b"1e+23"),
+    (0x44B52D02C7E14AF7, b"1.0000000000000001e+23"),
+    (0x444B1AE4D6E2EF4E, b"999999999999999700000"),
+    (0x444B1AE4D6E2EF4F, b"999999999999999900000"),
+    (0x444B1AE4D6E2EF50, b"1e+21"),
+    (0x3EB0C6F7A0B5ED8C, b"9.999999999999997e-7"),
+    (0x3EB0C6F7A0B5ED8D, b"0.000001"),
+    (0x41B3DE4355555553, b"333333333.3333332"),
+    (0x41B3DE4355555554, b"333333333.33333325"),
+    (0x41B3DE4355555555, b"333333333.3333333"),
+    (0x41B3DE4355555556, b"333333333.3333334"),
+    (0x41B3DE4355555557, b"333333333.33333343"),
+    (0xBECBF647612F3696, b"-0.0000033333333333333333"),
+    (0x43143FF3C1CB0959, b"1424953923781206.2"),
+)
+
+
+def from_bits(bits):
+    return struct.unpack(">d", bits.to_bytes(8, "big"))[0]
+
 
 class TestCanonicalTests:
     def test_dumps_ctrl_escape(self):
@@ -25,3 +64,232 @@ class TestCanonicalTests:
             orjson.dumps({"spaces": "\u2028 \u2029"})
             == b'{"spaces":"\xe2\x80\xa8 \xe2\x80\xa9"}'
         )
+
+
+@dataclasses.dataclass
+class Dataclass:
+    zeta: int
+    alpha: str
+    _private: int = 0
+
+
+@dataclasses.dataclass
+class SlotsDataclass:
+    __slots__ = ("b", "a")
+    b: float
+    a: int
+
+
+class TestCanonical:
+    @pytest.mark.parametrize("bits,expected", NUMBERS)
+    def test_canonical_number(self, bits, expected):
+        """
+        OPT_CANONICAL serializes floats as RFC 8785 appendix B
+        """
+        assert orjson.dumps(from_bits(bits), option=orjson.OPT_CANONICAL) == expected
+
+    def test_canonical_number_layout(self):
+        """
+        OPT_CANONICAL integral, fractional, and exponent float layout
+        """
+        assert (
+            orjson.dumps(
+                [1.0, 100.0, 0.5, 4.25, 1e-6, 1e-7, 1.5e-7, 1e20, 1e21, 1.25e21],
+                option=orjson.OPT_CANONICAL,
+            )
+            == b"[1,100,0.5,4.25,0.000001,1e-7,1.5e-7,100000000000000000000,1e+21,1.25e+21]"
+        )
+
+    def test_canonical_integer(self):
+        """
+        OPT_CANONICAL integers within 53 bits
+        """
+        assert (
+            orjson.dumps(
+                [0, -1, 9007199254740991, -9007199254740991],
+                option=orjson.OPT_CANONICAL,
+            )
+            == b"[0,-1,9007199254740991,-9007199254740991]"
+        )
+
+    @pytest.mark.parametrize(
+        "value",
+        (9007199254740992, -9007199254740992, 18446744073709551615),
+    )
+    def test_canonical_integer_53_bits(self, value):
+        """
+        OPT_CANONICAL raises on integers beyond 53 bits
+        """
+        with pytest.raises(orjson.JSONEncodeError, match="53-bit"):
+            orjson.dumps(value, option=orjson.OPT_CANONICAL)
+
+    @pytest.mark.parametrize("value", (float("nan"), float("inf"), float("-inf")))
+    def test_canonical_not_finite(self, value):
+        """
+        OPT_CANONICAL raises on nan and infinity
+        """
+        with pytest.raises(orjson.JSONEncodeError, match="finite"):
+            orjson.dumps([value], option=orjson.OPT_CANONICAL)
+
+    def test_canonical_sort_utf16(self):
+        """
+        OPT_CANONICAL sorts keys by UTF-16 code units per RFC 8785 3.2.3
+        """
+        obj = {
+            "€": "Euro Sign",
+            "\r": "Carriage Return",
+            "דּ": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\U0001f600": "Emoji: Grinning Face",
+            "\u0080": "Control",
+            "ö": "Latin Small Letter O With Diaeresis",
+        }
+        assert list(orjson.loads(orjson.dumps(obj, option=orjson.OPT_CANONICAL))) == [
+            "\r",
+            "1",
+            "\u0080",
+            "ö",
+            "€",
+            "\U0001f600",
+            "דּ",
+        ]
+
+    def test_canonical_sort_differs_from_sort_keys(self):
+        """
+        OPT_CANONICAL sorts supplementary characters before U+E000-U+FFFF
+        """
+        obj = {"דּ": 1, "\U0001f600": 2}
+        assert orjson.dumps(obj, option=orjson.OPT_CANONICAL) == (
+            b'{"\xf0\x9f\x98\x80":2,"\xef\xac\xb3":1}'
+        )
+        assert orjson.dumps(obj, option=orjson.OPT_SORT_KEYS) == (
+            b'{"\xef\xac\xb3":1,"\xf0\x9f\x98\x80":2}'
+        )
+
+    def test_canonical_nested(self):
+        """
+        OPT_CANONICAL sorts nested maps
+        """
+        assert (
+            orjson.dumps(
+                {"b": [{"d": 1, "c": 2.0}], "a": {"f": None, "e": True}},
+                option=orjson.OPT_CANONICAL,
+            )
+            == b'{"a":{"e":true,"f":null},"b":[{"c":2,"d":1}]}'
+        )
+
+    def test_canonical_str(self):
+        """
+        OPT_CANONICAL escapes strings minimally
+        """
+        assert (
+            orjson.dumps(
+                "\x00\x08\t\n\x0c\r\x1f\"\\/\x7fé \U0001f600",
+                option=orjson.OPT_CANONICAL,
+            )
+            == b'"\\u0000\\b\\t\\n\\f\\r\\u001f\\"\\\\/\x7f\xc3\xa9\xe2\x80\xa8\xf0\x9f\x98\x80"'
+        )
+
+    def test_canonical_dataclass(self):
+        """
+        OPT_CANONICAL sorts dataclass fields
+        """
+        assert (
+            orjson.dumps(Dataclass(1, "a"), option=orjson.OPT_CANONICAL)
+            == b'{"alpha":"a","zeta":1}'
+        )
+        assert orjson.dumps(Dataclass(1, "a")) == b'{"zeta":1,"alpha":"a"}'
+
+    def test_canonical_dataclass_slots(self):
+        """
+        OPT_CANONICAL sorts fields of dataclass with __slots__
+        """
+        assert (
+            orjson.dumps(SlotsDataclass(1.0, 2), option=orjson.OPT_CANONICAL)
+            == b'{"a":2,"b":1}'
+        )
+
+    def test_canonical_non_str_keys(self):
+        """
+        OPT_CANONICAL sorts keys serialized by OPT_NON_STR_KEYS as str
+        """
+        assert (
+            orjson.dumps(
+                {10: "a", 2: "b", 1e21: "c", "1": "d", None: "e"},
+                option=orjson.OPT_CANONICAL | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":"d","10":"a","1e+21":"c","2":"b","null":"e"}'
+        )
+
+    def test_canonical_non_str_keys_without_option(self):
+        """
+        OPT_CANONICAL requires str keys without OPT_NON_STR_KEYS
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({1: 1}, option=orjson.OPT_CANONICAL)
+
+    def test_canonical_indent(self):
+        """
+        OPT_CANONICAL is not compatible with OPT_INDENT_2
+        """
+        with pytest.raises(orjson.JSONEncodeError, match="Invalid opts"):
+            orjson.dumps({}, option=orjson.OPT_CANONICAL | orjson.OPT_INDENT_2)
+
+    def test_canonical_append_newline(self):
+        """
+        OPT_CANONICAL with OPT_APPEND_NEWLINE
+        """
+        assert (
+            orjson.dumps(
+                {"b": 1, "a": 2},
+                option=orjson.OPT_CANONICAL | orjson.OPT_APPEND_NEWLINE,
+            )
+            == b'{"a":2,"b":1}\n'
+        )
+
+    def test_canonical_fragment(self):
+        """
+        OPT_CANONICAL raises on orjson.Fragment
+        """
+        for obj in (
+            orjson.Fragment(b'{"b":1,"a":1.0}'),
+            [orjson.Fragment(b"1")],
+            {"a": orjson.Fragment("1")},
+        ):
+            with pytest.raises(orjson.JSONEncodeError, match="OPT_CANONICAL"):
+                orjson.dumps(obj, option=orjson.OPT_CANONICAL)
+
+    def test_canonical_sort_keys(self):
+        """
+        OPT_CANONICAL with OPT_SORT_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {"דּ": 1, "\U0001f600": 2},
+                option=orjson.OPT_CANONICAL | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"\xf0\x9f\x98\x80":2,"\xef\xac\xb3":1}'
+        )
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_canonical_numpy(self):
+        """
+        OPT_CANONICAL numpy scalars and arrays
+        """
+        assert (
+            orjson.dumps(
+                [
+                    numpy.float64(1.0),
+                    numpy.float32(0.5),
+                    numpy.array([1e21, 2.0], numpy.float64),
+                    numpy.int64(3),
+                ],
+                option=orjson.OPT_CANONICAL | orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"[1,0.5,[1e+21,2],3]"
+        )
+        with pytest.raises(orjson.JSONEncodeError, match="53-bit"):
+            orjson.dumps(
+                numpy.array([2**60], numpy.int64),
+                option=orjson.OPT_CANONICAL | orjson.OPT_SERIALIZE_NUMPY,
+            )
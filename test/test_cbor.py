@@ -0,0 +1,473 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import datetime
+import decimal
+import math
+import sys
+import uuid
+
+import pytest
+
+import orjson
+
+from .util import IS_FREETHREADING, numpy
+
+# https://www.rfc-editor.org/rfc/rfc8949#appendix-A
+SCALARS = (
+    (0, b"\x00"),
+    (1, b"\x01"),
+    (23, b"\x17"),
+    (24, b"\x18\x18"),
+    (100, b"\x18\x64"),
+    (1000, b"\x19\x03\xe8"),
+    (1000000, b"\x1a\x00\x0f\x42\x40"),
+    (1000000000000, b"\x1b\x00\x00\x00\xe8\xd4\xa5\x10\x00"),
+    (18446744073709551615, b"\x1b\xff\xff\xff\xff\xff\xff\xff\xff"),
+    (18446744073709551616, b"\xc2\x49\x01\x00\x00\x00\x00\x00\x00\x00\x00"),
+    (-18446744073709551616, b"\x3b\xff\xff\xff\xff\xff\xff\xff\xff"),
+    (-18446744073709551617, b"\xc3\x49\x01\x00\x00\x00\x00\x00\x00\x00\x00"),
+    (-1, b"\x20"),
+    (-10, b"\x29"),
+    (-100, b"\x38\x63"),
+    (-1000, b"\x39\x03\xe7"),
+    (1.1, b"\xfb\x3f\xf1\x99\x99\x99\x99\x99\x9a"),
+    (float("inf"), b"\xfb\x7f\xf0\x00\x00\x00\x00\x00\x00"),
+    (False, b"\xf4"),
+    (True, b"\xf5"),
+    (None, b"\xf6"),
+    (b"", b"\x40"),
+    (b"\x01\x02\x03\x04", b"\x44\x01\x02\x03\x04"),
+    ("", b"\x60"),
+    ("a", b"\x61\x61"),
+    ("IETF", b"\x64\x49\x45\x54\x46"),
+    ("ü", b"\x62\xc3\xbc"),
+    ("\U00010151", b"\x64\xf0\x90\x85\x91"),
+    ("x" * 24, b"\x78\x18" + b"x" * 24),
+    ([], b"\x80"),
+    ([1, 2, 3], b"\x83\x01\x02\x03"),
+    ([1, [2, 3], [4, 5]], b"\x83\x01\x82\x02\x03\x82\x04\x05"),
+    (list(range(1, 26)), b"\x98\x19" + bytes(range(1, 24)) + b"\x18\x18\x18\x19"),
+    ({}, b"\xa0"),
+    ({"a": 1, "b": [2, 3]}, b"\xa2\x61\x61\x01\x61\x62\x82\x02\x03"),
+    (
+        {"a": "A", "b": "B", "c": "C", "d": "D", "e": "E"},
+        b"\xa5\x61\x61\x61\x41\x61\x62\x61\x42\x61\x63\x61\x43\x61\x64\x61\x44\x61\x65\x61\x45",
+    ),
+)
+
+
+class TestCbor:
+    @pytest.mark.parametrize("obj,expected", SCALARS)
+    def test_dumps_cbor_scalar(self, obj, expected):
+        """
+        dumps_cbor() in the smallest head
+        """
+        assert orjson.dumps_cbor(obj) == expected
+
+    def test_dumps_cbor_tuple(self):
+        """
+        dumps_cbor() tuple as array
+        """
+        assert orjson.dumps_cbor((1, (2,))) == b"\x82\x01\x81\x02"
+
+    def test_dumps_cbor_datetime(self):
+        """
+        dumps_cbor() aware datetime as tag 0, others as str
+        """
+        assert (
+            orjson.dumps_cbor(
+                datetime.datetime(2013, 3, 21, 20, 4, tzinfo=datetime.timezone.utc),
+                option=orjson.OPT_UTC_Z,
+            )
+            == b"\xc0\x742013-03-21T20:04:00Z"
+        )
+        assert (
+            orjson.dumps_cbor(datetime.datetime(2013, 3, 21, 20, 4))
+            == b"\x732013-03-21T20:04:00"
+        )
+        assert (
+            orjson.dumps_cbor(
+                datetime.datetime(2013, 3, 21, 20, 4), option=orjson.OPT_NAIVE_UTC
+            )
+            == b"\xc0\x78\x192013-03-21T20:04:00+00:00"
+        )
+        assert orjson.dumps_cbor(datetime.date(2013, 3, 21)) == b"\x6a2013-03-21"
+
+    def test_dumps_cbor_uuid(self):
+        """
+        dumps_cbor() uuid as tag 37
+        """
+        assert (
+            orjson.dumps_cbor(uuid.UUID(int=1))
+            == b"\xd8\x25\x50" + b"\x00" * 15 + b"\x01"
+        )
+
+    @pytest.mark.parametrize(
+        "obj,expected",
+        (
+            (decimal.Decimal("273.15"), b"\xc4\x82\x21\x19\x6a\xb3"),
+            (decimal.Decimal("-1.5"), b"\xc4\x82\x20\x2e"),
+            (decimal.Decimal("1E+3"), b"\xc4\x82\x03\x01"),
+            (
+                decimal.Decimal("1" + "0" * 20),
+                b"\xc4\x82\x00\xc2\x49\x05\x6b\xc7\x5e\x2d\x63\x10\x00\x00",
+            ),
+            (decimal.Decimal("nan"), b"\xfb\x7f\xf8\x00\x00\x00\x00\x00\x00"),
+            (decimal.Decimal("-inf"), b"\xfb\xff\xf0\x00\x00\x00\x00\x00\x00"),
+            (decimal.Decimal("-0"), b"\xfb\x80\x00\x00\x00\x00\x00\x00\x00"),
+            (decimal.Decimal("-0E-2"), b"\xfb\x80\x00\x00\x00\x00\x00\x00\x00"),
+            (decimal.Decimal("0E-2"), b"\xc4\x82\x21\x00"),
+        ),
+    )
+    def test_dumps_cbor_decimal(self, obj, expected):
+        """
+        dumps_cbor() decimal as tag 4, or float if not finite or negative zero
+        """
+        assert orjson.dumps_cbor(obj) == expected
+
+    @pytest.mark.parametrize("obj", (decimal.Decimal("-0"), decimal.Decimal("-0.00")))
+    def test_dumps_cbor_decimal_negative_zero(self, obj):
+        """
+        dumps_cbor() decimal negative zero round-trips with its sign
+        """
+        val = orjson.loads_cbor(orjson.dumps_cbor(obj))
+        assert val == 0
+        assert math.copysign(1.0, val) == -1.0
+
+    @pytest.mark.skipif(
+        not hasattr(sys, "set_int_max_str_digits"), reason="no int digit limit"
+    )
+    def test_dumps_cbor_decimal_digits(self):
+        """
+        dumps_cbor() decimal exceeding the int digit limit raises
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_cbor(decimal.Decimal("1" * 5000))
+
+    def test_dumps_decimal(self):
+        """
+        dumps() does not serialize decimal natively
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(decimal.Decimal("1"))
+        assert orjson.dumps(decimal.Decimal("1.5"), default=str) == b'"1.5"'
+
+    def test_dumps_cbor_dataclass(self):
+        """
+        dumps_cbor() dataclass
+        """
+
+        @dataclasses.dataclass
+        class Dataclass:
+            a: int
+            b: list
+
+        assert orjson.dumps_cbor(Dataclass(1, [])) == b"\xa2\x61\x61\x01\x61\x62\x80"
+
+    def test_dumps_cbor_options(self):
+        """
+        dumps_cbor() sort keys, non-str keys, strict integer
+        """
+        assert (
+            orjson.dumps_cbor({"b": 1, "a": 2}, option=orjson.OPT_SORT_KEYS)
+            == b"\xa2\x61\x61\x02\x61\x62\x01"
+        )
+        assert (
+            orjson.dumps_cbor({1: True}, option=orjson.OPT_NON_STR_KEYS)
+            == b"\xa1\x61\x31\xf5"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_cbor(2**53, option=orjson.OPT_STRICT_INTEGER)
+
+    @pytest.mark.parametrize(
+        "option",
+        (
+            orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_CANONICAL,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_MSGPACK_TIMESTAMP,
+        ),
+    )
+    def test_dumps_cbor_options_invalid(self, option):
+        """
+        dumps_cbor() options of other formats are invalid
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_cbor(1, option=option)
+
+    def test_dumps_cbor_typed_array_option(self):
+        """
+        OPT_CBOR_TYPED_ARRAY is only for dumps_cbor()
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(1, option=orjson.OPT_CBOR_TYPED_ARRAY)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(1, option=orjson.OPT_CBOR_TYPED_ARRAY)
+        assert orjson.dumps_cbor([1], option=orjson.OPT_CBOR_TYPED_ARRAY) == b"\x81\x01"
+
+    def test_dumps_cbor_fragment(self):
+        """
+        dumps_cbor() Fragment raises
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_cbor([orjson.Fragment(b"{}")])
+
+    def test_dumps_cbor_default(self):
+        """
+        dumps_cbor() default, and bytes not passed to default
+        """
+
+        class Custom:
+            pass
+
+        assert orjson.dumps_cbor(Custom(), default=lambda _: [1]) == b"\x81\x01"
+        assert orjson.dumps_cbor(b"a", default=lambda _: None) == b"\x41a"
+
+    def test_dumps_cbor_unsupported(self):
+        """
+        dumps_cbor() unsupported type names the format
+        """
+        with pytest.raises(
+            orjson.JSONEncodeError, match="^Type is not CBOR serializable: object$"
+        ):
+            orjson.dumps_cbor(object())
+
+    @pytest.mark.skipif(IS_FREETHREADING, reason="passed to default")
+    def test_dumps_cbor_bytearray_memoryview(self):
+        """
+        dumps_cbor() bytearray and memoryview are byte strings
+        """
+        assert orjson.dumps_cbor(bytearray(b"a")) == b"\x41a"
+        assert orjson.dumps_cbor([memoryview(b"ab")]) == b"\x81\x42ab"
+
+    def test_dumps_cbor_recursion(self):
+        """
+        dumps_cbor() circular reference raises
+        """
+        obj: list = []
+        obj.append(obj)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_cbor(obj)
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_dumps_cbor_numpy(self):
+        """
+        dumps_cbor() numpy as array without OPT_CBOR_TYPED_ARRAY
+        """
+        assert (
+            orjson.dumps_cbor(
+                numpy.array([[1, 2], [3, 4]], numpy.int32),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"\x82\x82\x01\x02\x82\x03\x04"
+        )
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    @pytest.mark.skipif(sys.byteorder != "little", reason="little-endian")
+    def test_dumps_cbor_numpy_typed_array(self):
+        """
+        dumps_cbor() numpy as typed array
+        """
+        option = orjson.OPT_SERIALIZE_NUMPY | orjson.OPT_CBOR_TYPED_ARRAY
+        assert (
+            orjson.dumps_cbor(numpy.array([1, 2], numpy.uint8), option=option)
+            == b"\xd8\x40\x42\x01\x02"
+        )
+        assert (
+            orjson.dumps_cbor(numpy.array([1, 2], numpy.uint16), option=option)
+            == b"\xd8\x45\x44\x01\x00\x02\x00"
+        )
+        assert (
+            orjson.dumps_cbor(numpy.array([-1], numpy.int8), option=option)
+            == b"\xd8\x48\x41\xff"
+        )
+        assert (
+            orjson.dumps_cbor(numpy.array([1.5], numpy.float64), option=option)
+            == b"\xd8\x56\x48" + numpy.array([1.5], numpy.float64).tobytes()
+        )
+        assert (
+            orjson.dumps_cbor(
+                numpy.array([[1, 2, 3], [4, 5, 6]], numpy.uint8), option=option
+            )
+            == b"\xd8\x28\x82\x82\x02\x03\xd8\x40\x46\x01\x02\x03\x04\x05\x06"
+        )
+        assert (
+            orjson.dumps_cbor(numpy.array([True, False]), option=option)
+            == b"\x82\xf5\xf4"
+        )
+        assert orjson.loads_cbor(
+            orjson.dumps_cbor(numpy.array([[1, 2], [3, 4]], numpy.int64), option=option)
+        ) == [[1, 2], [3, 4]]
+
+    @pytest.mark.parametrize("expected,data", SCALARS)
+    def test_loads_cbor_scalar(self, expected, data):
+        """
+        loads_cbor() scalars
+        """
+        assert orjson.loads_cbor(data) == expected
+
+    @pytest.mark.parametrize(
+        "data,expected",
+        (
+            (b"\xf9\x00\x00", 0.0),
+            (b"\xf9\x80\x00", -0.0),
+            (b"\xf9\x3c\x00", 1.0),
+            (b"\xf9\x7b\xff", 65504.0),
+            (b"\xf9\x00\x01", 5.960464477539063e-08),
+            (b"\xf9\x7c\x00", float("inf")),
+            (b"\xfa\x47\xc3\x50\x00", 100000.0),
+            (b"\xfa\x7f\x7f\xff\xff", 3.4028234663852886e38),
+            (b"\x5f\x42\x01\x02\x43\x03\x04\x05\xff", b"\x01\x02\x03\x04\x05"),
+            (b"\x7f\x65strea\x64ming\xff", "streaming"),
+            (b"\x9f\xff", []),
+            (b"\x9f\x01\x82\x02\x03\x9f\x04\x05\xff\xff", [1, [2, 3], [4, 5]]),
+            (b"\xbf\x61\x61\x01\x61\x62\x9f\x02\x03\xff\xff", {"a": 1, "b": [2, 3]}),
+            (b"\xa2\x01\x02\x03\x04", {1: 2, 3: 4}),
+            (b"\xd8\x18\x45\x64\x49\x45\x54\x46", b"\x64\x49\x45\x54\x46"),
+            (b"\xf7", None),
+        ),
+    )
+    def test_loads_cbor_formats(self, data, expected):
+        """
+        loads_cbor() formats dumps_cbor() does not write
+        """
+        assert orjson.loads_cbor(data) == expected
+
+    def test_loads_cbor_tags(self):
+        """
+        loads_cbor() standard tags
+        """
+        utc = datetime.timezone.utc
+        assert orjson.loads_cbor(b"\xc0\x742013-03-21T20:04:00Z") == datetime.datetime(
+            2013, 3, 21, 20, 4, tzinfo=utc
+        )
+        assert orjson.loads_cbor(
+            b"\xc0\x78\x192013-03-21T20:04:00+01:00"
+        ) == datetime.datetime(
+            2013, 3, 21, 20, 4, tzinfo=datetime.timezone(datetime.timedelta(hours=1))
+        )
+        assert orjson.loads_cbor(b"\xc1\x1a\x51\x4b\x67\xb0") == datetime.datetime(
+            2013, 3, 21, 20, 4, tzinfo=utc
+        )
+        assert orjson.loads_cbor(
+            b"\xc1\xfb\x41\xd4\x52\xd9\xec\x20\x00\x00"
+        ) == datetime.datetime(2013, 3, 21, 20, 4, 0, 500000, tzinfo=utc)
+        assert orjson.loads_cbor(b"\xc4\x82\x21\x19\x6a\xb3") == decimal.Decimal(
+            "273.15"
+        )
+        assert orjson.loads_cbor(b"\xd8\x25\x50" + b"\x00" * 15 + b"\x01") == uuid.UUID(
+            int=1
+        )
+        assert orjson.loads_cbor(b"\xd8\x45\x44\x01\x00\x02\x00") == [1, 2]
+        assert orjson.loads_cbor(b"\xd8\x41\x44\x00\x01\x00\x02") == [1, 2]
+        assert orjson.loads_cbor(
+            b"\xd8\x28\x82\x82\x02\x03\xd8\x40\x46\x01\x02\x03\x04\x05\x06"
+        ) == [[1, 2, 3], [4, 5, 6]]
+        assert orjson.loads_cbor(
+            b"\xd8\x28\x82\x82\x01\x02\x82\x61\x61\x61\x62"
+        ) == [["a", "b"]]
+
+    def test_loads_cbor_roundtrip(self):
+        """
+        loads_cbor() of dumps_cbor()
+        """
+        obj = {
+            "a": [None, True, False, 1, -1, 2**64, -(2**64) - 1, 2**200, 1.5],
+            "b": {"c": ["x" * 70000, b"\x00" * 300, [], {}]},
+            "d": list(range(70000)),
+            "e": [
+                datetime.datetime(2000, 1, 1, 1, 1, 1, 1, tzinfo=datetime.timezone.utc),
+                uuid.UUID(int=2**127),
+                decimal.Decimal("-0.001"),
+            ],
+            "é": {str(idx): idx for idx in range(30)},
+        }
+        assert orjson.loads_cbor(orjson.dumps_cbor(obj)) == obj
+
+    def test_loads_cbor_input_type(self):
+        """
+        loads_cbor() bytes, bytearray, memoryview, and not str
+        """
+        assert orjson.loads_cbor(b"\x81\x01") == [1]
+        assert orjson.loads_cbor(bytearray(b"\x81\x01")) == [1]
+        assert orjson.loads_cbor(memoryview(b"\x81\x01")) == [1]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_cbor("\x00")  # type: ignore
+
+    @pytest.mark.parametrize(
+        "data,pos",
+        (
+            (b"", 0),
+            (b"\x1c", 0),
+            (b"\xff", 0),
+            (b"\x82\x01", 2),
+            (b"\x01\x02", 1),
+            (b"\xa1\xf6\x00", 1),
+            (b"\x61\xff", 0),
+            (b"\x5f\x61\x61\xff", 1),
+            (b"\x9b\xff\xff\xff\xff\xff\xff\xff\xff", 9),
+            (b"\xd8\x25\x41\x00", 0),
+            (b"\xc0\x61\x61", 0),
+            (b"\xc1\x61\x61", 0),
+            (b"\xc0\x71a", 3),
+        ),
+    )
+    def test_loads_cbor_invalid(self, data, pos):
+        """
+        loads_cbor() invalid data raises with the byte offset
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_cbor(data)
+        assert exc_info.value.pos == pos
+
+    def test_loads_cbor_decimal_exponent(self):
+        """
+        loads_cbor() tag 4 of an exponent out of range of decimal.Decimal raises
+        """
+        assert orjson.loads_cbor(
+            bytes.fromhex("c4821b0de0b6b3a763ffff01")
+        ) == decimal.Decimal("1E999999999999999999")
+        for data in (
+            "c4821b7fffffffffffffff01",
+            "c4823b7fffffffffffffff01",
+            "c4821b0de0b6b3a763ffff187b",
+            "c482c24901000000000000000001",
+        ):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads_cbor(bytes.fromhex(data))
+            assert exc_info.value.pos == 0
+            assert str(exc_info.value).startswith("Invalid content of tag")
+
+    def test_loads_cbor_uuid_invalid(self):
+        """
+        loads_cbor() tag 37 of other than 16 bytes raises
+        """
+        for data in (
+            b"\xd8\x25\x40",
+            b"\xd8\x25\x51" + b"\x00" * 17,
+            b"\xd8\x25\x01",
+        ):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.loads_cbor(data)
+            assert exc_info.value.pos == 0
+
+    def test_loads_cbor_recursion(self):
+        """
+        loads_cbor() recursion limit of arrays, maps, and tags
+        """
+        assert orjson.loads_cbor(b"\x81" * 1023 + b"\x80")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_cbor(b"\x81" * 1024 + b"\x80")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_cbor(b"\xd8\x64" * 1025 + b"\x00")
+
+    def test_loads_cbor_args(self):
+        """
+        loads_cbor() arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.loads_cbor()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_cbor(b"\x00", None)  # type: ignore
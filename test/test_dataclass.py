@@ -2,8 +2,11 @@
 # Copyright ijl (2019-2026)
 
 import abc
+import gc
+import json
 import uuid
-from dataclasses import InitVar, asdict, dataclass, field
+import weakref
+from dataclasses import InitVar, asdict, dataclass, field, make_dataclass
 from enum import Enum
 from typing import ClassVar, Optional
 
@@ -98,6 +101,36 @@ class InitDataclass:
         self.ab = f"{a} {b}"
 
 
+@dataclass
+class MetadataDataclass:
+    user_id: int = field(metadata={"orjson": {"name": "userId"}})
+    password: str = field(default="", metadata={"orjson": {"skip": True}})
+    plain: int = field(default=0, metadata={"unrelated": True})
+    _private: int = field(default=0, metadata={"orjson": {"name": "private"}})
+
+    def __post_init__(self):
+        self.extra = 1
+
+
+@dataclass(slots=True)
+class MetadataSlotsDataclass:
+    a: int = field(metadata={"orjson": {"name": "A"}})
+    b: int = 0
+
+
+@dataclass
+class UnderscoreDataclass:
+    _id: str = field(metadata={"orjson": {"include": True}})
+    name: str = ""
+    _rev: int = 0
+
+
+@dataclass(slots=True)
+class UnderscoreSlotsDataclass:
+    _id: str = field(metadata={"orjson": {"include": True}})
+    _rev: int = 0
+
+
 class AbstractBase(abc.ABC):
     @abc.abstractmethod
     def key(self):
@@ -203,6 +236,21 @@ class TestDataclass:
         assert "__dict__" not in dir(obj)
         assert orjson.dumps(obj) == b'{"a":"a","b":1}'
 
+    def test_dataclass_slots_unset(self):
+        """
+        dumps() dataclass with __slots__ and a field with init=False that is not set
+        """
+
+        @dataclass(slots=True)
+        class UnsetSlotsDataclass:
+            a: int
+            b: int = field(init=False)
+
+        obj = UnsetSlotsDataclass(1)
+        assert orjson.dumps(obj) == b'{"a":1}'
+        obj.b = 2
+        assert orjson.dumps(obj) == b'{"a":1,"b":2}'
+
     def test_dataclass_default(self):
         """
         dumps() dataclass with default
@@ -260,6 +308,82 @@ class TestDataclass:
             == b'{"name":"a","number":1,"sub":null}'
         )
 
+    def test_dataclass_underscore_option(self):
+        """
+        dumps() includes under attributes with OPT_SERIALIZE_UNDERSCORE
+        """
+        obj = InitDataclass("zxc", "vbn")
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_UNDERSCORE)
+            == b'{"ab":"zxc vbn","_other":1}'
+        )
+        assert (
+            orjson.dumps(
+                MetadataDataclass(1, "secret", 2, 3),
+                option=orjson.OPT_SERIALIZE_UNDERSCORE,
+            )
+            == b'{"userId":1,"plain":2,"private":3,"extra":1}'
+        )
+
+    def test_dataclass_underscore_include(self):
+        """
+        dumps() includes under fields whose metadata includes them
+        """
+        obj = UnderscoreDataclass("a", "b", 1)
+        assert orjson.dumps(obj) == b'{"_id":"a","name":"b"}'
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_UNDERSCORE)
+            == b'{"_id":"a","name":"b","_rev":1}'
+        )
+        del obj._id
+        obj._id = "c"
+        assert orjson.dumps(obj) == b'{"name":"b","_id":"c"}'
+        obj = UnderscoreSlotsDataclass("a", 1)
+        assert orjson.dumps(obj) == b'{"_id":"a"}'
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_UNDERSCORE)
+            == b'{"_id":"a","_rev":1}'
+        )
+
+    def test_dataclass_dict_order(self):
+        """
+        dumps() dataclass whose __dict__ is not in the order of its fields
+        """
+        obj = Dataclass1("a", 1, None)
+        del obj.name
+        obj.name = "b"
+        obj.other = 2
+        assert orjson.dumps(obj) == b'{"number":1,"sub":null,"name":"b","other":2}'
+        assert orjson.dumps(Dataclass1("a", 1, None)) == b'{"name":"a","number":1,"sub":null}'
+
+    def test_dataclass_escaped_key(self):
+        """
+        dumps() dataclass field renamed to a key that must be escaped
+        """
+
+        @dataclass
+        class Escaped:
+            a: int = field(metadata={"orjson": {"name": 'a"\n\u00e9'}})
+
+        obj = Escaped(1)
+        assert orjson.dumps(obj) == b'{"a\\"\\n\xc3\xa9":1}'
+        assert orjson.loads(orjson.dumps([obj, obj])) == [{'a"\n\u00e9': 1}] * 2
+        assert orjson.loads_msgpack(orjson.dumps_msgpack(obj)) == {'a"\n\u00e9': 1}
+        assert orjson.loads_cbor(orjson.dumps_cbor(obj)) == {'a"\n\u00e9': 1}
+
+    def test_dataclass_class_freed(self):
+        """
+        dumps() does not keep a dataclass alive and does not use what it
+        cached for a class that was freed
+        """
+        for idx in range(1000):
+            cls = make_dataclass("Dynamic", [(f"field{idx}", int)])
+            ref = weakref.ref(cls)
+            assert orjson.dumps(cls(idx)) == f'{{"field{idx}":{idx}}}'.encode()
+            del cls
+            gc.collect()
+            assert ref() is None
+
 
 class TestDataclassPassthrough:
     def test_dataclass_passthrough_raise(self):
@@ -295,6 +419,88 @@ class TestDataclassPassthrough:
             == b'{"name":"a","number":1}'
         )
 
+    def test_dataclass_metadata_name_skip(self):
+        """
+        dumps() dataclass field metadata renames and skips fields
+        """
+        obj = MetadataDataclass(1, "secret", 2, 3)
+        assert orjson.dumps(obj) == b'{"userId":1,"plain":2,"extra":1}'
+        assert orjson.dumps([obj, obj]) == (
+            b'[{"userId":1,"plain":2,"extra":1},{"userId":1,"plain":2,"extra":1}]'
+        )
+
+    def test_dataclass_metadata_not_interned(self):
+        """
+        dumps() dataclass field metadata of __dict__ keys that are not interned
+        """
+        obj = MetadataDataclass.__new__(MetadataDataclass)
+        obj.__dict__.update(
+            json.loads('{"user_id": 2, "password": "hunter2", "_private": 3}')
+        )
+        assert orjson.dumps(obj) == b'{"userId":2}'
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_UNDERSCORE)
+            == b'{"userId":2,"private":3}'
+        )
+
+    def test_dataclass_metadata_post_init(self):
+        """
+        dumps() dataclass field metadata of fields assigned in __post_init__
+        """
+
+        @dataclass
+        class PostInit:
+            a: int
+            renamed: int = field(init=False, metadata={"orjson": {"name": "B"}})
+            skipped: int = field(init=False, metadata={"orjson": {"skip": True}})
+
+            def __post_init__(self):
+                self.other = 0
+                self.skipped = 1
+                self.renamed = 2
+
+        assert orjson.dumps(PostInit(1)) == b'{"a":1,"other":0,"B":2}'
+
+    def test_dataclass_metadata_slots(self):
+        """
+        dumps() dataclass field metadata with __slots__
+        """
+        assert orjson.dumps(MetadataSlotsDataclass(1, 2)) == b'{"A":1,"b":2}'
+
+    def test_dataclass_metadata_options(self):
+        """
+        dumps() dataclass field metadata with OPT_CANONICAL and OPT_INDENT_2
+        """
+        obj = MetadataDataclass(1)
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_CANONICAL)
+            == b'{"extra":1,"plain":0,"userId":1}'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_INDENT_2)
+            == b'{\n  "userId": 1,\n  "plain": 0,\n  "extra": 1\n}'
+        )
+
+    @pytest.mark.parametrize(
+        "metadata",
+        (
+            {"orjson": 1},
+            {"orjson": {"name": 1}},
+            {"orjson": {"name": ""}},
+        ),
+    )
+    def test_dataclass_metadata_invalid(self, metadata):
+        """
+        dumps() dataclass field metadata that is invalid raises
+        """
+
+        @dataclass
+        class Invalid:
+            a: int = field(metadata=metadata)
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Invalid(1))
+
 
 class TestAbstractDataclass:
     def test_dataclass_abc(self):
@@ -0,0 +1,197 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+
+def decode_chunks(data, size):
+    decoder = orjson.Decoder()
+    res = []
+    for idx in range(0, len(data), size):
+        res.extend(decoder.feed(data[idx : idx + size]))
+    res.extend(decoder.close())
+    return res
+
+
+class TestDecoder:
+    def test_decoder_single(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b'{"a": [1, 2]}') == [{"a": [1, 2]}]
+        assert decoder.close() == []
+
+    def test_decoder_split(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b'{"a": [1, ') == []
+        assert decoder.feed(b'2]} [3] 4') == [{"a": [1, 2]}, [3]]
+        assert decoder.close() == [4]
+
+    def test_decoder_multiple(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b'1 "a" [] {} true null 2.5\n') == [
+            1,
+            "a",
+            [],
+            {},
+            True,
+            None,
+            2.5,
+        ]
+
+    def test_decoder_adjacent(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b'[1][2]{"a":3}"b"') == [[1], [2], {"a": 3}, "b"]
+
+    def test_decoder_number_pending(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"12") == []
+        assert decoder.feed(b"3") == []
+        assert decoder.feed(b".5 ") == [123.5]
+        assert decoder.feed(b"-1e") == []
+        assert decoder.feed(b"2") == []
+        assert decoder.close() == [-100.0]
+
+    def test_decoder_literal(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"tr") == []
+        assert decoder.feed(b"ue") == [True]
+        assert decoder.feed(b"null") == [None]
+
+    def test_decoder_string_escape(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b'["a\\') == []
+        assert decoder.feed(b'"]", "b\\\\"] ') == [['a"]', 'b\\']]
+
+    def test_decoder_byte_chunks(self):
+        data = b'{"a": "\xc3\xa9\xf0\x9f\x90\x88", "b": [1, 2.5, true, null]} "c" 12 [[]]'
+        expected = [{"a": "é\U0001f408", "b": [1, 2.5, True, None]}, "c", 12, [[]]]
+        for size in range(1, 8):
+            assert decode_chunks(data, size) == expected
+
+    def test_decoder_str(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed('{"a": "é') == []
+        assert decoder.feed('"}') == [{"a": "é"}]
+
+    def test_decoder_bytearray_memoryview(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(bytearray(b"[1")) == []
+        assert decoder.feed(memoryview(b"]")) == [[1]]
+
+    def test_decoder_empty_chunk(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"") == []
+        assert decoder.feed(b" \n") == []
+        assert decoder.close() == []
+
+    def test_decoder_invalid(self):
+        decoder = orjson.Decoder()
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b"]")
+
+    def test_decoder_invalid_after_documents(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"[1] ]") == [[1]]
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b"[2]")
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b"[]")
+
+    def test_decoder_invalid_after_documents_scalar(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed("1 2 x") == [1, 2]
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.close()
+        assert decoder.close() == []
+
+    def test_decoder_invalid_after_documents_close(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"[1] ") == [[1]]
+        assert decoder.feed(b"[2] x") == [[2]]
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.close()
+
+    def test_decoder_invalid_document(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"[1] [2, x]") == [[1]]
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            decoder.feed(b"")
+        assert exc_info.value.pos == 4
+
+    def test_decoder_invalid_literal(self):
+        for chunks in (["tr€"], ["t", "r€"], [b"tr\xe2", b"\x82\xac"], ["[tr€]"]):
+            decoder = orjson.Decoder()
+            with pytest.raises(orjson.JSONDecodeError, match="invalid literal"):
+                for chunk in chunks:
+                    decoder.feed(chunk)
+                decoder.close()
+
+    def test_decoder_invalid_utf8(self):
+        decoder = orjson.Decoder()
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b'"\xed\xa0\x80"')
+
+    def test_decoder_incomplete(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b"[1, 2") == []
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.close()
+
+    def test_decoder_incomplete_string(self):
+        decoder = orjson.Decoder()
+        assert decoder.feed(b'"abc') == []
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.close()
+
+    def test_decoder_closed(self):
+        decoder = orjson.Decoder()
+        assert decoder.close() == []
+        assert decoder.close() == []
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b"[]")
+
+    def test_decoder_closed_after_error(self):
+        decoder = orjson.Decoder()
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b"]")
+        with pytest.raises(orjson.JSONDecodeError):
+            decoder.feed(b"[]")
+
+    def test_decoder_type(self):
+        decoder = orjson.Decoder()
+        for val in (1, 3.14, [], {}, None):
+            with pytest.raises(orjson.JSONDecodeError):
+                decoder.feed(val)  # type: ignore
+        assert decoder.feed(b"[]") == [[]]
+
+    def test_decoder_args(self):
+        with pytest.raises(TypeError):
+            orjson.Decoder(b"[]")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.Decoder(a=1)  # type: ignore
+        decoder = orjson.Decoder()
+        with pytest.raises(TypeError):
+            decoder.feed()  # type: ignore
+        with pytest.raises(TypeError):
+            decoder.close(b"")  # type: ignore
+
+    def test_decoder_independent(self):
+        first = orjson.Decoder()
+        second = orjson.Decoder()
+        assert first.feed(b"[1, ") == []
+        assert second.feed(b"[2]") == [[2]]
+        assert first.feed(b"3]") == [[1, 3]]
+
+    def test_decoder_large(self):
+        data = orjson.dumps([{"a": idx, "b": str(idx) * idx} for idx in range(1000)])
+        assert decode_chunks(data * 3, 4096) == [orjson.loads(data)] * 3
+
+    @needs_data
+    def test_decoder_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        expected = orjson.loads(data)
+        for size in (1, 7, 4096):
+            assert decode_chunks(data + b"\n" + data, size) == [expected, expected]
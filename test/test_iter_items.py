@@ -0,0 +1,173 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+
+class TestIterItems:
+    def test_iter_items_array(self):
+        assert list(orjson.iter_items(b'[1, {"a": 2}, [3], "x", null]')) == [
+            1,
+            {"a": 2},
+            [3],
+            "x",
+            None,
+        ]
+
+    def test_iter_items_str(self):
+        assert list(orjson.iter_items('["é", {"\U0001f408": []}]')) == [
+            "é",
+            {"\U0001f408": []},
+        ]
+
+    def test_iter_items_lazy(self):
+        it = orjson.iter_items(b"[1, 2]")
+        assert iter(it) is it
+        assert next(it) == 1
+        assert next(it) == 2
+        with pytest.raises(StopIteration):
+            next(it)
+
+    def test_iter_items_empty_array(self):
+        assert list(orjson.iter_items(b" [ ] ")) == []
+
+    def test_iter_items_nested(self):
+        data = b'{"meta": {"data": [0]}, "data": [{"id": 1}, {"id": 2}], "z": 1}'
+        assert list(orjson.iter_items(data, "data.item")) == [{"id": 1}, {"id": 2}]
+        assert list(orjson.iter_items(data, prefix="data.item")) == [
+            {"id": 1},
+            {"id": 2},
+        ]
+
+    def test_iter_items_nested_key(self):
+        data = b'{"data": [{"id": 1, "x": [1]}, {"x": 2}, {"id": [3]}]}'
+        assert list(orjson.iter_items(data, "data.item.id")) == [1, [3]]
+
+    def test_iter_items_object_value(self):
+        data = b'{"a": {"b": [1, 2]}, "b": 3}'
+        assert list(orjson.iter_items(data, "a")) == [{"b": [1, 2]}]
+        assert list(orjson.iter_items(data, "a.b")) == [[1, 2]]
+        assert list(orjson.iter_items(data, "a.b.item")) == [1, 2]
+
+    def test_iter_items_nested_arrays(self):
+        assert list(orjson.iter_items(b"[[1, 2], [], [3]]", "item.item")) == [1, 2, 3]
+
+    def test_iter_items_empty_prefix(self):
+        assert list(orjson.iter_items(b' {"a": 1} ', "")) == [{"a": 1}]
+
+    def test_iter_items_key_escape(self):
+        assert list(orjson.iter_items(b'{"a\\u0062": [1], "ab": [2]}', "ab.item")) == [
+            1,
+            2,
+        ]
+
+    def test_iter_items_key_brackets(self):
+        data = b'{"[": "]", "a": ["{", "}"]}'
+        assert list(orjson.iter_items(data, "a.item")) == ["{", "}"]
+
+    def test_iter_items_no_match(self):
+        assert list(orjson.iter_items(b"5")) == []
+        assert list(orjson.iter_items(b'{"a": 1}')) == []
+        assert list(orjson.iter_items(b'{"a": 1}', "b")) == []
+        assert list(orjson.iter_items(b"[1]", "a")) == []
+
+    def test_iter_items_error_after_values(self):
+        it = orjson.iter_items(b"[1, [2, x], 3]")
+        assert next(it) == 1
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            next(it)
+        assert exc_info.value.pos == 8
+        assert list(it) == []
+
+    def test_iter_items_error_pos_str(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            list(orjson.iter_items('["é", x]'))
+        assert exc_info.value.pos == 6
+
+    def test_iter_items_invalid(self):
+        for val in (
+            b"[1, 2,]",
+            b"[1 2]",
+            b"[1, 2",
+            b"[1] x",
+            b"]",
+            b'{"a" 1}',
+            b'{"a": 1,}',
+            b"{1: 2}",
+            b" ",
+        ):
+            with pytest.raises(orjson.JSONDecodeError):
+                list(orjson.iter_items(val, "a"))
+            with pytest.raises(orjson.JSONDecodeError):
+                list(orjson.iter_items(val))
+
+    def test_iter_items_invalid_skipped(self):
+        for val in (
+            '{"a": nul!, "b": [1]}',
+            '{"a": [1,,,2}, "b": [1]}',
+            '{"a": [1, 2], "b": [1], "c": {"d" 1}}',
+            '{"a": "\x01", "b": [1]}',
+            '{"\\x": 1, "b": [1]}',
+            '{"a": 01, "b": [1]}',
+            '{"a": tr€, "b": [1]}',
+            '{"b": [1]} tr€',
+        ):
+            with pytest.raises(orjson.JSONDecodeError):
+                list(orjson.iter_items(val, "b.item"))
+        with pytest.raises(orjson.JSONDecodeError):
+            list(orjson.iter_items("[tr€]"))
+        with pytest.raises(orjson.JSONDecodeError):
+            list(orjson.iter_items("tr€", "a"))
+
+    def test_iter_items_invalid_skipped_pos(self):
+        it = orjson.iter_items(b'{"b": [1], "a": [1, 2, x]}', "b.item")
+        assert next(it) == 1
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            next(it)
+        assert exc_info.value.pos == 23
+
+    def test_iter_items_invalid_utf8(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.iter_items(b'["\xed\xa0\x80"]')
+
+    def test_iter_items_empty(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.iter_items(b"")
+
+    def test_iter_items_type(self):
+        for val in (bytearray(b"[]"), memoryview(b"[]"), 1, None, []):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.iter_items(val)  # type: ignore
+
+    def test_iter_items_args(self):
+        with pytest.raises(TypeError):
+            orjson.iter_items()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.iter_items(b"[]", 1)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.iter_items(b"[]", "item", "item")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.iter_items(b"[]", "item", prefix="item")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.iter_items(b"[]", foo="item")  # type: ignore
+
+    def test_iter_items_not_instantiable(self):
+        with pytest.raises(TypeError):
+            type(orjson.iter_items(b"[]"))()
+
+    def test_iter_items_large(self):
+        data = [{"id": idx, "name": str(idx) * 10} for idx in range(10000)]
+        assert list(orjson.iter_items(orjson.dumps(data))) == data
+
+    @needs_data
+    def test_iter_items_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        expected = orjson.loads(data)
+        assert list(orjson.iter_items(data, "statuses.item")) == expected["statuses"]
+        assert list(orjson.iter_items(data, "search_metadata")) == [
+            expected["search_metadata"],
+        ]
@@ -0,0 +1,362 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import datetime
+import uuid
+
+import pytest
+
+import orjson
+
+# https://www.rfc-editor.org/rfc/rfc6902#appendix-A
+EXAMPLES = (
+    (
+        {"foo": "bar"},
+        [{"op": "add", "path": "/baz", "value": "qux"}],
+        {"baz": "qux", "foo": "bar"},
+    ),
+    (
+        {"foo": ["bar", "baz"]},
+        [{"op": "add", "path": "/foo/1", "value": "qux"}],
+        {"foo": ["bar", "qux", "baz"]},
+    ),
+    (
+        {"baz": "qux", "foo": "bar"},
+        [{"op": "remove", "path": "/baz"}],
+        {"foo": "bar"},
+    ),
+    (
+        {"foo": ["bar", "qux", "baz"]},
+        [{"op": "remove", "path": "/foo/1"}],
+        {"foo": ["bar", "baz"]},
+    ),
+    (
+        {"baz": "qux", "foo": "bar"},
+        [{"op": "replace", "path": "/baz", "value": "boo"}],
+        {"baz": "boo", "foo": "bar"},
+    ),
+    (
+        {"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}},
+        [{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}],
+        {"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}},
+    ),
+    (
+        {"foo": ["all", "grass", "cows", "eat"]},
+        [{"op": "move", "from": "/foo/1", "path": "/foo/3"}],
+        {"foo": ["all", "cows", "eat", "grass"]},
+    ),
+    (
+        {"baz": "qux", "foo": ["a", 2, "c"]},
+        [
+            {"op": "test", "path": "/baz", "value": "qux"},
+            {"op": "test", "path": "/foo/1", "value": 2},
+        ],
+        {"baz": "qux", "foo": ["a", 2, "c"]},
+    ),
+    (
+        {"foo": "bar"},
+        [{"op": "add", "path": "/child", "value": {"grandchild": {}}}],
+        {"foo": "bar", "child": {"grandchild": {}}},
+    ),
+    (
+        {"foo": "bar"},
+        [{"op": "add", "path": "/baz", "value": "qux", "xyz": 123}],
+        {"foo": "bar", "baz": "qux"},
+    ),
+    (
+        {"foo": ["bar"]},
+        [{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}],
+        {"foo": ["bar", ["abc", "def"]]},
+    ),
+    (
+        {"/": 9, "~1": 10},
+        [{"op": "test", "path": "/~01", "value": 10}],
+        {"/": 9, "~1": 10},
+    ),
+)
+
+# https://www.rfc-editor.org/rfc/rfc6902#appendix-A
+FAILURES = (
+    ({"baz": "qux"}, [{"op": "test", "path": "/baz", "value": "bar"}]),
+    ({"foo": "bar"}, [{"op": "add", "path": "/baz/bat", "value": "qux"}]),
+    ({"/": 9, "~1": 10}, [{"op": "test", "path": "/~01", "value": "10"}]),
+    ({"foo": ["bar"]}, [{"op": "add", "path": "/foo/2", "value": 1}]),
+    ({"foo": ["bar"]}, [{"op": "remove", "path": "/foo/01"}]),
+    ({"foo": "bar"}, [{"op": "replace", "path": "/baz", "value": 1}]),
+    ({"foo": {"bar": 1}}, [{"op": "move", "from": "/foo", "path": "/foo/bar"}]),
+    ({"foo": 1}, [{"op": "copy", "from": "/bar", "path": "/baz"}]),
+    ({"foo": 1}, [{"op": "remove", "path": ""}]),
+    ({"foo": 1}, [{"op": "invalid", "path": "/foo"}]),
+    ({"foo": 1}, [{"op": "add", "path": "foo", "value": 1}]),
+    ({"foo": 1}, [{"op": "add", "path": "/bar"}]),
+    ({"foo": 1}, [{"path": "/foo"}]),
+    ({"foo": 1}, [{"op": "move", "path": "/foo"}]),
+    ({"foo": 1}, [{"op": 1, "path": "/foo"}]),
+)
+
+
+class TestApplyPatch:
+    @pytest.mark.parametrize("doc,ops,expected", EXAMPLES)
+    def test_apply_patch(self, doc, ops, expected):
+        """
+        apply_patch() RFC 6902 examples
+        """
+        assert orjson.apply_patch(doc, ops) == expected
+
+    @pytest.mark.parametrize("doc,ops", FAILURES)
+    def test_apply_patch_error(self, doc, ops):
+        """
+        apply_patch() operation that cannot be applied
+        """
+        with pytest.raises(ValueError, match="operation 0"):
+            orjson.apply_patch(doc, ops)
+
+    def test_apply_patch_error_index(self):
+        """
+        apply_patch() error names the failing operation
+        """
+        with pytest.raises(ValueError, match="operation 1: test failed: /a"):
+            orjson.apply_patch(
+                {"a": 1},
+                [
+                    {"op": "replace", "path": "/a", "value": 2},
+                    {"op": "test", "path": "/a", "value": 1},
+                ],
+            )
+
+    def test_apply_patch_does_not_modify(self):
+        """
+        apply_patch() does not modify doc or ops
+        """
+        doc = {"a": {"b": [1, 2]}, "c": (3,)}
+        ops = [
+            {"op": "add", "path": "/a/b/0", "value": 0},
+            {"op": "remove", "path": "/c/0"},
+        ]
+        assert orjson.apply_patch(doc, ops) == {"a": {"b": [0, 1, 2]}, "c": []}
+        assert doc == {"a": {"b": [1, 2]}, "c": (3,)}
+        assert ops == [
+            {"op": "add", "path": "/a/b/0", "value": 0},
+            {"op": "remove", "path": "/c/0"},
+        ]
+
+    def test_apply_patch_does_not_modify_on_error(self):
+        """
+        apply_patch() does not modify doc if an operation fails
+        """
+        doc = {"a": [1]}
+        with pytest.raises(ValueError):
+            orjson.apply_patch(
+                doc,
+                [
+                    {"op": "add", "path": "/a/-", "value": 2},
+                    {"op": "remove", "path": "/b"},
+                ],
+            )
+        assert doc == {"a": [1]}
+
+    def test_apply_patch_shares_unchanged(self):
+        """
+        apply_patch() result shares values the patch does not change
+        """
+        doc = {"a": [1], "b": {"c": 2}}
+        result = orjson.apply_patch(doc, [{"op": "add", "path": "/b/d", "value": 3}])
+        assert result["a"] is doc["a"]
+        assert result["b"] is not doc["b"]
+
+    def test_apply_patch_copy(self):
+        """
+        apply_patch() copy is independent of its source
+        """
+        assert orjson.apply_patch(
+            {"a": {"b": [1]}},
+            [
+                {"op": "copy", "from": "/a", "path": "/c"},
+                {"op": "add", "path": "/c/b/-", "value": 2},
+                {"op": "add", "path": "/a/d", "value": 3},
+            ],
+        ) == {"a": {"b": [1], "d": 3}, "c": {"b": [1, 2]}}
+
+    def test_apply_patch_root(self):
+        """
+        apply_patch() replace and add at the root
+        """
+        assert orjson.apply_patch({"a": 1}, [{"op": "replace", "path": "", "value": [1]}]) == [1]
+        assert orjson.apply_patch(
+            {"a": 1},
+            [
+                {"op": "add", "path": "", "value": {"b": 1}},
+                {"op": "add", "path": "/c", "value": 2},
+            ],
+        ) == {"b": 1, "c": 2}
+
+    def test_apply_patch_test_json(self):
+        """
+        apply_patch() test compares as JSON
+        """
+        doc = {
+            "a": (1, 2),
+            "b": datetime.date(2020, 1, 1),
+            "c": uuid.UUID("7202d115-7ff3-4c81-a7c1-2a1f067b1ece"),
+        }
+        orjson.apply_patch(
+            doc,
+            [
+                {"op": "test", "path": "/a", "value": [1, 2]},
+                {"op": "test", "path": "/b", "value": "2020-01-01"},
+                {"op": "test", "path": "/c", "value": "7202d115-7ff3-4c81-a7c1-2a1f067b1ece"},
+            ],
+        )
+        with pytest.raises(ValueError):
+            orjson.apply_patch({"a": 1}, [{"op": "test", "path": "/a", "value": True}])
+
+    def test_apply_patch_test_number(self):
+        """
+        apply_patch() test compares int and float numerically
+        """
+        assert orjson.apply_patch(
+            {"a": 1, "b": [2.0]},
+            [
+                {"op": "test", "path": "/a", "value": 1.0},
+                {"op": "test", "path": "/b", "value": [2]},
+            ],
+        ) == {"a": 1, "b": [2.0]}
+        for value in (1.5, True, "1"):
+            with pytest.raises(ValueError):
+                orjson.apply_patch(
+                    {"a": 1}, [{"op": "test", "path": "/a", "value": value}]
+                )
+
+    def test_apply_patch_ops_type(self):
+        """
+        apply_patch() ops must be a list of dict
+        """
+        with pytest.raises(TypeError):
+            orjson.apply_patch({}, {"op": "remove", "path": "/a"})
+        with pytest.raises(TypeError):
+            orjson.apply_patch({}, [["remove", "/a"]])
+
+    def test_apply_patch_args(self):
+        """
+        apply_patch() requires two positional arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.apply_patch({})  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.apply_patch({}, [], [])  # type: ignore
+
+
+DIFFS = (
+    ({"a": 1}, {"a": 1}),
+    ({"a": 1, "b": 2}, {"b": 3, "c": 4}),
+    ({"a": {"b": {"c": 1}}}, {"a": {"b": {"c": 2, "d": 3}}}),
+    ([1, 2, 3], [1, 3]),
+    ([1, 2, 3], [0, 1, 2, 3, 4]),
+    ([1, 2, 3, 4, 5], [1, 5]),
+    ([{"a": 1}, 2], [{"a": 2}, 2, 3]),
+    ({"a": [1]}, {"a": {"0": 1}}),
+    ({"a/b": 1, "c~d": 2}, {"a/b": 2, "c~d": 3}),
+    ([], None),
+    ("a", ["a"]),
+    ({"a": (1, 2)}, {"a": [1, 2, 3]}),
+)
+
+
+class TestDiff:
+    @pytest.mark.parametrize("a,b", DIFFS)
+    def test_diff(self, a, b):
+        """
+        diff() result produces b from a
+        """
+        assert orjson.dumps(orjson.apply_patch(a, orjson.diff(a, b))) == orjson.dumps(b)
+
+    def test_diff_ops(self):
+        """
+        diff() operations
+        """
+        assert orjson.diff({"a": 1, "b": [1, 2, 3]}, {"b": [1, 3], "c": True}) == [
+            {"op": "remove", "path": "/a"},
+            {"op": "remove", "path": "/b/1"},
+            {"op": "add", "path": "/c", "value": True},
+        ]
+        assert orjson.diff([1, 2], [1, 2, 3, 4]) == [
+            {"op": "add", "path": "/2", "value": 3},
+            {"op": "add", "path": "/3", "value": 4},
+        ]
+        assert orjson.diff(1, 2) == [{"op": "replace", "path": "", "value": 2}]
+
+    def test_diff_escape(self):
+        """
+        diff() escapes ~ and / in paths
+        """
+        assert orjson.diff({"a/b~": 1}, {"a/b~": 2}) == [
+            {"op": "replace", "path": "/a~1b~0", "value": 2},
+        ]
+
+    def test_diff_json_types(self):
+        """
+        diff() compares as JSON
+        """
+        assert orjson.diff({"a": 1}, {"a": True}) == [
+            {"op": "replace", "path": "/a", "value": True},
+        ]
+        assert orjson.diff({"a": 1.0}, {"a": 1}) == []
+        assert orjson.diff({"a": 1}, {"a": 1.0}) == []
+        assert orjson.diff([2**53 + 1], [float(2**53 + 1)]) == [
+            {"op": "replace", "path": "/0", "value": float(2**53 + 1)},
+        ]
+        assert orjson.diff({"a": 1.0}, {"a": True}) == [
+            {"op": "replace", "path": "/a", "value": True},
+        ]
+        assert orjson.diff({"a": (1, 2)}, {"a": [1, 2]}) == []
+        assert orjson.diff(
+            [datetime.datetime(2020, 1, 1, 12, 0), uuid.UUID(int=1)],
+            ["2020-01-01T12:00:00", "00000000-0000-0000-0000-000000000001"],
+        ) == []
+        assert orjson.diff(
+            {"a": datetime.date(2020, 1, 1)},
+            {"a": datetime.date(2020, 1, 2)},
+        ) == [{"op": "replace", "path": "/a", "value": datetime.date(2020, 1, 2)}]
+
+    def test_diff_shares_values(self):
+        """
+        diff() operations share values with b
+        """
+        b = {"a": [1]}
+        assert orjson.diff({}, b)[0]["value"] is b["a"]
+
+    def test_diff_non_str_key(self):
+        """
+        diff() raises on dict keys that are not str
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.diff({1: 1}, {1: 2})
+
+    def test_diff_unsupported(self):
+        """
+        diff() raises on values that cannot be serialized
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.diff({"a": object()}, {"a": 1j})
+
+    def test_diff_recursion(self):
+        """
+        diff() of deeply nested values
+        """
+        a: list = []
+        b: list = []
+        for _ in range(10000):
+            a = [a]
+            b = [b]
+        b = [b, 1]
+        with pytest.raises(RecursionError):
+            orjson.diff(a, b)
+
+    def test_diff_args(self):
+        """
+        diff() requires two positional arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.diff({})  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.diff({}, {}, {})  # type: ignore
@@ -0,0 +1,443 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import collections
+import dataclasses
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Dataclass:
+    user_id: int
+    display_name: str = dataclasses.field(metadata={"orjson": {"name": "the_name"}})
+
+
+@dataclasses.dataclass
+class SlotsDataclass:
+    __slots__ = ("user_id", "first_name")
+    user_id: int
+    first_name: str
+
+
+@dataclasses.dataclass
+class PostInitDataclass:
+    user_id: int
+
+    def __post_init__(self):
+        self.created_at = 0
+
+
+@dataclasses.dataclass
+class CollidingDataclass:
+    user_id: int
+    other: int = dataclasses.field(metadata={"orjson": {"name": "userId"}})
+
+
+class TestKeyCase:
+    def test_camel_case_keys(self):
+        """
+        OPT_CAMEL_CASE_KEYS
+        """
+        obj = {
+            "user_id": 1,
+            "first_name_of_user": 2,
+            "id": 3,
+            "_private_id": 4,
+            "trailing_": 5,
+            "alreadyCamel": 6,
+            "nested": {"inner_key": [{"list_key": 7}]},
+        }
+        assert orjson.dumps(obj, option=orjson.OPT_CAMEL_CASE_KEYS) == (
+            b'{"userId":1,"firstNameOfUser":2,"id":3,"_privateId":4,'
+            b'"trailing_":5,"alreadyCamel":6,"nested":{"innerKey":[{"listKey":7}]}}'
+        )
+
+    def test_pascal_case_keys(self):
+        """
+        OPT_PASCAL_CASE_KEYS
+        """
+        obj = {"user_id": 1, "id": 2, "_private_id": 3, "Already": 4}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_PASCAL_CASE_KEYS)
+            == b'{"UserId":1,"Id":2,"_PrivateId":3,"Already":4}'
+        )
+
+    def test_kebab_case_keys(self):
+        """
+        OPT_KEBAB_CASE_KEYS
+        """
+        obj = {"user_id": 1, "id": 2, "_private_id": 3, "a_b_c": 4}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_KEBAB_CASE_KEYS)
+            == b'{"user-id":1,"id":2,"_private-id":3,"a-b-c":4}'
+        )
+
+    def test_key_case_non_ascii(self):
+        """
+        OPT_CAMEL_CASE_KEYS and OPT_PASCAL_CASE_KEYS upper-case non-ASCII
+        letters
+        """
+        assert (
+            orjson.dumps({"é_ü": 1}, option=orjson.OPT_CAMEL_CASE_KEYS)
+            == '{"éÜ":1}'.encode("utf-8")
+        )
+        assert (
+            orjson.dumps({"é_ü": 1, "ß_x": 2}, option=orjson.OPT_PASCAL_CASE_KEYS)
+            == '{"ÉÜ":1,"SSX":2}'.encode("utf-8")
+        )
+        assert (
+            orjson.dumps({"Éü": 1}, option=orjson.OPT_PASCAL_CASE_KEYS)
+            == '{"Éü":1}'.encode("utf-8")
+        )
+
+    def test_key_case_escaped(self):
+        """
+        OPT_CAMEL_CASE_KEYS key that must be escaped
+        """
+        assert (
+            orjson.dumps({'a_b"': 1}, option=orjson.OPT_CAMEL_CASE_KEYS)
+            == b'{"aB\\"":1}'
+        )
+
+    def test_key_case_long_key(self):
+        """
+        OPT_CAMEL_CASE_KEYS key longer than those cached
+        """
+        key = "_".join(["word"] * 32)
+        expected = "word" + "Word" * 31
+        for _ in range(2):
+            assert orjson.loads(
+                orjson.dumps({key: 1}, option=orjson.OPT_CAMEL_CASE_KEYS),
+            ) == {expected: 1}
+
+    def test_key_case_repeated(self):
+        """
+        OPT_CAMEL_CASE_KEYS and OPT_KEBAB_CASE_KEYS using the cache
+        """
+        for _ in range(3):
+            assert (
+                orjson.dumps({"a_b": 1}, option=orjson.OPT_CAMEL_CASE_KEYS)
+                == b'{"aB":1}'
+            )
+            assert (
+                orjson.dumps({"a_b": 1}, option=orjson.OPT_KEBAB_CASE_KEYS)
+                == b'{"a-b":1}'
+            )
+
+    def test_key_case_sort_keys(self):
+        """
+        OPT_CAMEL_CASE_KEYS sorts by the converted key
+        """
+        obj = {"a_c": 1, "ab": 2, "a_b": 3}
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_SORT_KEYS,
+            )
+            == b'{"aB":3,"aC":1,"ab":2}'
+        )
+
+    def test_key_case_omit_none(self):
+        """
+        OPT_CAMEL_CASE_KEYS and OPT_OMIT_NONE
+        """
+        assert (
+            orjson.dumps(
+                {"a_b": None, "c_d": 1},
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_OMIT_NONE,
+            )
+            == b'{"cD":1}'
+        )
+
+    def test_key_case_indent(self):
+        """
+        OPT_CAMEL_CASE_KEYS and OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                {"a_b": {"c_d": 1}},
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "aB": {\n    "cD": 1\n  }\n}'
+        )
+
+    def test_key_case_non_str_keys(self):
+        """
+        OPT_CAMEL_CASE_KEYS and OPT_NON_STR_KEYS
+        """
+        assert (
+            orjson.dumps(
+                {"a_b": 1, 2: 3},
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"aB":1,"2":3}'
+        )
+
+    def test_key_case_non_str_key_error(self):
+        """
+        OPT_CAMEL_CASE_KEYS raises on a non-str key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({1: 1}, option=orjson.OPT_CAMEL_CASE_KEYS)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {"a": 1, 1: 1},
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_SORT_KEYS,
+            )
+
+    def test_key_case_collision(self):
+        """
+        key case option raises on a converted key equal to another key
+        """
+        for option in (
+            orjson.OPT_CAMEL_CASE_KEYS,
+            orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_SORT_KEYS,
+            orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_NON_STR_KEYS,
+        ):
+            for obj in ({"a_b": 1, "aB": 2}, {"aB": 1, "a_b": 2}, {"a_b": 1, "a__b": 2}):
+                with pytest.raises(orjson.JSONEncodeError):
+                    orjson.dumps(obj, option=option)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({"a-b": 1, "a_b": 2}, option=orjson.OPT_KEBAB_CASE_KEYS)
+        assert (
+            orjson.dumps(
+                {"a_b": 1, "aB": None},
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_OMIT_NONE,
+            )
+            == b'{"aB":1}'
+        )
+        assert (
+            orjson.dumps(
+                {1: 1, "1": 2},
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_NON_STR_KEYS,
+            )
+            == b'{"1":1,"1":2}'
+        )
+
+    def test_key_case_collision_dataclass(self):
+        """
+        key case option raises on a converted dataclass key equal to a
+        renamed field
+        """
+        obj = CollidingDataclass(1, 2)
+        assert orjson.dumps(obj) == b'{"user_id":1,"userId":2}'
+        for option in (
+            orjson.OPT_CAMEL_CASE_KEYS,
+            orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_CANONICAL,
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=option)
+
+    def test_key_case_collision_namedtuple(self):
+        """
+        key case option raises on a converted named tuple key equal to another
+        """
+        obj = collections.namedtuple("Colliding", ("a_b", "aB"))(1, 2)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_SERIALIZE_NAMEDTUPLE,
+            )
+
+    def test_key_case_invalid(self):
+        """
+        more than one key case option, or OPT_SNAKE_CASE_KEYS, raises
+        """
+        for option in (
+            orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_PASCAL_CASE_KEYS,
+            orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_KEBAB_CASE_KEYS,
+            orjson.OPT_PASCAL_CASE_KEYS | orjson.OPT_KEBAB_CASE_KEYS,
+            orjson.OPT_SNAKE_CASE_KEYS,
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps({}, option=option)
+
+    def test_key_case_dataclass(self):
+        """
+        OPT_CAMEL_CASE_KEYS dataclass does not convert a renamed field
+        """
+        obj = Dataclass(1, "a")
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_CAMEL_CASE_KEYS)
+            == b'{"userId":1,"the_name":"a"}'
+        )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_CAMEL_CASE_KEYS | orjson.OPT_CANONICAL,
+            )
+            == b'{"the_name":"a","userId":1}'
+        )
+
+    def test_key_case_dataclass_slots(self):
+        """
+        OPT_PASCAL_CASE_KEYS dataclass with __slots__
+        """
+        assert (
+            orjson.dumps(SlotsDataclass(1, "a"), option=orjson.OPT_PASCAL_CASE_KEYS)
+            == b'{"UserId":1,"FirstName":"a"}'
+        )
+
+    def test_key_case_dataclass_attribute(self):
+        """
+        OPT_KEBAB_CASE_KEYS dataclass attribute that is not a field
+        """
+        assert (
+            orjson.dumps(PostInitDataclass(1), option=orjson.OPT_KEBAB_CASE_KEYS)
+            == b'{"user-id":1,"created-at":0}'
+        )
+
+    def test_key_case_msgpack(self):
+        """
+        dumps_msgpack() OPT_CAMEL_CASE_KEYS
+        """
+        obj = {"user_id": 1, "nested": Dataclass(2, "a")}
+        assert orjson.loads_msgpack(
+            orjson.dumps_msgpack(obj, option=orjson.OPT_CAMEL_CASE_KEYS),
+        ) == {"userId": 1, "nested": {"userId": 2, "the_name": "a"}}
+
+    def test_key_case_cbor(self):
+        """
+        dumps_cbor() OPT_KEBAB_CASE_KEYS
+        """
+        assert orjson.loads_cbor(
+            orjson.dumps_cbor({"user_id": 1}, option=orjson.OPT_KEBAB_CASE_KEYS),
+        ) == {"user-id": 1}
+
+    def test_loads_snake_case_keys(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS
+        """
+        doc = (
+            b'{"userId": 1, "UserName": 2, "user-email": 3, "HTTPServer": 4,'
+            b'"parseURL": 5, "line2Item": 6, "id": 7, "_privateId": 8,'
+            b'"nested": [{"innerKey": {"deepKey": 9}}]}'
+        )
+        assert orjson.loads(doc, option=orjson.OPT_SNAKE_CASE_KEYS) == {
+            "user_id": 1,
+            "user_name": 2,
+            "user_email": 3,
+            "http_server": 4,
+            "parse_url": 5,
+            "line2_item": 6,
+            "id": 7,
+            "_private_id": 8,
+            "nested": [{"inner_key": {"deep_key": 9}}],
+        }
+        assert orjson.loads(doc)["userId"] == 1
+
+    def test_loads_snake_case_keys_non_ascii(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS lower-cases non-ASCII letters
+        """
+        assert orjson.loads(
+            '{"éÜ": 1, "Éü": 2}'.encode("utf-8"),
+            option=orjson.OPT_SNAKE_CASE_KEYS,
+        ) == {"é_ü": 1, "éü": 2}
+
+    def test_loads_snake_case_keys_none(self):
+        """
+        loads() option=None
+        """
+        assert orjson.loads(b'{"userId": 1}', option=None) == {"userId": 1}
+        assert orjson.loads(b'{"userId": 1}', option=0) == {"userId": 1}
+
+    def test_loads_snake_case_keys_str(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS str input
+        """
+        assert orjson.loads('{"userId": 1}', option=orjson.OPT_SNAKE_CASE_KEYS) == {
+            "user_id": 1,
+        }
+
+    def test_loads_snake_case_keys_pointer(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS and pointer
+        """
+        assert orjson.loads(
+            b'{"data": {"userId": 1}}',
+            pointer="/data",
+            option=orjson.OPT_SNAKE_CASE_KEYS,
+        ) == {"user_id": 1}
+
+    def test_loads_snake_case_keys_keys(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS selects by the key in the document
+        """
+        doc = b'{"userId": {"HTTPServer": 1, "other": 2}, "skipMe": 3}'
+        assert orjson.loads(
+            doc,
+            keys={"userId": {"HTTPServer"}},
+            option=orjson.OPT_SNAKE_CASE_KEYS,
+        ) == {"user_id": {"http_server": 1}}
+
+    def test_loads_snake_case_keys_collision(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS raises if different keys convert to the
+        same key, at the later key
+        """
+        for doc, pos in (
+            (b'{"aB": 1, "a_b": 2}', 10),
+            (b'{"a_b": 1, "aB": 2}', 11),
+            (b'[{"x": {"userId": 1, "user-id": 2}}]', 21),
+            (b'{"\\u0061B": 1, "a_b": 2}', 15),
+        ):
+            with pytest.raises(orjson.JSONDecodeError, match="collides") as exc_info:
+                orjson.loads(doc, option=orjson.OPT_SNAKE_CASE_KEYS)
+            assert exc_info.value.pos == pos
+            orjson.loads(doc)
+        assert orjson.loads(
+            b'{"aB": 1, "aB": 2}', option=orjson.OPT_SNAKE_CASE_KEYS
+        ) == {"a_b": 2}
+
+    def test_loads_snake_case_keys_collision_selected(self):
+        """
+        loads() OPT_SNAKE_CASE_KEYS raises on a collision only between keys
+        that are deserialized
+        """
+        doc = b'{"data": {"aB": 1, "a_b": 2}, "c": 3}'
+        option = orjson.OPT_SNAKE_CASE_KEYS
+        assert orjson.loads(doc, keys={"c"}, option=option) == {"c": 3}
+        assert orjson.loads(doc, keys={"data": {"aB"}}, option=option) == {
+            "data": {"a_b": 1}
+        }
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(doc, keys={"data"}, option=option)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(doc, pointer="/data", option=option)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(doc, pointer=("/c", "/data"), option=option)
+
+    def test_loads_snake_case_keys_invalid(self):
+        """
+        loads() raises on an option other than OPT_SNAKE_CASE_KEYS
+        """
+        for option in (
+            orjson.OPT_CAMEL_CASE_KEYS,
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_SNAKE_CASE_KEYS | orjson.OPT_SORT_KEYS,
+            -1,
+        ):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(b"{}", option=option)
+
+    def test_key_case_roundtrip(self):
+        """
+        OPT_CAMEL_CASE_KEYS and OPT_SNAKE_CASE_KEYS round-trip
+        """
+        obj = {"user_id": 1, "first_name": {"last_login_at": [{"is_admin": True}]}}
+        for option in (
+            orjson.OPT_CAMEL_CASE_KEYS,
+            orjson.OPT_PASCAL_CASE_KEYS,
+            orjson.OPT_KEBAB_CASE_KEYS,
+        ):
+            assert (
+                orjson.loads(
+                    orjson.dumps(obj, option=option),
+                    option=orjson.OPT_SNAKE_CASE_KEYS,
+                )
+                == obj
+            )
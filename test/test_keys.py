@@ -0,0 +1,110 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+DOCUMENT = b"""{
+    "id": 1,
+    "user": {"name": "a", "tags": ["x"], "meta": {"created": 2, "updated": 3}},
+    "items": [{"id": 1, "price": 2.5, "extra": null}, {"id": 2}, 3, [{"id": 4, "x": 5}]],
+    "\xc3\xa9": true
+}"""
+
+
+class TestKeys:
+    def test_keys_set(self):
+        assert orjson.loads(DOCUMENT, keys={"id", "é"}) == {"id": 1, "é": True}
+
+    def test_keys_frozenset(self):
+        assert orjson.loads(DOCUMENT, keys=frozenset(("id",))) == {"id": 1}
+
+    def test_keys_empty(self):
+        assert orjson.loads(DOCUMENT, keys=set()) == {}
+        assert orjson.loads(DOCUMENT, keys={}) == {}
+
+    def test_keys_missing(self):
+        assert orjson.loads(DOCUMENT, keys={"id", "nope"}) == {"id": 1}
+
+    def test_keys_none(self):
+        assert orjson.loads(DOCUMENT, keys=None) == orjson.loads(DOCUMENT)
+
+    def test_keys_dict_true(self):
+        assert orjson.loads(DOCUMENT, keys={"user": True}) == {
+            "user": {"name": "a", "tags": ["x"], "meta": {"created": 2, "updated": 3}},
+        }
+
+    def test_keys_dict_nested(self):
+        assert orjson.loads(
+            DOCUMENT,
+            keys={"id": True, "user": {"name": True, "meta": {"updated"}}},
+        ) == {"id": 1, "user": {"name": "a", "meta": {"updated": 3}}}
+
+    def test_keys_array(self):
+        assert orjson.loads(DOCUMENT, keys={"items": {"id"}}) == {
+            "items": [{"id": 1}, {"id": 2}, 3, [{"id": 4}]],
+        }
+
+    def test_keys_root_array(self):
+        assert orjson.loads(b'[{"a": 1, "b": 2}, {"b": 3}]', keys={"a"}) == [
+            {"a": 1},
+            {},
+        ]
+
+    def test_keys_scalar(self):
+        assert orjson.loads(b"1", keys={"a"}) == 1
+        assert orjson.loads(DOCUMENT, keys={"id": {"a"}}) == {"id": 1}
+
+    def test_keys_duplicate(self):
+        assert orjson.loads(b'{"a": 1, "b": 2, "a": 3}', keys={"a"}) == {"a": 3}
+
+    def test_keys_escape(self):
+        assert orjson.loads(b'{"a\\u0062": 1, "c": 2}', keys={"ab"}) == {"ab": 1}
+
+    def test_keys_pointer(self):
+        assert orjson.loads(DOCUMENT, pointer="/user", keys={"name"}) == {"name": "a"}
+        assert orjson.loads(
+            DOCUMENT,
+            pointer=("/user/meta", "/items"),
+            keys={"id", "created"},
+        ) == ({"created": 2}, [{"id": 1}, {"id": 2}, 3, [{"id": 4}]])
+
+    def test_keys_invalid_document(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(b'{"a": 1, "b": [}', keys={"a"})
+
+    def test_keys_invalid_type(self):
+        for keys in (
+            "a",
+            ["a"],
+            ("a",),
+            {1},
+            {b"a"},
+            {"a": 1},
+            {"a": None},
+            {"a": False},
+            {"a": {"b": ["c"]}},
+            {1: True},
+        ):
+            with pytest.raises(TypeError):
+                orjson.loads(DOCUMENT, keys=keys)  # type: ignore
+
+    def test_keys_recursive(self):
+        keys: dict = {}
+        keys["a"] = keys
+        with pytest.raises(TypeError):
+            orjson.loads(DOCUMENT, keys=keys)
+
+    @needs_data
+    def test_keys_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        expected = orjson.loads(data)
+        assert orjson.loads(data, keys={"statuses": {"id": True, "user": {"id"}}}) == {
+            "statuses": [
+                {"id": each["id"], "user": {"id": each["user"]["id"]}}
+                for each in expected["statuses"]
+            ],
+        }
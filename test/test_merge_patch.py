@@ -0,0 +1,219 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+# https://www.rfc-editor.org/rfc/rfc7396#appendix-A
+EXAMPLES = (
+    ({"a": "b"}, {"a": "c"}, {"a": "c"}),
+    ({"a": "b"}, {"b": "c"}, {"a": "b", "b": "c"}),
+    ({"a": "b"}, {"a": None}, {}),
+    ({"a": "b", "b": "c"}, {"a": None}, {"b": "c"}),
+    ({"a": ["b"]}, {"a": "c"}, {"a": "c"}),
+    ({"a": "c"}, {"a": ["b"]}, {"a": ["b"]}),
+    ({"a": {"b": "c"}}, {"a": {"b": "d", "c": None}}, {"a": {"b": "d"}}),
+    ({"a": [{"b": "c"}]}, {"a": [1]}, {"a": [1]}),
+    (["a", "b"], ["c", "d"], ["c", "d"]),
+    ({"a": "b"}, ["c"], ["c"]),
+    ({"a": "foo"}, None, None),
+    ({"a": "foo"}, "bar", "bar"),
+    ({"e": None}, {"a": 1}, {"e": None, "a": 1}),
+    ([1, 2], {"a": "b", "c": None}, {"a": "b"}),
+    ({}, {"a": {"bb": {"ccc": None}}}, {"a": {"bb": {}}}),
+)
+
+
+class TestMergePatch:
+    @pytest.mark.parametrize("target,patch,expected", EXAMPLES)
+    def test_merge_patch(self, target, patch, expected):
+        """
+        merge_patch() RFC 7396 examples
+        """
+        assert orjson.merge_patch(target, patch) == expected
+
+    def test_merge_patch_does_not_modify(self):
+        """
+        merge_patch() does not modify target or patch
+        """
+        target = {"a": 1, "b": {"c": 2}}
+        patch = {"a": None, "b": {"d": 3}}
+        assert orjson.merge_patch(target, patch) == {"b": {"c": 2, "d": 3}}
+        assert target == {"a": 1, "b": {"c": 2}}
+        assert patch == {"a": None, "b": {"d": 3}}
+
+    def test_merge_patch_shares_unchanged(self):
+        """
+        merge_patch() result shares values patch does not change
+        """
+        target = {"a": [1], "b": {"c": 2}}
+        result = orjson.merge_patch(target, {"d": 1})
+        assert result["a"] is target["a"]
+        assert result["b"] is target["b"]
+
+    def test_merge_patch_order(self):
+        """
+        merge_patch() keeps target order and appends new members
+        """
+        assert list(orjson.merge_patch({"b": 1, "a": 2}, {"c": 3, "b": 4})) == [
+            "b",
+            "a",
+            "c",
+        ]
+
+    def test_merge_patch_recursion(self):
+        """
+        merge_patch() patch that contains itself
+        """
+        patch: dict = {}
+        patch["a"] = patch
+        with pytest.raises(RecursionError):
+            orjson.merge_patch({}, patch)
+
+    def test_merge_patch_args(self):
+        """
+        merge_patch() requires two positional arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.merge_patch({})  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.merge_patch({}, {}, {})  # type: ignore
+
+
+class TestCreateMergePatch:
+    @pytest.mark.parametrize("source,_patch,target", EXAMPLES)
+    def test_create_merge_patch(self, source, _patch, target):
+        """
+        create_merge_patch() result produces target from source
+        """
+        patch = orjson.create_merge_patch(source, target)
+        assert orjson.merge_patch(source, patch) == target
+
+    def test_create_merge_patch_minimal(self):
+        """
+        create_merge_patch() omits equal members
+        """
+        assert orjson.create_merge_patch(
+            {"a": 1, "b": {"c": 2, "d": 3}, "e": [1, {"f": 1}]},
+            {"b": {"c": 4, "d": 3}, "e": [1, {"f": 1}], "g": {"h": 1}},
+        ) == {"a": None, "b": {"c": 4}, "g": {"h": 1}}
+
+    def test_create_merge_patch_equal(self):
+        """
+        create_merge_patch() of equal objects is empty
+        """
+        assert orjson.create_merge_patch({"a": [1, 2]}, {"a": [1, 2]}) == {}
+
+    def test_create_merge_patch_json_types(self):
+        """
+        create_merge_patch() compares as JSON
+        """
+        assert orjson.create_merge_patch({"a": 1, "b": 0}, {"a": True, "b": 0}) == {
+            "a": True,
+        }
+        assert orjson.create_merge_patch({"a": (1, 2)}, {"a": [1, 2]}) == {}
+
+    def test_create_merge_patch_null_member(self):
+        """
+        create_merge_patch() cannot set a member to null
+        """
+        with pytest.raises(ValueError):
+            orjson.create_merge_patch({"a": 1}, {"a": None})
+        with pytest.raises(ValueError):
+            orjson.create_merge_patch({}, {"a": {"b": None}})
+        with pytest.raises(ValueError):
+            orjson.create_merge_patch([], {"a": None})
+
+    def test_create_merge_patch_null_member_equal(self):
+        """
+        create_merge_patch() null member equal in source
+        """
+        assert orjson.create_merge_patch({"a": None}, {"a": None, "b": 1}) == {"b": 1}
+
+    def test_create_merge_patch_null_in_array(self):
+        """
+        create_merge_patch() null in an array is replaced as is
+        """
+        assert orjson.create_merge_patch({"a": 1}, {"a": [None]}) == {"a": [None]}
+
+
+class TestMergePatchJson:
+    @pytest.mark.parametrize("target,patch,expected", EXAMPLES)
+    def test_merge_patch_json(self, target, patch, expected):
+        """
+        merge_patch_json() RFC 7396 examples
+        """
+        assert orjson.loads(
+            orjson.merge_patch_json(orjson.dumps(target), orjson.dumps(patch)),
+        ) == expected
+
+    def test_merge_patch_json_str(self):
+        """
+        merge_patch_json() str input
+        """
+        assert orjson.merge_patch_json('{"a":1}', '{"b":2}') == b'{"a":1,"b":2}'
+
+    def test_merge_patch_json_numbers(self):
+        """
+        merge_patch_json() preserves the text of numbers
+        """
+        assert (
+            orjson.merge_patch_json(
+                b'{"a": 1.50, "b": 12345678901234567890123}',
+                b'{"c": 1E3}',
+            )
+            == b'{"a":1.50,"b":12345678901234567890123,"c":1E3}'
+        )
+
+    def test_merge_patch_json_duplicate_keys(self):
+        """
+        merge_patch_json() last of duplicate keys at position of first
+        """
+        assert (
+            orjson.merge_patch_json(b'{"a": 1, "b": 2, "a": 3}', b'{"c": null, "c": 4}')
+            == b'{"a":3,"b":2,"c":4}'
+        )
+
+    def test_merge_patch_json_option(self):
+        """
+        merge_patch_json() option
+        """
+        assert (
+            orjson.merge_patch_json(
+                b'{"b": {"y": 1, "x": 2}}',
+                b'{"a": 1}',
+                option=orjson.OPT_SORT_KEYS | orjson.OPT_APPEND_NEWLINE,
+            )
+            == b'{"a":1,"b":{"x":2,"y":1}}\n'
+        )
+        assert orjson.merge_patch_json(
+            b"{}",
+            b'{"a": [1]}',
+            orjson.OPT_INDENT_2,
+        ) == orjson.dumps({"a": [1]}, option=orjson.OPT_INDENT_2)
+
+    def test_merge_patch_json_option_invalid(self):
+        """
+        merge_patch_json() option not supported
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.merge_patch_json(b"{}", b"{}", option=orjson.OPT_NAIVE_UTC)
+
+    def test_merge_patch_json_invalid(self):
+        """
+        merge_patch_json() invalid target or patch
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.merge_patch_json(b"{", b"{}")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.merge_patch_json(b"{}", b"[")
+
+    def test_merge_patch_json_args(self):
+        """
+        merge_patch_json() requires two positional arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.merge_patch_json(b"{}")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.merge_patch_json(b"{}", b"{}", option=None, other=1)  # type: ignore
@@ -0,0 +1,412 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import datetime
+import enum
+import uuid
+
+import pytest
+
+import orjson
+
+from .util import IS_FREETHREADING, numpy
+
+# https://github.com/msgpack/msgpack/blob/master/spec.md
+SCALARS = (
+    (None, b"\xc0"),
+    (False, b"\xc2"),
+    (True, b"\xc3"),
+    (0, b"\x00"),
+    (127, b"\x7f"),
+    (128, b"\xcc\x80"),
+    (255, b"\xcc\xff"),
+    (256, b"\xcd\x01\x00"),
+    (65535, b"\xcd\xff\xff"),
+    (65536, b"\xce\x00\x01\x00\x00"),
+    (4294967295, b"\xce\xff\xff\xff\xff"),
+    (4294967296, b"\xcf\x00\x00\x00\x01\x00\x00\x00\x00"),
+    (18446744073709551615, b"\xcf\xff\xff\xff\xff\xff\xff\xff\xff"),
+    (-1, b"\xff"),
+    (-32, b"\xe0"),
+    (-33, b"\xd0\xdf"),
+    (-128, b"\xd0\x80"),
+    (-129, b"\xd1\xff\x7f"),
+    (-32768, b"\xd1\x80\x00"),
+    (-32769, b"\xd2\xff\xff\x7f\xff"),
+    (-2147483648, b"\xd2\x80\x00\x00\x00"),
+    (-2147483649, b"\xd3\xff\xff\xff\xff\x7f\xff\xff\xff"),
+    (-9223372036854775808, b"\xd3\x80\x00\x00\x00\x00\x00\x00\x00"),
+    (1.5, b"\xcb\x3f\xf8\x00\x00\x00\x00\x00\x00"),
+    (float("inf"), b"\xcb\x7f\xf0\x00\x00\x00\x00\x00\x00"),
+    ("", b"\xa0"),
+    ("a", b"\xa1a"),
+    ("é", b"\xa2\xc3\xa9"),
+    ("x" * 31, b"\xbf" + b"x" * 31),
+    ("x" * 32, b"\xd9\x20" + b"x" * 32),
+    ("x" * 256, b"\xda\x01\x00" + b"x" * 256),
+    ("x" * 65536, b"\xdb\x00\x01\x00\x00" + b"x" * 65536),
+    (b"", b"\xc4\x00"),
+    (b"\x00\xff", b"\xc4\x02\x00\xff"),
+    (b"x" * 256, b"\xc5\x01\x00" + b"x" * 256),
+    (b"x" * 65536, b"\xc6\x00\x01\x00\x00" + b"x" * 65536),
+)
+
+
+class TestMsgpack:
+    @pytest.mark.parametrize("obj,expected", SCALARS)
+    def test_dumps_msgpack_scalar(self, obj, expected):
+        """
+        dumps_msgpack() scalars in their smallest format
+        """
+        assert orjson.dumps_msgpack(obj) == expected
+
+    def test_dumps_msgpack_float_nan(self):
+        """
+        dumps_msgpack() nan is not null
+        """
+        assert orjson.dumps_msgpack(float("nan"))[:3] == b"\xcb\x7f\xf8"
+
+    def test_dumps_msgpack_array(self):
+        """
+        dumps_msgpack() array formats
+        """
+        assert orjson.dumps_msgpack([]) == b"\x90"
+        assert orjson.dumps_msgpack(()) == b"\x90"
+        assert orjson.dumps_msgpack([1, [2, []]]) == b"\x92\x01\x92\x02\x90"
+        assert orjson.dumps_msgpack((1, 2)) == b"\x92\x01\x02"
+        assert orjson.dumps_msgpack([0] * 15) == b"\x9f" + b"\x00" * 15
+        assert orjson.dumps_msgpack([0] * 16) == b"\xdc\x00\x10" + b"\x00" * 16
+        assert (
+            orjson.dumps_msgpack([0] * 65536) == b"\xdd\x00\x01\x00\x00" + b"\x00" * 65536
+        )
+
+    def test_dumps_msgpack_map(self):
+        """
+        dumps_msgpack() map formats
+        """
+        assert orjson.dumps_msgpack({}) == b"\x80"
+        assert orjson.dumps_msgpack({"a": {"b": {}}}) == b"\x81\xa1a\x81\xa1b\x80"
+        obj = {str(idx): None for idx in range(16)}
+        assert orjson.dumps_msgpack(obj)[:3] == b"\xde\x00\x10"
+
+    def test_dumps_msgpack_datetime(self):
+        """
+        dumps_msgpack() datetime, date, time as str
+        """
+        assert (
+            orjson.dumps_msgpack(datetime.datetime(1970, 1, 1))
+            == b"\xb31970-01-01T00:00:00"
+        )
+        assert orjson.dumps_msgpack(datetime.date(1970, 1, 1)) == b"\xaa1970-01-01"
+        assert orjson.dumps_msgpack(datetime.time(12, 0)) == b"\xa812:00:00"
+        assert (
+            orjson.dumps_msgpack(
+                datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc),
+                option=orjson.OPT_UTC_Z,
+            )
+            == b"\xb41970-01-01T00:00:00Z"
+        )
+
+    def test_dumps_msgpack_uuid(self):
+        """
+        dumps_msgpack() uuid as str
+        """
+        assert (
+            orjson.dumps_msgpack(uuid.UUID(int=1))
+            == b"\xd9\x2400000000-0000-0000-0000-000000000001"
+        )
+
+    def test_dumps_msgpack_dataclass(self):
+        """
+        dumps_msgpack() dataclass with unknown number of fields
+        """
+
+        @dataclasses.dataclass
+        class Dataclass:
+            a: int
+            _b: int
+            c: list
+
+        assert orjson.dumps_msgpack(Dataclass(1, 2, [])) == b"\x82\xa1a\x01\xa1c\x90"
+        assert orjson.dumps_msgpack([Dataclass(1, 2, [])] * 16)[:4] == b"\xdc\x00\x10\x82"
+
+    def test_dumps_msgpack_dataclass_many_fields(self):
+        """
+        dumps_msgpack() dataclass with more fields than a fixmap
+        """
+        cls = dataclasses.make_dataclass("Dataclass", [f"f{idx}" for idx in range(20)])
+        obj = cls(*range(20))
+        assert orjson.dumps_msgpack(obj)[:3] == b"\xde\x00\x14"
+
+    def test_dumps_msgpack_enum(self):
+        """
+        dumps_msgpack() enum
+        """
+
+        class Enum(enum.Enum):
+            A = "a"
+            B = 1
+
+        assert orjson.dumps_msgpack([Enum.A, Enum.B]) == b"\x92\xa1a\x01"
+
+    def test_dumps_msgpack_default(self):
+        """
+        dumps_msgpack() default
+        """
+
+        class Custom:
+            pass
+
+        assert orjson.dumps_msgpack(Custom(), default=lambda _: [1]) == b"\x91\x01"
+
+    def test_dumps_msgpack_bytes_default(self):
+        """
+        dumps_msgpack() does not pass bytes to default
+        """
+        assert orjson.dumps_msgpack(b"a", default=lambda _: None) == b"\xc4\x01a"
+        assert orjson.dumps(b"a", default=lambda obj: obj.decode()) == b'"a"'
+
+    @pytest.mark.skipif(IS_FREETHREADING, reason="passed to default")
+    def test_dumps_msgpack_bytearray_memoryview(self):
+        """
+        dumps_msgpack() bytearray and memoryview are binary data
+        """
+        assert orjson.dumps_msgpack(bytearray(b"a")) == b"\xc4\x01a"
+        assert orjson.dumps_msgpack(memoryview(b"ab")) == b"\xc4\x02ab"
+        assert (
+            orjson.dumps_msgpack(memoryview(b"abc")[::2], default=bytes)
+            == b"\xc4\x02ac"
+        )
+        released = memoryview(b"a")
+        released.release()
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(released)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(bytearray(b"a"))
+
+    def test_dumps_msgpack_options(self):
+        """
+        dumps_msgpack() sort keys, non-str keys, strict integer
+        """
+        assert (
+            orjson.dumps_msgpack({"b": 1, "a": 2}, option=orjson.OPT_SORT_KEYS)
+            == b"\x82\xa1a\x02\xa1b\x01"
+        )
+        assert (
+            orjson.dumps_msgpack({1: True}, option=orjson.OPT_NON_STR_KEYS)
+            == b"\x81\xa11\xc3"
+        )
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(2**53, option=orjson.OPT_STRICT_INTEGER)
+
+    @pytest.mark.parametrize(
+        "option",
+        (orjson.OPT_APPEND_NEWLINE, orjson.OPT_CANONICAL, orjson.OPT_INDENT_2),
+    )
+    def test_dumps_msgpack_options_json(self, option):
+        """
+        dumps_msgpack() JSON options are invalid
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(1, option=option)
+
+    def test_dumps_msgpack_fragment(self):
+        """
+        dumps_msgpack() Fragment raises
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack([orjson.Fragment(b"{}")])
+
+    def test_dumps_msgpack_unsupported(self):
+        """
+        dumps_msgpack() unsupported type, integer, and key raise
+        """
+        with pytest.raises(
+            orjson.JSONEncodeError, match="^Type is not MessagePack serializable: object$"
+        ):
+            orjson.dumps_msgpack(object())
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(2**64)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack({1: 1})
+
+    def test_dumps_msgpack_recursion(self):
+        """
+        dumps_msgpack() circular reference raises
+        """
+        obj: list = []
+        obj.append(obj)
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(obj)
+
+    def test_dumps_msgpack_args(self):
+        """
+        dumps_msgpack() arguments
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack()  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(1, unknown=1)  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps_msgpack(1, None, None, option=None)  # type: ignore
+
+    @pytest.mark.skipif(numpy is None, reason="numpy is not installed")
+    def test_dumps_msgpack_numpy(self):
+        """
+        dumps_msgpack() numpy
+        """
+        assert (
+            orjson.dumps_msgpack(
+                numpy.array([[1, 2], [3, 4]], numpy.int32),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"\x92\x92\x01\x02\x92\x03\x04"
+        )
+        assert (
+            orjson.dumps_msgpack(
+                numpy.array([1.5], numpy.float32),
+                option=orjson.OPT_SERIALIZE_NUMPY,
+            )
+            == b"\x91\xca\x3f\xc0\x00\x00"
+        )
+
+    @pytest.mark.parametrize("expected,data", SCALARS)
+    def test_loads_msgpack_scalar(self, expected, data):
+        """
+        loads_msgpack() scalars
+        """
+        assert orjson.loads_msgpack(data) == expected
+
+    def test_loads_msgpack_formats(self):
+        """
+        loads_msgpack() formats dumps_msgpack() does not write
+        """
+        assert orjson.loads_msgpack(b"\xca\x3f\xc0\x00\x00") == 1.5
+        assert orjson.loads_msgpack(b"\xd9\x01a") == "a"
+        assert orjson.loads_msgpack(b"\xdc\x00\x01\x01") == [1]
+        assert orjson.loads_msgpack(b"\xdd\x00\x00\x00\x01\x01") == [1]
+        assert orjson.loads_msgpack(b"\xde\x00\x01\xa1a\x01") == {"a": 1}
+        assert orjson.loads_msgpack(b"\xdf\x00\x00\x00\x01\xa1a\x01") == {"a": 1}
+        assert orjson.loads_msgpack(b"\xcc\x01") == 1
+        assert orjson.loads_msgpack(b"\xd3\x00\x00\x00\x00\x00\x00\x00\x01") == 1
+
+    def test_loads_msgpack_roundtrip(self):
+        """
+        loads_msgpack() of dumps_msgpack()
+        """
+        obj = {
+            "a": [None, True, False, 1, -1, 2**64 - 1, -(2**63), 1.5],
+            "b": {"c": ["x" * 70000, b"\x00" * 300, [], {}]},
+            "d": list(range(70000)),
+            "é": {str(idx): idx for idx in range(20)},
+        }
+        assert orjson.loads_msgpack(orjson.dumps_msgpack(obj)) == obj
+
+    def test_loads_msgpack_input_type(self):
+        """
+        loads_msgpack() bytes, bytearray, memoryview, and not str
+        """
+        assert orjson.loads_msgpack(b"\x91\x01") == [1]
+        assert orjson.loads_msgpack(bytearray(b"\x91\x01")) == [1]
+        assert orjson.loads_msgpack(memoryview(b"\x91\x01")) == [1]
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_msgpack("\x00")  # type: ignore
+
+    def test_loads_msgpack_duplicate_key(self):
+        """
+        loads_msgpack() duplicate map key keeps the last value
+        """
+        assert orjson.loads_msgpack(b"\x82\xa1a\x01\xa1a\x02") == {"a": 2}
+
+    @pytest.mark.parametrize(
+        "data,pos",
+        (
+            (b"", 0),
+            (b"\xc1", 0),
+            (b"\x92\x01", 2),
+            (b"\x01\x02", 1),
+            (b"\x81\x01\x02", 1),
+            (b"\x81\xc4\x01a\x02", 1),
+            (b"\xdd\xff\xff\xff\xff", 5),
+            (b"\x91\xa1\xff", 2),
+            (b"\xd6\x01\x00\x00\x00\x00", 0),
+            (b"\xd6\xff\x00\x00\x00\x00", 0),
+        ),
+    )
+    def test_loads_msgpack_invalid(self, data, pos):
+        """
+        loads_msgpack() invalid data raises with the byte offset
+        """
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.loads_msgpack(data)
+        assert exc_info.value.pos == pos
+
+    def test_loads_msgpack_recursion(self):
+        """
+        loads_msgpack() recursion limit
+        """
+        assert orjson.loads_msgpack(b"\x91" * 1023 + b"\x90")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_msgpack(b"\x91" * 1024 + b"\x90")
+
+    def test_loads_msgpack_timestamp(self):
+        """
+        loads_msgpack() timestamp extension type as datetime
+        """
+        option = orjson.OPT_MSGPACK_TIMESTAMP
+        assert orjson.loads_msgpack(
+            b"\xd6\xff\x00\x00\x00\x00", option=option
+        ) == datetime.datetime(1970, 1, 1, tzinfo=datetime.timezone.utc)
+        assert orjson.loads_msgpack(
+            b"\xd7\xff" + ((123456789 << 34) | 1700000000).to_bytes(8, "big"),
+            option=option,
+        ) == datetime.datetime(
+            2023, 11, 14, 22, 13, 20, 123456, tzinfo=datetime.timezone.utc
+        )
+        assert orjson.loads_msgpack(
+            b"\xc7\x0c\xff"
+            + (5000).to_bytes(4, "big")
+            + (-62135596800).to_bytes(8, "big", signed=True),
+            option=option,
+        ) == datetime.datetime(1, 1, 1, 0, 0, 0, 5, tzinfo=datetime.timezone.utc)
+
+    @pytest.mark.parametrize(
+        "data",
+        (
+            b"\xd5\xff\x00\x00",
+            b"\xc7\x0c\xff" + (10**9).to_bytes(4, "big") + bytes(8),
+            b"\xc7\x0c\xff" + bytes(4) + (-62135596801).to_bytes(8, "big", signed=True),
+            b"\xd4\x01\x00",
+        ),
+    )
+    def test_loads_msgpack_timestamp_invalid(self, data):
+        """
+        loads_msgpack() invalid or out of range timestamp, other extension type
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_msgpack(data, option=orjson.OPT_MSGPACK_TIMESTAMP)
+
+    def test_loads_msgpack_options(self):
+        """
+        loads_msgpack() option is only OPT_MSGPACK_TIMESTAMP
+        """
+        assert orjson.loads_msgpack(b"\x00", None) == 0
+        assert orjson.loads_msgpack(b"\x00", orjson.OPT_MSGPACK_TIMESTAMP) == 0
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_msgpack(b"\x00", option=orjson.OPT_SORT_KEYS)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads_msgpack(b"\x00", option="")  # type: ignore
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(1, option=orjson.OPT_MSGPACK_TIMESTAMP)
+
+    def test_loads_msgpack_args(self):
+        """
+        loads_msgpack() arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.loads_msgpack()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_msgpack(b"\x00", unknown=1)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads_msgpack(b"\x00", None, option=None)  # type: ignore
@@ -0,0 +1,266 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import collections
+import gc
+import typing
+import weakref
+
+import pytest
+
+import orjson
+
+Point = collections.namedtuple("Point", ("x", "y"))
+
+Renamed = collections.namedtuple("Renamed", ("a", "def", "a"), rename=True)
+
+Defaults = collections.namedtuple("Defaults", ("a", "b", "c"), defaults=(2, None))
+
+
+class User(typing.NamedTuple):
+    user_id: int
+    display_name: str = "a"
+    tags: tuple = ()
+
+
+class UserSubclass(User):
+    def describe(self):
+        return self.display_name
+
+
+class Plain(tuple):
+    pass
+
+
+class InvalidFields(tuple):
+    _fields = 5
+
+
+class InvalidFieldNames(tuple):
+    _fields = (1,)
+
+
+class FieldsTuple(tuple):
+    _fields = ("a", "b")
+
+
+class TestNamedTuple:
+    def test_namedtuple_disabled(self):
+        """
+        dumps() namedtuple without OPT_SERIALIZE_NAMEDTUPLE
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Point(1, 2))
+        assert orjson.dumps(Point(1, 2), default=list) == b"[1,2]"
+
+    def test_namedtuple_collections(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE collections.namedtuple
+        """
+        assert (
+            orjson.dumps(Point(1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_namedtuple_typing(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE typing.NamedTuple
+        """
+        assert (
+            orjson.dumps(User(1, tags=("a",)), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"user_id":1,"display_name":"a","tags":["a"]}'
+        )
+
+    def test_namedtuple_subclass(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE subclass of a namedtuple
+        """
+        assert (
+            orjson.dumps(UserSubclass(1), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"user_id":1,"display_name":"a","tags":[]}'
+        )
+
+    def test_namedtuple_renamed(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE namedtuple with rename=True
+        """
+        assert (
+            orjson.dumps(Renamed(1, 2, 3), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"a":1,"_1":2,"_2":3}'
+        )
+
+    def test_namedtuple_tuple_subclass(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE subclass of tuple is passed to default
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Plain((1,)), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+        assert (
+            orjson.dumps((1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE) == b"[1,2]"
+        )
+
+    def test_namedtuple_invalid_fields(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE subclass of tuple with _fields not a tuple
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(InvalidFields((1,)), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+        assert (
+            orjson.dumps(
+                InvalidFields((1,)),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE,
+                default=list,
+            )
+            == b"[1]"
+        )
+
+    def test_namedtuple_invalid_field_names(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE subclass of tuple with _fields not of str
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                InvalidFieldNames((1,)),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE,
+            )
+
+    def test_namedtuple_length_mismatch(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE named tuple of more or fewer items than _fields
+        """
+        assert (
+            orjson.dumps(FieldsTuple((1, 2)), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"a":1,"b":2}'
+        )
+        for items in ((1,), (1, 2, 3)):
+            with pytest.raises(orjson.JSONEncodeError, match="as many items as _fields"):
+                orjson.dumps(FieldsTuple(items), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+
+    def test_namedtuple_class_modified(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE subclass of tuple given _fields after it was
+        serialized
+        """
+
+        class Modified(tuple):
+            pass
+
+        for _ in range(2):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(Modified((1,)), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+        Modified._fields = ("a",)  # type: ignore
+        assert (
+            orjson.dumps(Modified((1,)), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"a":1}'
+        )
+
+    def test_namedtuple_omit_defaults(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE and OPT_OMIT_DEFAULTS
+        """
+        option = orjson.OPT_SERIALIZE_NAMEDTUPLE | orjson.OPT_OMIT_DEFAULTS
+        assert orjson.dumps(Defaults(1), option=option) == b'{"a":1}'
+        assert orjson.dumps(Defaults(1, 3, 0), option=option) == b'{"a":1,"b":3,"c":0}'
+        assert orjson.dumps(User(1), option=option) == b'{"user_id":1}'
+
+    def test_namedtuple_omit_none(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE and OPT_OMIT_NONE
+        """
+        assert (
+            orjson.dumps(
+                Point(None, 1),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE | orjson.OPT_OMIT_NONE,
+            )
+            == b'{"y":1}'
+        )
+
+    def test_namedtuple_key_case(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE and OPT_CAMEL_CASE_KEYS
+        """
+        assert (
+            orjson.dumps(
+                User(1),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE | orjson.OPT_CAMEL_CASE_KEYS,
+            )
+            == b'{"userId":1,"displayName":"a","tags":[]}'
+        )
+
+    def test_namedtuple_canonical(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE and OPT_CANONICAL
+        """
+        assert (
+            orjson.dumps(
+                User(1),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE | orjson.OPT_CANONICAL,
+            )
+            == b'{"display_name":"a","tags":[],"user_id":1}'
+        )
+
+    def test_namedtuple_indent(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE and OPT_INDENT_2
+        """
+        assert (
+            orjson.dumps(
+                Point(1, (2,)),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "x": 1,\n  "y": [\n    2\n  ]\n}'
+        )
+
+    def test_namedtuple_nested(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE nested in containers and other namedtuples
+        """
+        obj = {"a": [Point(User(1), {"b": Point(1, 2)})]}
+        assert orjson.dumps(obj, option=orjson.OPT_SERIALIZE_NAMEDTUPLE) == (
+            b'{"a":[{"x":{"user_id":1,"display_name":"a","tags":[]},'
+            b'"y":{"b":{"x":1,"y":2}}}]}'
+        )
+        assert orjson.loads_msgpack(
+            orjson.dumps_msgpack(Point(1, 2), option=orjson.OPT_SERIALIZE_NAMEDTUPLE),
+        ) == {"x": 1, "y": 2}
+
+    def test_namedtuple_default_not_called(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE does not call default for a namedtuple
+        """
+
+        def default(obj):
+            raise AssertionError
+
+        assert (
+            orjson.dumps(
+                Point(1, 2),
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE,
+                default=default,
+            )
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_namedtuple_non_str_key(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE namedtuple as a dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {Point(1, 2): 1},
+                option=orjson.OPT_SERIALIZE_NAMEDTUPLE | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_namedtuple_class_freed(self):
+        """
+        OPT_SERIALIZE_NAMEDTUPLE class is not kept alive by serializing it
+        """
+        Temporary = collections.namedtuple("Temporary", ("a",))
+        assert (
+            orjson.dumps(Temporary(1), option=orjson.OPT_SERIALIZE_NAMEDTUPLE)
+            == b'{"a":1}'
+        )
+        ref = weakref.ref(Temporary)
+        del Temporary
+        gc.collect()
+        assert ref() is None
@@ -0,0 +1,379 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import array
+import collections
+import dataclasses
+import functools
+import gc
+import operator
+import re
+import weakref
+
+import pytest
+
+import orjson
+
+
+class Plain:
+    def __init__(self, a, b=None):
+        self.a = a
+        self.b = b
+        self._private = 1
+
+
+class Slots:
+    __slots__ = ("x", "y", "_z")
+
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+        self._z = 3
+
+
+class SlotsStr:
+    __slots__ = "x"
+
+    def __init__(self, x):
+        self.x = x
+
+
+class SlotsSubclass(Slots):
+    __slots__ = ("w",)
+
+    def __init__(self, x, y, w):
+        super().__init__(x, y)
+        self.w = w
+
+
+class DictSubclass(Slots):
+    def __init__(self, x, y, d):
+        super().__init__(x, y)
+        self.d = d
+
+
+class Unset:
+    __slots__ = ("a", "b")
+
+    def __init__(self, a):
+        self.a = a
+
+
+class Mangled:
+    __slots__ = ("__secret", "public")
+
+    def __init__(self):
+        self.__secret = 1
+        self.public = 2
+
+
+class Empty:
+    pass
+
+
+class SubPlain(Plain):
+    pass
+
+
+NamedTuple = collections.namedtuple("NamedTuple", ("a", "b"))
+
+
+class StrSubclass(str):
+    pass
+
+
+class Error(Exception):
+    pass
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+
+
+class TestObject:
+    def test_object_disabled(self):
+        """
+        dumps() object without OPT_SERIALIZE_OBJECT
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Plain(1))
+
+    def test_object_dict(self):
+        """
+        OPT_SERIALIZE_OBJECT __dict__
+        """
+        assert (
+            orjson.dumps(Plain(1, [2]), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"a":1,"b":[2]}'
+        )
+
+    def test_object_subclass(self):
+        """
+        OPT_SERIALIZE_OBJECT subclass of a class
+        """
+        assert (
+            orjson.dumps(SubPlain(1), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"a":1,"b":null}'
+        )
+
+    def test_object_slots(self):
+        """
+        OPT_SERIALIZE_OBJECT __slots__
+        """
+        assert (
+            orjson.dumps(Slots(1, 2), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"x":1,"y":2}'
+        )
+        assert (
+            orjson.dumps(SlotsStr(1), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"x":1}'
+        )
+
+    def test_object_slots_subclass(self):
+        """
+        OPT_SERIALIZE_OBJECT __slots__ of base classes are first
+        """
+        assert (
+            orjson.dumps(SlotsSubclass(1, 2, 3), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"x":1,"y":2,"w":3}'
+        )
+
+    def test_object_slots_and_dict(self):
+        """
+        OPT_SERIALIZE_OBJECT __slots__ and then __dict__
+        """
+        assert (
+            orjson.dumps(DictSubclass(1, 2, 3), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"x":1,"y":2,"d":3}'
+        )
+
+    def test_object_slots_unset(self):
+        """
+        OPT_SERIALIZE_OBJECT __slots__ that are not set
+        """
+        assert (
+            orjson.dumps(Unset(1), option=orjson.OPT_SERIALIZE_OBJECT) == b'{"a":1}'
+        )
+
+    def test_object_empty(self):
+        """
+        OPT_SERIALIZE_OBJECT no attributes
+        """
+        assert orjson.dumps(Empty(), option=orjson.OPT_SERIALIZE_OBJECT) == b"{}"
+        assert (
+            orjson.dumps(
+                Empty(),
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_INDENT_2,
+            )
+            == b"{}"
+        )
+
+    def test_object_underscore(self):
+        """
+        OPT_SERIALIZE_OBJECT and OPT_SERIALIZE_UNDERSCORE
+        """
+        option = orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_SERIALIZE_UNDERSCORE
+        assert (
+            orjson.dumps(Plain(1), option=option) == b'{"a":1,"b":null,"_private":1}'
+        )
+        assert orjson.dumps(Slots(1, 2), option=option) == b'{"x":1,"y":2,"_z":3}'
+        assert (
+            orjson.dumps(Mangled(), option=option)
+            == b'{"_Mangled__secret":1,"public":2}'
+        )
+        assert (
+            orjson.dumps(Mangled(), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"public":2}'
+        )
+
+    def test_object_options(self):
+        """
+        OPT_SERIALIZE_OBJECT and options of maps
+        """
+        obj = Plain(None, 1)
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_OMIT_NONE,
+            )
+            == b'{"b":1}'
+        )
+        assert (
+            orjson.dumps(
+                DictSubclass(1, 2, 3),
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_CANONICAL,
+            )
+            == b'{"d":3,"x":1,"y":2}'
+        )
+        assert (
+            orjson.dumps(
+                SlotsSubclass(1, 2, 3),
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_PASCAL_CASE_KEYS,
+            )
+            == b'{"X":1,"Y":2,"W":3}'
+        )
+
+    def test_object_nested(self):
+        """
+        OPT_SERIALIZE_OBJECT nested in containers and other objects
+        """
+        obj = [Plain(Slots(1, 2), {"k": Empty()})]
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'[{"a":{"x":1,"y":2},"b":{"k":{}}}]'
+        )
+        assert orjson.loads_msgpack(
+            orjson.dumps_msgpack(obj, option=orjson.OPT_SERIALIZE_OBJECT),
+        ) == [{"a": {"x": 1, "y": 2}, "b": {"k": {}}}]
+
+    def test_object_cycle(self):
+        """
+        OPT_SERIALIZE_OBJECT object containing itself
+        """
+        obj = Plain(1)
+        obj.b = obj
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(obj, option=orjson.OPT_SERIALIZE_OBJECT)
+
+    def test_object_default_not_called(self):
+        """
+        OPT_SERIALIZE_OBJECT does not call default for an object
+        """
+
+        def default(obj):
+            raise AssertionError
+
+        assert (
+            orjson.dumps(Slots(1, 2), option=orjson.OPT_SERIALIZE_OBJECT, default=default)
+            == b'{"x":1,"y":2}'
+        )
+
+    def test_object_builtin_subclass(self):
+        """
+        OPT_SERIALIZE_OBJECT subclasses of built-in types are passed to default
+        """
+        for obj in (NamedTuple(1, 2), Error("a"), {1}, Plain):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps(obj, option=orjson.OPT_SERIALIZE_OBJECT)
+        assert (
+            orjson.dumps(
+                NamedTuple(1, 2),
+                option=orjson.OPT_SERIALIZE_OBJECT,
+                default=list,
+            )
+            == b"[1,2]"
+        )
+        assert (
+            orjson.dumps(
+                StrSubclass("a"),
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_PASSTHROUGH_SUBCLASS,
+                default=str,
+            )
+            == b'"a"'
+        )
+
+    def test_object_extension_type(self):
+        """
+        OPT_SERIALIZE_OBJECT extension types and their subclasses are passed
+        to default
+        """
+
+        class PartialSubclass(functools.partial):
+            pass
+
+        pattern = re.compile("a")
+        for obj in (
+            pattern,
+            pattern.match("a"),
+            functools.partial(print),
+            PartialSubclass(print),
+            operator.itemgetter(1),
+            array.array("i"),
+        ):
+            assert (
+                orjson.dumps(
+                    obj,
+                    option=orjson.OPT_SERIALIZE_OBJECT,
+                    default=lambda obj: "default",
+                )
+                == b'"default"'
+            )
+
+    def test_object_base_object(self):
+        """
+        OPT_SERIALIZE_OBJECT object() is passed to default
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(object(), option=orjson.OPT_SERIALIZE_OBJECT)
+        assert (
+            orjson.dumps(
+                object(),
+                option=orjson.OPT_SERIALIZE_OBJECT,
+                default=lambda obj: "default",
+            )
+            == b'"default"'
+        )
+
+    def test_object_dict_not_dict(self):
+        """
+        OPT_SERIALIZE_OBJECT __dict__ that is not a dict raises
+        """
+
+        class DictProperty:
+            @property
+            def __dict__(self):
+                return [1]
+
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(DictProperty(), option=orjson.OPT_SERIALIZE_OBJECT)
+
+    def test_object_passthrough_dataclass(self):
+        """
+        OPT_SERIALIZE_OBJECT and OPT_PASSTHROUGH_DATACLASS
+        """
+        assert (
+            orjson.dumps(
+                Dataclass(1),
+                option=orjson.OPT_SERIALIZE_OBJECT,
+            )
+            == b'{"a":1}'
+        )
+        assert (
+            orjson.dumps(
+                Dataclass(1),
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_PASSTHROUGH_DATACLASS,
+                default=lambda obj: "default",
+            )
+            == b'"default"'
+        )
+
+    def test_object_non_str_key(self):
+        """
+        OPT_SERIALIZE_OBJECT object as a dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(
+                {Empty(): 1},
+                option=orjson.OPT_SERIALIZE_OBJECT | orjson.OPT_NON_STR_KEYS,
+            )
+
+    def test_object_class_freed(self):
+        """
+        OPT_SERIALIZE_OBJECT class is not kept alive by serializing it
+        """
+
+        class Temporary:
+            __slots__ = ("a",)
+
+            def __init__(self):
+                self.a = 1
+
+        assert (
+            orjson.dumps(Temporary(), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"a":1}'
+        )
+        ref = weakref.ref(Temporary)
+        del Temporary
+        gc.collect()
+        assert ref() is None
@@ -0,0 +1,244 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+from typing import ClassVar, Optional
+
+import pytest
+
+import orjson
+
+
+@dataclasses.dataclass
+class Config:
+    name: str
+    retries: int = 3
+    ratio: float = 0.5
+    label: Optional[str] = None
+    tags: list = dataclasses.field(default_factory=list)
+    extra: dict = dataclasses.field(default_factory=dict)
+    pair: tuple = dataclasses.field(default_factory=tuple)
+    _private: int = 0
+    version: ClassVar[int] = 1
+
+
+@dataclasses.dataclass
+class SlotsConfig:
+    __slots__ = ("name", "retries")
+    name: str
+    retries: int
+
+
+@dataclasses.dataclass
+class Nested:
+    config: Config = dataclasses.field(default_factory=lambda: Config("a"))
+    count: int = 0
+
+
+@dataclasses.dataclass
+class PostInit:
+    a: int
+    b: int = 2
+    c: int = dataclasses.field(init=False, default=3)
+
+    def __post_init__(self):
+        self.extra = 5
+
+
+class RaisesEq:
+    def __eq__(self, other):
+        raise ValueError("comparison")
+
+    __hash__ = object.__hash__
+
+
+class TestOmitDefaults:
+    def test_omit_defaults(self):
+        """
+        OPT_OMIT_DEFAULTS omits fields equal to their default
+        """
+        assert orjson.dumps(Config("a")) == (
+            b'{"name":"a","retries":3,"ratio":0.5,"label":null,"tags":[],'
+            b'"extra":{},"pair":[]}'
+        )
+        assert (
+            orjson.dumps(Config("a"), option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"name":"a"}'
+        )
+
+    def test_omit_defaults_changed(self):
+        """
+        OPT_OMIT_DEFAULTS serializes fields not equal to their default
+        """
+        obj = Config("a", 4, 1.0, "b", [1], {"c": 1}, (2,))
+        assert orjson.dumps(obj, option=orjson.OPT_OMIT_DEFAULTS) == (
+            b'{"name":"a","retries":4,"ratio":1.0,"label":"b","tags":[1],'
+            b'"extra":{"c":1},"pair":[2]}'
+        )
+
+    def test_omit_defaults_equal(self):
+        """
+        OPT_OMIT_DEFAULTS compares using == values of the same type
+        """
+        assert (
+            orjson.dumps(Config("a", ratio=float("0.5")), option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"name":"a"}'
+        )
+        assert (
+            orjson.dumps(Config("a", retries=3.0), option=orjson.OPT_OMIT_DEFAULTS)  # type: ignore
+            == b'{"name":"a","retries":3.0}'
+        )
+
+    def test_omit_defaults_bool_int(self):
+        """
+        OPT_OMIT_DEFAULTS does not omit a bool equal to an int default
+        """
+
+        @dataclasses.dataclass
+        class Flags:
+            count: int = 0
+            enabled: bool = True
+            ratio: float = 1.0
+
+        assert (
+            orjson.dumps(
+                Flags(False, 1, True),  # type: ignore
+                option=orjson.OPT_OMIT_DEFAULTS,
+            )
+            == b'{"count":false,"enabled":1,"ratio":true}'
+        )
+        assert orjson.dumps(Flags(), option=orjson.OPT_OMIT_DEFAULTS) == b"{}"
+
+    def test_omit_defaults_factory_type(self):
+        """
+        OPT_OMIT_DEFAULTS default_factory of a container requires the same type
+        """
+        assert (
+            orjson.dumps(Config("a", tags=()), option=orjson.OPT_OMIT_DEFAULTS)  # type: ignore
+            == b'{"name":"a","tags":[]}'
+        )
+
+    def test_omit_defaults_factory_other(self):
+        """
+        OPT_OMIT_DEFAULTS does not call other default_factory
+        """
+        assert (
+            orjson.dumps(Nested(), option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"config":{"name":"a"}}'
+        )
+
+    def test_omit_defaults_slots(self):
+        """
+        OPT_OMIT_DEFAULTS dataclass with __slots__ and no defaults
+        """
+        assert (
+            orjson.dumps(SlotsConfig("a", 1), option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"name":"a","retries":1}'
+        )
+
+    def test_omit_defaults_all(self):
+        """
+        OPT_OMIT_DEFAULTS with every field omitted
+        """
+        cls = dataclasses.make_dataclass("Empty", [("a", int, dataclasses.field(default=1))])
+        assert orjson.dumps(cls(), option=orjson.OPT_OMIT_DEFAULTS) == b"{}"
+        assert (
+            orjson.dumps(
+                cls(), option=orjson.OPT_OMIT_DEFAULTS | orjson.OPT_INDENT_2
+            )
+            == b"{}"
+        )
+        assert orjson.dumps_msgpack(cls(), option=orjson.OPT_OMIT_DEFAULTS) == b"\x80"
+
+    def test_omit_defaults_attribute(self):
+        """
+        OPT_OMIT_DEFAULTS serializes attributes that are not fields
+        """
+        assert orjson.dumps(PostInit(1)) == b'{"a":1,"b":2,"extra":5}'
+        assert (
+            orjson.dumps(PostInit(1), option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"a":1,"extra":5}'
+        )
+
+    def test_omit_defaults_out_of_order(self):
+        """
+        OPT_OMIT_DEFAULTS field assigned after an attribute that is not a field
+        """
+        obj = PostInit(1)
+        obj.c = 3
+        obj.b = 4
+        assert orjson.dumps(obj) == b'{"a":1,"b":4,"extra":5,"c":3}'
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_DEFAULTS)
+            == b'{"a":1,"b":4,"extra":5}'
+        )
+
+    def test_omit_defaults_set(self):
+        """
+        OPT_OMIT_DEFAULTS default_factory of set and frozenset
+        """
+        cls = dataclasses.make_dataclass(
+            "Cls",
+            [
+                ("a", set, dataclasses.field(default_factory=set)),
+                ("b", frozenset, dataclasses.field(default_factory=frozenset)),
+            ],
+        )
+        assert orjson.dumps(cls(), option=orjson.OPT_OMIT_DEFAULTS) == b"{}"
+
+    def test_omit_defaults_options(self):
+        """
+        OPT_OMIT_DEFAULTS with OPT_OMIT_NONE, OPT_INDENT_2, OPT_CANONICAL
+        """
+        obj = Config("a", retries=4)
+        assert (
+            orjson.dumps(
+                obj, option=orjson.OPT_OMIT_DEFAULTS | orjson.OPT_INDENT_2
+            )
+            == b'{\n  "name": "a",\n  "retries": 4\n}'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_DEFAULTS | orjson.OPT_CANONICAL)
+            == b'{"name":"a","retries":4}'
+        )
+        cls = dataclasses.make_dataclass("Cls", [("a", object), ("b", int, 1)])
+        assert (
+            orjson.dumps(
+                cls(None, 2), option=orjson.OPT_OMIT_DEFAULTS | orjson.OPT_OMIT_NONE
+            )
+            == b'{"b":2}'
+        )
+
+    def test_omit_defaults_many_classes(self):
+        """
+        OPT_OMIT_DEFAULTS with more classes than are cached
+        """
+        for idx in range(1000):
+            cls = dataclasses.make_dataclass(
+                f"Cls{idx}", [("a", int, dataclasses.field(default=idx))]
+            )
+            assert (
+                orjson.dumps([cls(), cls(-1)], option=orjson.OPT_OMIT_DEFAULTS)
+                == b'[{},{"a":-1}]'
+            )
+
+    def test_omit_defaults_eq_raises(self):
+        """
+        OPT_OMIT_DEFAULTS comparison that raises
+        """
+        cls = dataclasses.make_dataclass("Cls", [("a", object, RaisesEq())])
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps(cls(RaisesEq()), option=orjson.OPT_OMIT_DEFAULTS)
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_omit_defaults_class_modified(self):
+        """
+        OPT_OMIT_DEFAULTS uses a default changed after the class was
+        serialized
+        """
+        cls = dataclasses.make_dataclass("Cls", [("a", int, dataclasses.field(default=1))])
+        assert orjson.dumps(cls(1), option=orjson.OPT_OMIT_DEFAULTS) == b"{}"
+        cls.__dataclass_fields__["a"].default = 2
+        cls.a = 2
+        assert orjson.dumps(cls(1), option=orjson.OPT_OMIT_DEFAULTS) == b'{"a":1}'
+        assert orjson.dumps(cls(2), option=orjson.OPT_OMIT_DEFAULTS) == b"{}"
@@ -0,0 +1,169 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+
+import orjson
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: object = None
+    b: object = None
+    _c: object = None
+
+
+@dataclasses.dataclass
+class SlotsDataclass:
+    __slots__ = ("a", "b")
+    a: object
+    b: object
+
+
+class SubDict(dict):
+    pass
+
+
+class TestOmitNone:
+    def test_omit_none_dict(self):
+        """
+        OPT_OMIT_NONE dict
+        """
+        obj = {"a": None, "b": 1, "c": None, "d": {"e": None}, "f": [None]}
+        assert orjson.dumps(obj) == (
+            b'{"a":null,"b":1,"c":null,"d":{"e":null},"f":[null]}'
+        )
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_NONE)
+            == b'{"b":1,"d":{},"f":[null]}'
+        )
+
+    def test_omit_none_dict_all(self):
+        """
+        OPT_OMIT_NONE dict of only None
+        """
+        assert orjson.dumps({"a": None}, option=orjson.OPT_OMIT_NONE) == b"{}"
+        assert (
+            orjson.dumps(
+                {"a": None, "b": None},
+                option=orjson.OPT_OMIT_NONE | orjson.OPT_INDENT_2,
+            )
+            == b"{}"
+        )
+
+    def test_omit_none_dict_subclass(self):
+        """
+        OPT_OMIT_NONE dict subclass
+        """
+        assert (
+            orjson.dumps(SubDict(a=None, b=2), option=orjson.OPT_OMIT_NONE)
+            == b'{"b":2}'
+        )
+
+    def test_omit_none_indent(self):
+        """
+        OPT_OMIT_NONE with OPT_INDENT_2 has no dangling comma
+        """
+        obj = {"a": None, "b": 1, "c": None, "d": {"e": None, "f": 2}, "g": None}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_NONE | orjson.OPT_INDENT_2)
+            == b'{\n  "b": 1,\n  "d": {\n    "f": 2\n  }\n}'
+        )
+
+    def test_omit_none_sort_keys(self):
+        """
+        OPT_OMIT_NONE with OPT_SORT_KEYS
+        """
+        obj = {"c": None, "b": 1, "a": None, "d": 2}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_NONE | orjson.OPT_SORT_KEYS)
+            == b'{"b":1,"d":2}'
+        )
+        assert (
+            orjson.dumps(
+                obj,
+                option=orjson.OPT_OMIT_NONE
+                | orjson.OPT_SORT_KEYS
+                | orjson.OPT_INDENT_2,
+            )
+            == b'{\n  "b": 1,\n  "d": 2\n}'
+        )
+
+    def test_omit_none_non_str_keys(self):
+        """
+        OPT_OMIT_NONE with OPT_NON_STR_KEYS
+        """
+        obj = {1: None, "a": 1, 2: 2, None: None}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_NONE | orjson.OPT_NON_STR_KEYS)
+            == b'{"a":1,"2":2}'
+        )
+
+    def test_omit_none_canonical(self):
+        """
+        OPT_OMIT_NONE with OPT_CANONICAL
+        """
+        obj = {"b": None, "a": 1}
+        assert (
+            orjson.dumps(obj, option=orjson.OPT_OMIT_NONE | orjson.OPT_CANONICAL)
+            == b'{"a":1}'
+        )
+        assert (
+            orjson.dumps(
+                Dataclass(b=1), option=orjson.OPT_OMIT_NONE | orjson.OPT_CANONICAL
+            )
+            == b'{"b":1}'
+        )
+
+    def test_omit_none_dataclass(self):
+        """
+        OPT_OMIT_NONE dataclass
+        """
+        assert orjson.dumps(Dataclass()) == b'{"a":null,"b":null}'
+        assert orjson.dumps(Dataclass(), option=orjson.OPT_OMIT_NONE) == b"{}"
+        assert (
+            orjson.dumps([Dataclass(1), Dataclass(b=2)], option=orjson.OPT_OMIT_NONE)
+            == b'[{"a":1},{"b":2}]'
+        )
+
+    def test_omit_none_dataclass_slots(self):
+        """
+        OPT_OMIT_NONE dataclass with __slots__
+        """
+        assert (
+            orjson.dumps(SlotsDataclass(None, 1), option=orjson.OPT_OMIT_NONE)
+            == b'{"b":1}'
+        )
+        assert (
+            orjson.dumps(
+                SlotsDataclass(None, None),
+                option=orjson.OPT_OMIT_NONE | orjson.OPT_INDENT_2,
+            )
+            == b"{}"
+        )
+
+    def test_omit_none_default(self):
+        """
+        OPT_OMIT_NONE does not omit an item whose default returns None
+        """
+
+        class Custom:
+            pass
+
+        assert (
+            orjson.dumps(
+                {"a": Custom()}, default=lambda _: None, option=orjson.OPT_OMIT_NONE
+            )
+            == b'{"a":null}'
+        )
+
+    def test_omit_none_msgpack(self):
+        """
+        OPT_OMIT_NONE dumps_msgpack() and dumps_cbor() map length
+        """
+        obj = {"a": None, "b": 1}
+        assert (
+            orjson.dumps_msgpack(obj, option=orjson.OPT_OMIT_NONE) == b"\x81\xa1b\x01"
+        )
+        assert orjson.dumps_cbor(obj, option=orjson.OPT_OMIT_NONE) == b"\xa1ab\x01"
+        assert orjson.dumps_cbor(Dataclass(), option=orjson.OPT_OMIT_NONE) == b"\xa0"
@@ -0,0 +1,192 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import gc
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+DOCUMENT = b"""{
+    "type": "order",
+    "data": {"items": [1, {"id": 2}, [3], "\xc3\xa9", null, 1.5], "empty": {}},
+    "dup": 1,
+    "dup": 2,
+    "flag": true
+}"""
+
+
+class TestParseLazy:
+    def test_parse_lazy_object(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        assert isinstance(doc, orjson.Node)
+        assert doc["type"] == "order"
+        assert doc["flag"] is True
+        assert isinstance(doc["data"], orjson.Node)
+
+    def test_parse_lazy_array(self):
+        items = orjson.parse_lazy(DOCUMENT)["data"]["items"]
+        assert items[0] == 1
+        assert items[1]["id"] == 2
+        assert items[2][0] == 3
+        assert items[3] == "é"
+        assert items[4] is None
+        assert items[5] == 1.5
+        assert items[-1] == 1.5
+        assert items[-6] == 1
+
+    def test_parse_lazy_len(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        assert len(doc) == 4
+        assert len(doc["data"]["items"]) == 6
+        assert len(doc["data"]["empty"]) == 0
+
+    def test_parse_lazy_iter_object(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        assert list(doc) == ["type", "data", "dup", "flag"]
+        assert doc.keys() == ["type", "data", "dup", "flag"]
+        assert list(doc) == list(orjson.loads(DOCUMENT))
+
+    def test_parse_lazy_iter_array(self):
+        values = list(orjson.parse_lazy(DOCUMENT)["data"]["items"])
+        assert values[0] == 1
+        assert isinstance(values[1], orjson.Node)
+        assert values[1].to_python() == {"id": 2}
+        assert values[3:] == ["é", None, 1.5]
+
+    def test_parse_lazy_duplicate_key(self):
+        assert orjson.parse_lazy(DOCUMENT)["dup"] == 2
+
+    def test_parse_lazy_index_repeated(self):
+        """
+        Node indexed repeatedly matches loads()
+        """
+        obj = {
+            "array": [[idx] if idx % 2 else idx for idx in range(1000)],
+            "object": {str(idx): idx for idx in range(1000)},
+        }
+        doc = orjson.parse_lazy(orjson.dumps(obj))
+        array = doc["array"]
+        for _ in range(2):
+            assert len(array) == 1000
+            for idx in range(-1000, 1000):
+                each = array[idx]
+                if isinstance(each, orjson.Node):
+                    each = each.to_python()
+                assert each == obj["array"][idx]
+        members = doc["object"]
+        for _ in range(2):
+            assert len(members) == 1000
+            assert members.keys() == list(obj["object"])
+            for key, value in obj["object"].items():
+                assert members[key] == value
+
+    def test_parse_lazy_get(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        assert doc.get("type") == "order"
+        assert doc.get("nope") is None
+        assert doc.get("nope", 1) == 1
+        items = doc["data"]["items"]
+        assert items.get(0) == 1
+        assert items.get(6) is None
+        assert items.get(-7, "x") == "x"
+        assert items.get(2**70) is None
+
+    def test_parse_lazy_get_args(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        with pytest.raises(TypeError):
+            doc.get()  # type: ignore
+        with pytest.raises(TypeError):
+            doc.get("a", 1, 2)  # type: ignore
+        with pytest.raises(TypeError):
+            doc.get(1)
+
+    def test_parse_lazy_to_python(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        assert doc.to_python() == orjson.loads(DOCUMENT)
+        assert doc["data"].to_python() == orjson.loads(DOCUMENT)["data"]
+
+    def test_parse_lazy_scalar(self):
+        assert orjson.parse_lazy(b"1") == 1
+        assert orjson.parse_lazy(b' "a" ') == "a"
+        assert orjson.parse_lazy(b"null") is None
+
+    def test_parse_lazy_input_types(self):
+        for data in (
+            DOCUMENT,
+            bytearray(DOCUMENT),
+            memoryview(DOCUMENT),
+            DOCUMENT.decode("utf-8"),
+        ):
+            assert orjson.parse_lazy(data)["data"]["items"][1]["id"] == 2
+
+    def test_parse_lazy_input_modified(self):
+        data = bytearray(b'{"a": [1]}')
+        doc = orjson.parse_lazy(data)
+        data[:] = b'{"b": [2]}'
+        assert doc["a"][0] == 1
+
+    def test_parse_lazy_lifetime(self):
+        items = orjson.parse_lazy(DOCUMENT)["data"]["items"]
+        gc.collect()
+        assert items[1]["id"] == 2
+
+    def test_parse_lazy_key_error(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        with pytest.raises(KeyError):
+            doc["nope"]
+        with pytest.raises(KeyError):
+            doc["data"]["empty"]["a"]
+
+    def test_parse_lazy_index_error(self):
+        items = orjson.parse_lazy(DOCUMENT)["data"]["items"]
+        with pytest.raises(IndexError):
+            items[6]
+        with pytest.raises(IndexError):
+            items[-7]
+        with pytest.raises(IndexError):
+            items[2**70]
+
+    def test_parse_lazy_key_type(self):
+        doc = orjson.parse_lazy(DOCUMENT)
+        for key in (0, b"type", None):
+            with pytest.raises(TypeError):
+                doc[key]  # type: ignore
+        for key in ("0", 1.0, True):
+            with pytest.raises(TypeError):
+                doc["data"]["items"][key]  # type: ignore
+
+    def test_parse_lazy_keys_array(self):
+        with pytest.raises(TypeError):
+            orjson.parse_lazy(b"[1]").keys()
+
+    def test_parse_lazy_invalid(self):
+        for data in (b"", b"[1,]", b'{"a": 1', b'{"a": 1} x', b'["\xed\xa0\x80"]'):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.parse_lazy(data)
+
+    def test_parse_lazy_type(self):
+        for data in (1, None, [], {}):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.parse_lazy(data)  # type: ignore
+
+    def test_parse_lazy_args(self):
+        with pytest.raises(TypeError):
+            orjson.parse_lazy()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.parse_lazy(b"[]", b"[]")  # type: ignore
+
+    def test_parse_lazy_not_instantiable(self):
+        with pytest.raises(TypeError):
+            orjson.Node()  # type: ignore
+
+    @needs_data
+    def test_parse_lazy_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        expected = orjson.loads(data)
+        doc = orjson.parse_lazy(data)
+        assert doc["statuses"][1]["user"]["id"] == expected["statuses"][1]["user"]["id"]
+        assert len(doc["statuses"]) == len(expected["statuses"])
+        assert doc.to_python() == expected
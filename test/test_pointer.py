@@ -0,0 +1,126 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+DOCUMENT = b"""{
+    "data": {"items": [1, {"a/b": 2, "m~n": 3, "": 4}], "x": null},
+    "meta": {"count": 2},
+    "\xc3\xa9": [true]
+}"""
+
+
+class TestPointer:
+    def test_pointer_object(self):
+        assert orjson.loads(DOCUMENT, pointer="/meta") == {"count": 2}
+        assert orjson.loads(DOCUMENT, pointer="/meta/count") == 2
+
+    def test_pointer_array(self):
+        assert orjson.loads(DOCUMENT, pointer="/data/items/0") == 1
+        assert orjson.loads(DOCUMENT, pointer="/data/items/1") == {
+            "a/b": 2,
+            "m~n": 3,
+            "": 4,
+        }
+
+    def test_pointer_null(self):
+        assert orjson.loads(DOCUMENT, pointer="/data/x") is None
+
+    def test_pointer_root(self):
+        assert orjson.loads(DOCUMENT, pointer="") == orjson.loads(DOCUMENT)
+
+    def test_pointer_none(self):
+        assert orjson.loads(DOCUMENT, pointer=None) == orjson.loads(DOCUMENT)
+
+    def test_pointer_escape(self):
+        assert orjson.loads(DOCUMENT, pointer="/data/items/1/a~1b") == 2
+        assert orjson.loads(DOCUMENT, pointer="/data/items/1/m~0n") == 3
+
+    def test_pointer_empty_key(self):
+        assert orjson.loads(DOCUMENT, pointer="/data/items/1/") == 4
+
+    def test_pointer_non_ascii(self):
+        assert orjson.loads(DOCUMENT, pointer="/é/0") is True
+
+    def test_pointer_str_input(self):
+        assert orjson.loads(DOCUMENT.decode("utf-8"), pointer="/meta/count") == 2
+
+    def test_pointer_scalar_document(self):
+        assert orjson.loads(b"1", pointer="") == 1
+
+    def test_pointer_duplicate_key(self):
+        assert orjson.loads(b'{"a": 1, "a": 2}', pointer="/a") == 2
+
+    def test_pointer_tuple(self):
+        assert orjson.loads(
+            DOCUMENT,
+            pointer=("/data/items/0", "/meta", "/data/x", ""),
+        ) == (1, {"count": 2}, None, orjson.loads(DOCUMENT))
+
+    def test_pointer_tuple_empty(self):
+        assert orjson.loads(DOCUMENT, pointer=()) == ()
+
+    def test_pointer_tuple_same(self):
+        assert orjson.loads(DOCUMENT, pointer=("/meta", "/meta")) == (
+            {"count": 2},
+            {"count": 2},
+        )
+
+    def test_pointer_not_found(self):
+        for pointer in (
+            "/nope",
+            "/data/items/2",
+            "/data/items/-",
+            "/data/items/01",
+            "/data/items/+1",
+            "/data/items/a",
+            "/meta/count/0",
+            "/data/x/a",
+        ):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(DOCUMENT, pointer=pointer)
+
+    def test_pointer_tuple_not_found(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(DOCUMENT, pointer=("/meta", "/nope"))
+
+    def test_pointer_invalid(self):
+        for pointer in ("meta", "/data/items/1/m~2n", "/data/items/1/m~"):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.loads(DOCUMENT, pointer=pointer)
+
+    def test_pointer_invalid_document(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(b'{"a": 1, "b": [}', pointer="/a")
+
+    def test_pointer_invalid_type(self):
+        for pointer in (1, b"/a", ["/a"], ("/a", 1)):
+            with pytest.raises(TypeError):
+                orjson.loads(DOCUMENT, pointer=pointer)  # type: ignore
+
+    def test_loads_kwargs(self):
+        with pytest.raises(TypeError):
+            orjson.loads()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads(DOCUMENT, "/meta")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads(DOCUMENT, foo="/meta")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.loads(obj=DOCUMENT)  # type: ignore
+
+    @needs_data
+    def test_pointer_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        expected = orjson.loads(data)
+        assert (
+            orjson.loads(data, pointer="/statuses/1/user")
+            == expected["statuses"][1]["user"]
+        )
+        assert orjson.loads(data, pointer=("/search_metadata", "/statuses/0/id")) == (
+            expected["search_metadata"],
+            expected["statuses"][0]["id"],
+        )
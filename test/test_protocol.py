@@ -0,0 +1,225 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import dataclasses
+import gc
+import weakref
+
+import pytest
+
+import orjson
+
+
+class Money:
+    def __init__(self, amount, currency="EUR"):
+        self.amount = amount
+        self.currency = currency
+
+    def __orjson__(self):
+        return {"amount": self.amount, "currency": self.currency}
+
+
+class Cents(Money):
+    pass
+
+
+class Raw:
+    def __orjson__(self):
+        return orjson.Fragment(b'{"a": 1}')
+
+
+class Self:
+    def __orjson__(self):
+        return self
+
+
+class Countdown:
+    def __init__(self, count):
+        self.count = count
+
+    def __orjson__(self):
+        if self.count == 0:
+            return "done"
+        return Countdown(self.count - 1)
+
+
+class Raises:
+    def __orjson__(self):
+        raise ValueError("invalid")
+
+
+class Meta(type):
+    def __orjson__(cls):
+        return "meta"
+
+
+class WithMeta(metaclass=Meta):
+    pass
+
+
+class StrSubclass(str):
+    def __orjson__(self):
+        return "method"
+
+
+@dataclasses.dataclass
+class Dataclass:
+    a: int
+
+    def __orjson__(self):
+        return "method"
+
+
+class TestProtocol:
+    def test_protocol(self):
+        """
+        dumps() object defining __orjson__
+        """
+        assert orjson.dumps(Money(1)) == b'{"amount":1,"currency":"EUR"}'
+
+    def test_protocol_nested(self):
+        """
+        __orjson__ nested in containers and in what it returns
+        """
+        obj = {"a": [Money(Money(1))]}
+        assert orjson.dumps(obj) == (
+            b'{"a":[{"amount":{"amount":1,"currency":"EUR"},"currency":"EUR"}]}'
+        )
+        assert orjson.loads_msgpack(orjson.dumps_msgpack([Money(1)])) == [
+            {"amount": 1, "currency": "EUR"},
+        ]
+
+    def test_protocol_inherited(self):
+        """
+        __orjson__ defined by a base class
+        """
+        assert orjson.dumps(Cents(1)) == b'{"amount":1,"currency":"EUR"}'
+
+    def test_protocol_fragment(self):
+        """
+        __orjson__ returning orjson.Fragment
+        """
+        assert orjson.dumps([Raw()]) == b'[{"a": 1}]'
+
+    def test_protocol_options(self):
+        """
+        __orjson__ return value is serialized using option
+        """
+        assert (
+            orjson.dumps(Money(1), option=orjson.OPT_SORT_KEYS | orjson.OPT_INDENT_2)
+            == b'{\n  "amount": 1,\n  "currency": "EUR"\n}'
+        )
+
+    def test_protocol_before_default(self):
+        """
+        __orjson__ is called before default
+        """
+
+        def default(obj):
+            raise AssertionError
+
+        assert (
+            orjson.dumps(Money(1), default=default)
+            == b'{"amount":1,"currency":"EUR"}'
+        )
+        assert orjson.dumps(Money({1}), default=list) == (
+            b'{"amount":[1],"currency":"EUR"}'
+        )
+
+    def test_protocol_before_serialize_object(self):
+        """
+        __orjson__ is called before OPT_SERIALIZE_OBJECT
+        """
+        assert (
+            orjson.dumps(Money(1, "USD"), option=orjson.OPT_SERIALIZE_OBJECT)
+            == b'{"amount":1,"currency":"USD"}'
+        )
+        assert orjson.dumps(Raw(), option=orjson.OPT_SERIALIZE_OBJECT) == b'{"a": 1}'
+
+    def test_protocol_native(self):
+        """
+        __orjson__ is not called for a type serialized natively
+        """
+        assert orjson.dumps(Dataclass(1)) == b'{"a":1}'
+        assert orjson.dumps(StrSubclass("a")) == b'"a"'
+        assert (
+            orjson.dumps(Dataclass(1), option=orjson.OPT_PASSTHROUGH_DATACLASS)
+            == b'"method"'
+        )
+        assert (
+            orjson.dumps(StrSubclass("a"), option=orjson.OPT_PASSTHROUGH_SUBCLASS)
+            == b'"method"'
+        )
+
+    def test_protocol_metaclass(self):
+        """
+        __orjson__ of a metaclass is not a method of instances
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(WithMeta())
+        assert orjson.dumps(WithMeta(), default=lambda obj: "default") == b'"default"'
+
+    def test_protocol_raises(self):
+        """
+        __orjson__ raising is chained as __cause__
+        """
+        with pytest.raises(orjson.JSONEncodeError) as exc_info:
+            orjson.dumps([Raises()])
+        assert isinstance(exc_info.value.__cause__, ValueError)
+
+    def test_protocol_recursion(self):
+        """
+        __orjson__ returning itself raises
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Self())
+
+    def test_protocol_recursion_limit(self):
+        """
+        __orjson__ return value is counted as a call of default
+        """
+        assert orjson.dumps(Countdown(254)) == b'"done"'
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(Countdown(255))
+
+    def test_protocol_added(self):
+        """
+        __orjson__ added to a class after an instance was serialized
+        """
+
+        class Late:
+            pass
+
+        class LateSubclass(Late):
+            pass
+
+        for _ in range(2):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.dumps([Late(), LateSubclass()])
+        Late.__orjson__ = lambda self: type(self).__name__
+        assert orjson.dumps([Late(), LateSubclass()]) == b'["Late","LateSubclass"]'
+        del Late.__orjson__
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps(LateSubclass())
+
+    def test_protocol_non_str_key(self):
+        """
+        __orjson__ is not called for a dict key
+        """
+        with pytest.raises(orjson.JSONEncodeError):
+            orjson.dumps({Raw(): 1}, option=orjson.OPT_NON_STR_KEYS)
+
+    def test_protocol_class_freed(self):
+        """
+        __orjson__ class is not kept alive by serializing it
+        """
+
+        class Temporary:
+            def __orjson__(self):
+                return 1
+
+        assert orjson.dumps(Temporary()) == b"1"
+        ref = weakref.ref(Temporary)
+        del Temporary
+        gc.collect()
+        assert ref() is None
@@ -0,0 +1,352 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import datetime
+
+import pytest
+
+import orjson
+
+# https://www.rfc-editor.org/rfc/rfc9535#section-1.5
+STORE = b"""{ "store": {
+    "book": [
+      { "category": "reference",
+        "author": "Nigel Rees",
+        "title": "Sayings of the Century",
+        "price": 8.95
+      },
+      { "category": "fiction",
+        "author": "Evelyn Waugh",
+        "title": "Sword of Honour",
+        "price": 12.99
+      },
+      { "category": "fiction",
+        "author": "Herman Melville",
+        "title": "Moby Dick",
+        "isbn": "0-553-21311-3",
+        "price": 8.99
+      },
+      { "category": "fiction",
+        "author": "J. R. R. Tolkien",
+        "title": "The Lord of the Rings",
+        "isbn": "0-395-19395-8",
+        "price": 22.99
+      }
+    ],
+    "bicycle": {
+      "color": "red",
+      "price": 399
+    }
+  }
+}"""
+
+BOOKS = orjson.loads(STORE)["store"]["book"]
+
+EXAMPLES = (
+    ("$.store.book[*].author", [book["author"] for book in BOOKS]),
+    ("$..author", [book["author"] for book in BOOKS]),
+    ("$.store.*", [BOOKS, {"color": "red", "price": 399}]),
+    ("$.store..price", [399, 8.95, 12.99, 8.99, 22.99]),
+    ("$..book[2]", [BOOKS[2]]),
+    ("$..book[2].author", ["Herman Melville"]),
+    ("$..book[2].publisher", []),
+    ("$..book[-1]", [BOOKS[3]]),
+    ("$..book[0,1]", BOOKS[:2]),
+    ("$..book[:2]", BOOKS[:2]),
+    ("$..book[?@.isbn]", BOOKS[2:]),
+    ("$..book[?@.price<10]", [BOOKS[0], BOOKS[2]]),
+    ("$..book[?(@.price < 10)].title", ["Sayings of the Century", "Moby Dick"]),
+)
+
+
+class TestQuery:
+    @pytest.mark.parametrize("path,expected", EXAMPLES)
+    def test_query(self, path, expected):
+        """
+        query() RFC 9535 examples
+        """
+        assert sorted(map(orjson.dumps, orjson.query(STORE, path))) == sorted(
+            map(orjson.dumps, expected),
+        )
+
+    def test_query_descendants(self):
+        """
+        query() $..* selects every node
+        """
+        assert len(orjson.query(STORE, "$..*")) == 27
+
+    def test_query_input_types(self):
+        """
+        query() bytes, bytearray, memoryview, str
+        """
+        for doc in (STORE, bytearray(STORE), memoryview(STORE), STORE.decode()):
+            assert orjson.query(doc, "$.store.bicycle.color") == ["red"]
+
+    def test_query_object(self):
+        """
+        query() Python object returns the selected objects
+        """
+        doc = orjson.loads(STORE)
+        assert orjson.query(doc, "$..book[?@.price < 10].title") == [
+            "Sayings of the Century",
+            "Moby Dick",
+        ]
+        assert orjson.query(doc, "$.store.bicycle")[0] is doc["store"]["bicycle"]
+
+    def test_query_object_tuple(self):
+        """
+        query() tuple is an array
+        """
+        assert orjson.query({"a": (1, 2, 3)}, "$.a[-2:]") == [2, 3]
+
+    def test_query_object_other(self):
+        """
+        query() non-JSON types are not equal to literals
+        """
+        dt = datetime.datetime(2026, 1, 1)
+        assert orjson.query({"a": dt, 1: "b"}, "$.*") == [dt, "b"]
+        assert orjson.query([dt], '$[?@ == "2026-01-01T00:00:00"]') == []
+        assert orjson.query({1: "b"}, "$[?@ == 'b']") == ["b"]
+
+    def test_query_slice(self):
+        """
+        query() slices
+        """
+        doc = b'["a", "b", "c", "d", "e", "f", "g"]'
+        assert orjson.query(doc, "$[1:3]") == ["b", "c"]
+        assert orjson.query(doc, "$[5:]") == ["f", "g"]
+        assert orjson.query(doc, "$[1:5:2]") == ["b", "d"]
+        assert orjson.query(doc, "$[5:1:-2]") == ["f", "d"]
+        assert orjson.query(doc, "$[::-1]") == ["g", "f", "e", "d", "c", "b", "a"]
+        assert orjson.query(doc, "$[::0]") == []
+        assert orjson.query(doc, "$[-100:100]") == list("abcdefg")
+
+    def test_query_index(self):
+        """
+        query() index out of range selects nothing
+        """
+        assert orjson.query(b"[1, 2]", "$[2]") == []
+        assert orjson.query(b"[1, 2]", "$[-3]") == []
+        assert orjson.query(b'{"0": 1}', "$[0]") == []
+
+    def test_query_name(self):
+        """
+        query() names in brackets and escapes
+        """
+        doc = b'{"a b": 1, "\\u00e9": 2, "\'": 3, "o": {"j j": {"k.k": 4}}}'
+        assert orjson.query(doc, "$['a b']") == [1]
+        assert orjson.query(doc, '$["\\u00e9"]') == [2]
+        assert orjson.query(doc, "$['\\'']") == [3]
+        assert orjson.query(doc, "$.o['j j']['k.k']") == [4]
+        assert orjson.query(doc, "$.é") == [2]
+
+    def test_query_comparison(self):
+        """
+        query() comparisons per RFC 9535 2.3.5.3
+        """
+        doc = b'{"obj": {"x": "y"}, "arr": [2, 3]}'
+        for expr, expected in (
+            ("$.absent1 == $.absent2", True),
+            ("$.absent1 <= $.absent2", True),
+            ("$.absent == 'g'", False),
+            ("$.absent1 != $.absent2", False),
+            ("$.absent != 'g'", True),
+            ("1 <= 2", True),
+            ("1 > 2", False),
+            ("13 == '13'", False),
+            ("'a' <= 'b'", True),
+            ("'a' > 'b'", False),
+            ("$.obj == $.arr", False),
+            ("$.obj != $.arr", True),
+            ("$.obj == $.obj", True),
+            ("$.obj != $.obj", False),
+            ("$.arr == $.arr", True),
+            ("$.arr != $.arr", False),
+            ("$.obj == 17", False),
+            ("$.obj != 17", True),
+            ("$.obj <= $.arr", False),
+            ("$.obj < $.arr", False),
+            ("$.obj <= $.obj", True),
+            ("$.arr <= $.arr", True),
+            ("1 <= $.arr", False),
+            ("1 >= $.arr", False),
+            ("1 > $.arr", False),
+            ("1 < $.arr", False),
+            ("true <= true", True),
+            ("true > true", False),
+        ):
+            selected = orjson.query(doc, f"$[?{expr}]")
+            assert (len(selected) == 2) is expected, expr
+
+    def test_query_number(self):
+        """
+        query() numbers compare by value
+        """
+        doc = b"[1, 1.0, 1e0, 2, 18446744073709551615, -9223372036854775808]"
+        assert orjson.query(doc, "$[?@ == 1]") == [1, 1.0, 1.0]
+        assert orjson.query(doc, "$[?@ > 1.5]") == [2, 18446744073709551615]
+        assert orjson.query(doc, "$[?@ < -1e18]") == [-9223372036854775808]
+        assert orjson.query([2**70, 1], "$[?@ > 1]") == [2**70]
+
+    def test_query_logical(self):
+        """
+        query() logical operators
+        """
+        doc = b'[{"a": 1, "b": 2}, {"a": 1}, {"b": 2}, {}]'
+        assert orjson.query(doc, "$[?@.a && @.b]") == [{"a": 1, "b": 2}]
+        assert orjson.query(doc, "$[?@.a || @.b]") == [
+            {"a": 1, "b": 2},
+            {"a": 1},
+            {"b": 2},
+        ]
+        assert orjson.query(doc, "$[?!@.a]") == [{"b": 2}, {}]
+        assert orjson.query(doc, "$[?!(@.a == 1 || @.b == 2)]") == [{}]
+
+    def test_query_filter_root(self):
+        """
+        query() filter referring to the root
+        """
+        doc = b'{"limit": 2, "items": [1, 2, 3]}'
+        assert orjson.query(doc, "$.items[?@ >= $.limit]") == [2, 3]
+
+    def test_query_filter_nested(self):
+        """
+        query() nested filter
+        """
+        doc = b'[[1, 2], [3], [4, 5, 6]]'
+        assert orjson.query(doc, "$[?@[?@ > 4]]") == [[4, 5, 6]]
+
+    def test_query_length(self):
+        """
+        query() length()
+        """
+        doc = '["ab", "é", [1, 2, 3], {"a": 1}, 1, null]'
+        assert orjson.query(doc, "$[?length(@) == 1]") == ["é", {"a": 1}]
+        assert orjson.query(doc, "$[?length(@) > 1]") == ["ab", [1, 2, 3]]
+
+    def test_query_count(self):
+        """
+        query() count()
+        """
+        assert len(orjson.query(STORE, "$.store[?count(@.*) == 2]")) == 1
+        assert orjson.query(STORE, "$[?count(@..price) == 5]") != []
+
+    def test_query_value(self):
+        """
+        query() value()
+        """
+        doc = b'[{"a": [1]}, {"a": [1, 1]}, {"a": 1}]'
+        assert orjson.query(doc, "$[?value(@.a[0]) == 1]") == [
+            {"a": [1]},
+            {"a": [1, 1]},
+        ]
+        assert orjson.query(doc, "$[?value(@..*) == 1]") == [{"a": 1}]
+
+    def test_query_match(self):
+        """
+        query() match() is anchored
+        """
+        doc = b'["1974-05-01", "1974-05-01T00:00:00", "x1974-05-01"]'
+        assert orjson.query(doc, '$[?match(@, "1974-05-..")]') == ["1974-05-01"]
+        assert orjson.query(doc, '$[?search(@, "1974-05-..")]') == [
+            "1974-05-01",
+            "1974-05-01T00:00:00",
+            "x1974-05-01",
+        ]
+
+    def test_query_regex(self):
+        """
+        query() I-Regexp
+        """
+        doc = '["a", "b", "\\n", "é", "ab", "^a", "5"]'
+        assert orjson.query(doc, '$[?match(@, ".")]') == ["a", "b", "é", "5"]
+        assert orjson.query(doc, '$[?match(@, "[a-b]+")]') == ["a", "b", "ab"]
+        assert orjson.query(doc, '$[?match(@, "\\\\p{Nd}")]') == ["5"]
+        assert orjson.query(doc, '$[?match(@, "\\\\p{Ll}")]') == ["a", "b", "é"]
+        assert orjson.query(doc, '$[?search(@, "^a")]') == ["^a"]
+        assert orjson.query(doc, '$[?match(@, "a{1,2}b?")]') == ["a", "ab"]
+        assert orjson.query(doc, '$[?match(@, $[0])]') == ["a"]
+
+    def test_query_regex_invalid(self):
+        """
+        query() invalid I-Regexp matches nothing
+        """
+        for pattern in ("(?i)a", "\\\\d", "[", "a{", "\\\\w+"):
+            assert orjson.query(b'["a", "1"]', f'$[?match(@, "{pattern}")]') == []
+            assert orjson.query(b'["a", "1"]', f'$[?!match(@, "{pattern}")]') == [
+                "a",
+                "1",
+            ]
+
+    @pytest.mark.parametrize(
+        "path",
+        (
+            "",
+            "$.",
+            "$[",
+            "$]",
+            "@",
+            "$a",
+            "$..",
+            "$[01]",
+            "$[-0]",
+            "$[9007199254740992]",
+            "$['a]",
+            "$.a b",
+            " $",
+            "$ ",
+            "$[?@.a == @.*]",
+            "$[?length(@.*) == 1]",
+            "$[?count(1) == 1]",
+            "$[?match(@.a, 'a') == true]",
+            "$[?length(@)]",
+            "$[?@ == 1 == 1]",
+            "$[?true]",
+            "$[?foo(@)]",
+        ),
+    )
+    def test_query_path_invalid(self, path):
+        """
+        query() invalid path raises ValueError
+        """
+        with pytest.raises(ValueError):
+            orjson.query(b"{}", path)
+
+    def test_query_path_type(self):
+        """
+        query() path not str raises TypeError
+        """
+        with pytest.raises(TypeError):
+            orjson.query(b"{}", b"$")
+        with pytest.raises(TypeError):
+            orjson.query(b"{}", None)
+
+    def test_query_document_invalid(self):
+        """
+        query() invalid document raises JSONDecodeError
+        """
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.query(b"{", "$")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.query(b"", "$")
+
+    def test_query_recursion(self):
+        """
+        query() object containing itself raises RecursionError
+        """
+        doc: list = []
+        doc.append(doc)
+        assert orjson.query(doc, "$[0][0]") == [doc]
+        with pytest.raises(RecursionError):
+            orjson.query(doc, "$..a")
+
+    def test_query_args(self):
+        """
+        query() arguments
+        """
+        with pytest.raises(TypeError):
+            orjson.query(b"{}")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.query(b"{}", "$", "$")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.query(data=b"{}", path="$")  # type: ignore
@@ -0,0 +1,215 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import json
+
+import pytest
+
+import orjson
+
+
+class TestRawDecode:
+    def test_raw_decode_bytes(self):
+        assert orjson.raw_decode(b"[1,2]") == ([1, 2], 5)
+
+    def test_raw_decode_str(self):
+        assert orjson.raw_decode("[1,2]") == ([1, 2], 5)
+
+    def test_raw_decode_bytearray(self):
+        assert orjson.raw_decode(bytearray(b'{"a":1} x')) == ({"a": 1}, 7)
+
+    def test_raw_decode_memoryview(self):
+        assert orjson.raw_decode(memoryview(b'"a" x')) == ("a", 3)
+
+    def test_raw_decode_trailing(self):
+        assert orjson.raw_decode(b'{"a": 1} [2]') == ({"a": 1}, 8)
+
+    def test_raw_decode_trailing_invalid(self):
+        assert orjson.raw_decode(b"[] garbage") == ([], 2)
+
+    def test_raw_decode_idx(self):
+        data = b'{"a": 1} [2]'
+        obj, end = orjson.raw_decode(data)
+        assert obj == {"a": 1}
+        assert orjson.raw_decode(data, end) == ([2], 12)
+        assert orjson.raw_decode(data, idx=end) == ([2], 12)
+
+    def test_raw_decode_concatenated(self):
+        data = b'1 "a" [] {} true null 2.5 -1e3'
+        res = []
+        idx = 0
+        while idx < len(data):
+            obj, idx = orjson.raw_decode(data, idx)
+            res.append(obj)
+        assert res == [1, "a", [], {}, True, None, 2.5, -1e3]
+
+    def test_raw_decode_leading_whitespace(self):
+        assert orjson.raw_decode(b" \t\r\n[]") == ([], 6)
+
+    def test_raw_decode_scalar_adjacent(self):
+        assert orjson.raw_decode(b"1[2]") == (1, 1)
+        assert orjson.raw_decode(b'"a"[2]') == ("a", 3)
+        assert orjson.raw_decode(b"null{}") == (None, 4)
+
+    def test_raw_decode_literal_adjacent(self):
+        assert orjson.raw_decode(b"truex") == (True, 4)
+        assert orjson.raw_decode("falsey") == (False, 5)
+        assert orjson.raw_decode(b"null1") == (None, 4)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"trux")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"fals")
+
+    def test_raw_decode_literal_non_ascii(self):
+        for data in ("tr€", "t€", "nu€", "fals€", "[tr€]"):
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.raw_decode(data)
+            assert "invalid literal" in str(exc_info.value)
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.raw_decode(data.encode("utf-8"))
+        assert orjson.raw_decode('"nu€"') == ("nu€", 5)
+
+    def test_raw_decode_string_brackets(self):
+        assert orjson.raw_decode(b'["\\"]", "{"] 1') == (['"]', "{"], 12)
+
+    def test_raw_decode_str_non_ascii(self):
+        data = '"é\U0001f408" "b"'
+        obj, end = orjson.raw_decode(data)
+        assert obj == "é\U0001f408"
+        assert end == 4
+        assert data[end:] == ' "b"'
+        assert orjson.raw_decode(data, end) == ("b", 8)
+
+    def test_raw_decode_bytes_non_ascii(self):
+        data = '"é" "b"'.encode("utf-8")
+        obj, end = orjson.raw_decode(data)
+        assert obj == "é"
+        assert end == 4
+        assert orjson.raw_decode(data, end) == ("b", 8)
+
+    def test_raw_decode_empty(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"")
+
+    def test_raw_decode_whitespace_only(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.raw_decode(b"[] \n ", 2)
+        assert exc_info.value.pos == 5
+
+    def test_raw_decode_incomplete(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"[1,")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b'"abc')
+
+    def test_raw_decode_invalid_pos(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.raw_decode(b"[1] [2,x]", 3)
+        assert exc_info.value.pos == 7
+
+    def test_raw_decode_invalid_pos_str(self):
+        with pytest.raises(orjson.JSONDecodeError) as exc_info:
+            orjson.raw_decode('"é" [2,x]', 3)
+        assert exc_info.value.pos == 7
+
+    def test_raw_decode_invalid_start(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"]")
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"[1] ]", 3)
+
+    def test_raw_decode_idx_out_of_range(self):
+        for idx in (4, 2**64):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.raw_decode(b"[1]", idx)
+
+    def test_raw_decode_idx_negative(self):
+        for idx in (-1, -(2**64)):
+            with pytest.raises(ValueError) as exc_info:
+                orjson.raw_decode(b"[1]", idx)
+            assert not isinstance(exc_info.value, orjson.JSONDecodeError)
+            assert str(exc_info.value) == "idx cannot be negative"
+
+    def test_raw_decode_idx_end(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"[1]", 3)
+
+    def test_raw_decode_idx_type(self):
+        with pytest.raises(TypeError):
+            orjson.raw_decode(b"[1]", "0")  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.raw_decode(b"[1]", 0.0)  # type: ignore
+
+    def test_raw_decode_args(self):
+        with pytest.raises(TypeError):
+            orjson.raw_decode()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.raw_decode(b"[1]", 0, 0)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.raw_decode(b"[1]", 0, idx=0)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.raw_decode(b"[1]", foo=0)  # type: ignore
+
+    def test_raw_decode_type(self):
+        for val in (1, 3.14, [], {}, None):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.raw_decode(val)  # type: ignore
+
+    def test_raw_decode_invalid_utf8(self):
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b'"\xed\xa0\x80" 1')
+
+    def test_raw_decode_invalid_utf8_after(self):
+        assert orjson.raw_decode(b"[1] \xff") == ([1], 3)
+        assert orjson.raw_decode(b'"\xc3\xa9" \xff') == ("é", 4)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode(b"[1] \xff", 3)
+
+    def test_raw_decode_number_prefix(self):
+        decoder = json.JSONDecoder()
+        for data in (
+            "2024-01-01 x",
+            "1-2",
+            "3+4",
+            "1e5e5",
+            "5.0.1 ok",
+            "1.",
+            "1.e5",
+            "1e",
+            "1e+",
+            "01",
+            "-0.5e-3x",
+            "12abc",
+        ):
+            expected = decoder.raw_decode(data)
+            assert orjson.raw_decode(data) == expected
+            assert orjson.raw_decode(data.encode("utf-8")) == expected
+
+    def test_raw_decode_number_invalid(self):
+        for data in ("-", "-x", "-.5"):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.raw_decode(data)
+
+    def test_raw_decode_str_kinds(self):
+        for char in ("a", "é", "\u20ac", "\U0001f408"):
+            docs = [f'"{char}{idx}"' for idx in range(50)]
+            data = " ".join(docs)
+            res = []
+            idx = 0
+            while idx < len(data):
+                obj, idx = orjson.raw_decode(data, idx)
+                res.append(obj)
+            assert res == [json.loads(doc) for doc in docs]
+
+    def test_raw_decode_str_kinds_error_pos(self):
+        for char in ("é", "\u20ac", "\U0001f408"):
+            data = f'"{char}" [1,x]'
+            with pytest.raises(orjson.JSONDecodeError) as exc_info:
+                orjson.raw_decode(data, 3)
+            assert exc_info.value.pos == 7
+            assert exc_info.value.doc == data
+
+    def test_raw_decode_str_surrogate_after(self):
+        assert orjson.raw_decode('[1] "\ud800"') == ([1], 3)
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.raw_decode('[1] "\ud800"', 3)
@@ -0,0 +1,118 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import pytest
+
+import orjson
+
+from .util import needs_data, read_fixture_bytes
+
+DOCUMENT = b""" {
+    "b": [1, -0, 1.10, 1e5, 2.5E-3, 12345678901234567890123, "x\\u00e9\\n\\/"],
+    "a": {"z": null, "y": true, "x": false},
+    "c": [],
+    "d": {}
+} """
+
+
+class TestReformat:
+    def test_reformat(self):
+        assert (
+            orjson.reformat(DOCUMENT)
+            == b'{"b":[1,-0,1.10,1e5,2.5E-3,12345678901234567890123,"x\xc3\xa9\\n/"],'
+            b'"a":{"z":null,"y":true,"x":false},"c":[],"d":{}}'
+        )
+
+    def test_reformat_matches_dumps(self):
+        data = b'{"a": [1, 2.5, "\\u0000", null, {"b": true}], "c": "\xf0\x9f\x90\x88"}'
+        for option in (
+            None,
+            orjson.OPT_INDENT_2,
+            orjson.OPT_SORT_KEYS,
+            orjson.OPT_APPEND_NEWLINE,
+            orjson.OPT_INDENT_2 | orjson.OPT_SORT_KEYS | orjson.OPT_APPEND_NEWLINE,
+        ):
+            assert orjson.reformat(data, option=option) == orjson.dumps(
+                orjson.loads(data),
+                option=option,
+            )
+
+    def test_reformat_indent(self):
+        assert (
+            orjson.reformat(b'{"a": [1.0, {}], "b": []}', option=orjson.OPT_INDENT_2)
+            == b'{\n  "a": [\n    1.0,\n    {}\n  ],\n  "b": []\n}'
+        )
+
+    def test_reformat_sort_keys(self):
+        assert (
+            orjson.reformat(DOCUMENT, option=orjson.OPT_SORT_KEYS)
+            == b'{"a":{"x":false,"y":true,"z":null},'
+            b'"b":[1,-0,1.10,1e5,2.5E-3,12345678901234567890123,"x\xc3\xa9\\n/"],'
+            b'"c":[],"d":{}}'
+        )
+
+    def test_reformat_duplicate_keys(self):
+        data = b'{"b": 1, "a": 2, "b": 3, "a": 4}'
+        assert orjson.reformat(data) == b'{"b":1,"a":2,"b":3,"a":4}'
+        assert (
+            orjson.reformat(data, option=orjson.OPT_SORT_KEYS)
+            == b'{"a":2,"a":4,"b":1,"b":3}'
+        )
+
+    def test_reformat_numbers(self):
+        for num in (b"0", b"-1", b"1.5000", b"1E+2", b"-0.0e-0", b"18446744073709551616"):
+            assert orjson.reformat(b" " + num + b" ") == num
+            assert orjson.reformat(b"[" + num + b"]") == b"[" + num + b"]"
+
+    def test_reformat_scalar(self):
+        assert orjson.reformat(b" null ") == b"null"
+        assert orjson.reformat(b' "a" ') == b'"a"'
+        assert orjson.reformat(b"true", option=orjson.OPT_APPEND_NEWLINE) == b"true\n"
+
+    def test_reformat_key_numbers(self):
+        data = b'{"1": 2, "-3": "4", "5e6": [7]}'
+        assert orjson.reformat(data) == b'{"1":2,"-3":"4","5e6":[7]}'
+
+    def test_reformat_numbers_escaped_strings(self):
+        data = b'["\\"1", 2.50, {"\\\\": -3e0}, "\\u0031", 4]'
+        assert orjson.reformat(data) == b'["\\"1",2.50,{"\\\\":-3e0},"1",4]'
+
+    def test_reformat_input_types(self):
+        for data in (bytearray(b"[1]"), memoryview(b"[1]"), "[1]"):
+            assert orjson.reformat(data) == b"[1]"
+
+    def test_reformat_invalid(self):
+        for data in (b"", b"[1,]", b'{"a": 1', b"[1] x", b"[NaN]", b'["\xed\xa0\x80"]'):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.reformat(data)
+
+    def test_reformat_option_invalid(self):
+        for option in (
+            orjson.OPT_NAIVE_UTC,
+            orjson.OPT_SORT_KEYS | orjson.OPT_NON_STR_KEYS,
+            -1,
+            1 << 20,
+            "1",
+            1.0,
+        ):
+            with pytest.raises(orjson.JSONEncodeError):
+                orjson.reformat(b"[]", option=option)  # type: ignore
+
+    def test_reformat_args(self):
+        with pytest.raises(TypeError):
+            orjson.reformat()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.reformat(b"[]", None, None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.reformat(b"[]", None, option=None)  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.reformat(b"[]", foo=None)  # type: ignore
+
+    @needs_data
+    def test_reformat_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        for option in (None, orjson.OPT_INDENT_2 | orjson.OPT_SORT_KEYS):
+            assert orjson.reformat(data, option=option) == orjson.dumps(
+                orjson.loads(data),
+                option=option,
+            )
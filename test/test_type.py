@@ -291,6 +291,15 @@ class TestType:
         """
         assert orjson.loads(memoryview(b"[]")) == []
 
+    def test_memoryview_loads_released(self):
+        """
+        memoryview loads released raises
+        """
+        view = memoryview(b"[]")
+        view.release()
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.loads(view)
+
     @pytest.mark.skipif(SUPPORTS_MEMORYVIEW is True, reason="memoryview")
     def test_memoryview_loads_unsupported(self):
         """
@@ -0,0 +1,131 @@
+# SPDX-License-Identifier: MPL-2.0
+# Copyright ijl (2026)
+
+import os
+
+import pytest
+
+import orjson
+
+from .util import data_dir, needs_data, read_fixture_bytes
+
+
+class TestValidate:
+    def test_validate(self):
+        for data in (
+            b'{"a": [1, 2.5, "\xc3\xa9", null, true, false, {}]}',
+            b"[]",
+            b" 1 ",
+            b'"a"',
+            "[\"\U0001f408\"]",
+            bytearray(b"[1]"),
+            memoryview(b"[1]"),
+            b' { "a" : [ ] , "b" : { } } ',
+            b"-0.5e-10",
+            b"1e-400",
+            b"18446744073709551616",
+            b'["\\ud83d\\ude00", "\\"\\\\\\/\\b\\f\\n\\r\\t"]',
+            b"[" * 1024 + b"]" * 1024,
+        ):
+            assert orjson.validate(data) is None
+
+    def test_validate_invalid(self):
+        for data in (
+            b"",
+            b" ",
+            b"[1,]",
+            b'{"a": 1',
+            b'{"a": 1} x',
+            b"[NaN]",
+            b"01",
+            b'["\xed\xa0\x80"]',
+            b'["\xff"]',
+            b"truex",
+            b"[tru]",
+            b"-",
+            b"1.",
+            b"1e",
+            b"1e400",
+            b"1" + b"0" * 400,
+            b'{"a" 1}',
+            b"{1: 1}",
+            b"[1 2]",
+            b"[1}",
+            b'["\x01"]',
+            b'["\\x"]',
+            b'["\\ud800"]',
+            b'["\\udc00"]',
+            b'["\\ud800\\u0041"]',
+            b"[" * 1025 + b"]" * 1025,
+        ):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.validate(data)
+
+    def test_validate_error_matches_loads(self):
+        data = b'{"a": [1, 2}'
+        with pytest.raises(orjson.JSONDecodeError) as loads_info:
+            orjson.loads(data)
+        with pytest.raises(orjson.JSONDecodeError) as validate_info:
+            orjson.validate(data)
+        assert str(validate_info.value) == str(loads_info.value)
+        assert validate_info.value.pos == loads_info.value.pos == 11
+
+    def test_validate_matches_loads(self):
+        for data in (
+            b"[1, 2] 3",
+            b'{"a": tru}',
+            b"[01]",
+            b'["\\ud800"]',
+            b"[1e400]",
+            b"[" * 1025 + b"]" * 1025,
+        ):
+            with pytest.raises(orjson.JSONDecodeError) as loads_info:
+                orjson.loads(data)
+            with pytest.raises(orjson.JSONDecodeError) as validate_info:
+                orjson.validate(data)
+            assert str(validate_info.value) == str(loads_info.value)
+
+    def test_validate_type(self):
+        for data in (1, None, [], {}):
+            with pytest.raises(orjson.JSONDecodeError):
+                orjson.validate(data)  # type: ignore
+
+    def test_validate_args(self):
+        with pytest.raises(TypeError):
+            orjson.validate()  # type: ignore
+        with pytest.raises(TypeError):
+            orjson.validate(b"[]", b"[]")  # type: ignore
+
+    @needs_data
+    def test_validate_fixture(self):
+        data = read_fixture_bytes("twitter.json.xz")
+        assert orjson.validate(data) is None
+        with pytest.raises(orjson.JSONDecodeError):
+            orjson.validate(data[:-1])
+
+    @needs_data
+    def test_validate_parsing_matches_loads(self):
+        """
+        validate() accepts exactly the documents loads() does, with the same
+        error, over the JSONTestSuite corpus
+        """
+        filenames = sorted(os.listdir(os.path.join(data_dir, "parsing")))
+        assert filenames
+        for filename in filenames:
+            data = read_fixture_bytes(filename, "parsing")
+            try:
+                decoded = data.decode("utf-8")
+            except UnicodeDecodeError:
+                inputs = (data,)
+            else:
+                inputs = (data, decoded)
+            for doc in inputs:
+                try:
+                    orjson.loads(doc)
+                except orjson.JSONDecodeError as exc:
+                    with pytest.raises(orjson.JSONDecodeError) as validate_info:
+                        orjson.validate(doc)
+                    assert str(validate_info.value) == str(exc), filename
+                    assert validate_info.value.pos == exc.pos, filename
+                else:
+                    assert orjson.validate(doc) is None, filename
@@ -16,6 +16,12 @@ SUPPORTS_BYTEARRAY = not IS_FREETHREADING
 
 SUPPORTS_GETREFCOUNT = sys.implementation == "cpython"
 
+attrs = None  # type: ignore
+try:
+    import attrs  # type: ignore # noqa: F401
+except ImportError:
+    pass
+
 numpy = None  # type: ignore
 try:
     import numpy  # type: ignore # noqa: F401